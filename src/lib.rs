@@ -1 +1,28 @@
+//! Scanner is a simple utility to parse strings, numbers, lines and more
+//! from text.
+//!
+//! The core [`Scanner`](scanner::Scanner) has no dependencies. Integrations
+//! are additive cargo features, each enabling its own module:
+//!
+//! | Feature                 | Enables                                             |
+//! |-------------------------|-----------------------------------------------------|
+//! | `bignum`                | Arbitrary-precision integers with `num-bigint`      |
+//! | `chrono`                | Date and time scanning with `chrono`                |
+//! | `complex`               | Complex numbers as `num-complex` values             |
+//! | `decimal`               | Exact decimals with `rust_decimal`                  |
+//! | `encoding`              | BOM detection and UTF-16/Latin-1 decoding           |
+//! | `futures`               | A `Stream` of tokens for async code                 |
+//! | `gzip`                  | Scanning gzip-compressed input with `flate2`        |
+//! | `mmap`                  | Zero-copy scanning of memory-mapped files           |
+//! | `rational`              | Fractions as `num-rational` ratios                  |
+//! | `rayon`                 | Parallel line processing with `rayon`               |
+//! | `semver`                | Semantic versions with `semver`                     |
+//! | `serde_json`            | Typed JSON Lines iteration with `serde_json`        |
+//! | `tracing`               | Trace events for consumed tokens with `tracing`     |
+//! | `unicode-normalization` | The `NormalizeUnicode` filter                       |
+//! | `unicode-segmentation`  | Unicode word and grapheme cluster scanning          |
+//! | `unicode`               | All Unicode features                                |
+//! | `zstd`                  | Scanning Zstandard-compressed input with `zstd`     |
+//! | `full`                  | Every feature                                       |
+
 pub mod scanner;
@@ -7,15 +7,16 @@ fn main() {
     let mut scanner = Scanner::new(input);
 
     let x: isize = scanner.next_number().expect("Expecting number");
-    let op = scanner.next_word().expect("Expecting word");
+    let op = scanner
+        .expect_one_of(&["+", "-", "*", "/"])
+        .unwrap_or_else(|error| panic!("{error}"));
     let y: isize = scanner.next_number().expect("Expecting number");
 
     let result = match op {
         "+" => x + y,
         "-" => x - y,
         "*" => x * y,
-        "/" => x / y,
-        _ => panic!("Invalid operator"),
+        _ => x / y,
     };
 
     println!("{x} {op} {y} = {result}");
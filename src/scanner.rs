@@ -1,14 +1,196 @@
+use std::borrow::Cow;
 use std::str::FromStr;
 
+mod attempt;
+mod balanced;
+#[cfg(feature = "bignum")]
+mod bignum;
+mod boolean;
+mod budget;
+mod builder;
+mod bytes;
+mod chars;
+mod chunked;
+mod clf;
+mod codec;
+mod collect;
+mod columns;
+#[cfg(feature = "complex")]
+mod complex;
+#[cfg(any(feature = "gzip", feature = "zstd"))]
+mod compress;
+mod conditional;
+mod count;
+mod csv;
+#[cfg(feature = "chrono")]
+mod datetime;
+mod debug;
+#[cfg(feature = "decimal")]
+mod decimal;
+mod decode;
+mod dedup;
+mod diagnostic;
+mod duration;
+#[cfg(feature = "encoding")]
+mod encoding;
+mod error;
+mod expect;
+mod extract;
+mod filter;
+mod follow;
+mod fraction;
+mod graph;
+mod grid;
+mod http;
+mod ics;
+mod ident;
+mod indent;
+mod index;
+mod ini;
+mod intern;
+mod json;
+#[cfg(feature = "serde_json")]
+mod json_lines;
+mod lexer;
+mod lookahead;
+mod markup;
+mod matrix;
+#[cfg(feature = "mmap")]
+mod mmap;
+mod money;
+mod newline;
+#[cfg(feature = "unicode-normalization")]
+mod normalize;
+mod number;
+mod pairs;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod partition;
+mod point;
+mod pool;
+mod progress;
+mod query;
+mod range;
+#[cfg(feature = "rational")]
+mod ratio;
+mod record;
+mod recover;
+mod region;
+mod scanner_like;
+mod scripted;
+mod search;
+#[cfg(feature = "unicode-segmentation")]
+mod segment;
+mod shell;
+mod size;
+mod skip;
+mod source;
+mod span;
+mod stats;
+#[cfg(feature = "futures")]
+mod stream;
+mod syslog;
+mod table;
+mod take;
+mod token;
+#[cfg(feature = "tracing")]
+mod trace;
+mod unread;
+#[cfg(feature = "semver")]
+mod version;
+
+use budget::Budget;
+use progress::ProgressHook;
+
+pub use balanced::Group;
+pub use boolean::BOOL_SPELLINGS;
+pub use builder::ScannerBuilder;
+pub use bytes::ByteScanner;
+pub use chars::{CharIndices, Chars};
+pub use chunked::ChunkScanner;
+pub use clf::ClfRecord;
+pub use columns::Columns;
+pub use decode::{DecodedInput, Fallback, InvalidSequence};
+pub use dedup::DedupLines;
+pub use diagnostic::Diagnostic;
+pub use duration::IsoDuration;
+#[cfg(feature = "encoding")]
+pub use encoding::Encoding;
+pub use error::{ScanError, ScanErrorKind};
+pub use extract::ExtractNumbers;
+pub use filter::{
+    CollapseWhitespace, Filter, FilteredLine, FilteredLines, OffsetMap, Pipeline, StripAnsi,
+};
+pub use follow::FollowScanner;
+pub use graph::GraphFormat;
+pub use grid::Grid;
+pub use ics::IcsProperty;
+pub use ident::{IdentOrKeyword, IdentPath};
+pub use indent::{IndentToken, IndentTokens};
+pub use index::{IndexedToken, TokenIndex};
+pub use ini::IniEntries;
+pub use intern::{Interner, Symbol};
+#[cfg(feature = "serde_json")]
+pub use json_lines::JsonLines;
+pub use lexer::{Lexer, LexerBuilder, LiteralStyle, Spanned};
+pub use markup::Tag;
+#[cfg(feature = "mmap")]
+pub use mmap::MappedFile;
+pub use money::Money;
+pub use newline::NewlinePolicy;
+#[cfg(feature = "unicode-normalization")]
+pub use normalize::{NormalizationForm, NormalizeUnicode};
+pub use number::{NumberFormat, SignPolicy};
+pub use pairs::KeyValues;
+pub use point::PointFormat;
+pub use pool::{PooledBuffer, ScannerPool};
+pub use query::QueryPair;
+pub use record::DuplicateKeys;
+pub use region::MappedRegion;
+pub use scanner_like::ScannerLike;
+pub use scripted::{CallKind, RecordedCall, RecordingScanner, ScriptedScanner};
+pub use source::{ResolvedSpan, SourceId, SourceMap};
+pub use span::Span;
+pub use stats::Stats;
+#[cfg(feature = "futures")]
+pub use stream::TokenStream;
+pub use syslog::SyslogRecord;
+pub use table::Table;
+pub use token::{Token, TokenKind, Tokens};
+
 /// A `Scanner` is a simple utility for parsing strings, allowing access to words,
 /// numbers, and lines from an input string.
 ///
 /// The `Scanner` maintains a position in the input string and provides
 /// methods to extract the next number, word, or line from the remaining
 /// input.
+#[derive(Clone)]
 pub struct Scanner<'a> {
     input: &'a str,
     position: usize,
+    last: Option<(usize, usize)>,
+    sources: Option<&'a SourceMap>,
+    tokens: usize,
+    budget: Budget,
+    interner: Interner<'a>,
+    sign_policy: SignPolicy,
+    newline_policy: NewlinePolicy,
+    number_format: NumberFormat,
+    point_format: PointFormat,
+    progress: Option<ProgressHook<'a>>,
+    stats: Option<Stats>,
+    #[cfg(feature = "tracing")]
+    traced: bool,
+}
+
+/// A saved cursor state, used to undo a failed scan.
+#[derive(Clone, Copy)]
+struct Checkpoint<'a> {
+    position: usize,
+    last: Option<(usize, usize)>,
+    tokens: usize,
+    stats: Option<Stats>,
+    progress: Option<ProgressHook<'a>>,
 }
 
 impl<'a> Scanner<'a> {
@@ -25,7 +207,23 @@ impl<'a> Scanner<'a> {
     /// let scanner = Scanner::new("Hello, world!");
     /// ```
     pub fn new(input: &'a str) -> Self {
-        Scanner { input, position: 0 }
+        Scanner {
+            input,
+            position: 0,
+            last: None,
+            sources: None,
+            tokens: 0,
+            budget: Budget::default(),
+            interner: Interner::default(),
+            sign_policy: SignPolicy::default(),
+            newline_policy: NewlinePolicy::default(),
+            number_format: NumberFormat::default(),
+            point_format: PointFormat::default(),
+            progress: None,
+            stats: None,
+            #[cfg(feature = "tracing")]
+            traced: true,
+        }
     }
 
     /// Scans for the next token in the input string based on a provided predicate.
@@ -37,7 +235,7 @@ impl<'a> Scanner<'a> {
     /// # Arguments
     ///
     /// * `predicate`: A closure that takes a character as input and returns a boolean,
-    /// determining whether the character should be considered part of the token.
+    ///   determining whether the character should be considered part of the token.
     ///
     /// # Returns
     ///
@@ -57,6 +255,31 @@ impl<'a> Scanner<'a> {
     /// assert_eq!(scanner.next_token(|c, _| c.is_digit(10)), Some("123"));
     /// ```
     pub fn next_token<F>(&mut self, predicate: F) -> Option<&'a str>
+    where
+        F: Fn(char, usize) -> bool,
+    {
+        self.try_next_token(predicate).ok()
+    }
+
+    /// Scans for the next token in the input string based on a provided predicate.
+    ///
+    /// This is the `Result` returning counterpart of [`Scanner::next_token`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only whitespace remains.
+    /// * [`ScanErrorKind::NoMatch`] if no character of the remaining input satisfies `predicate`.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{ScanErrorKind, Scanner};
+    /// let mut scanner = Scanner::new("abc");
+    /// let error = scanner.try_next_token(|c, _| c.is_ascii_digit()).unwrap_err();
+    /// assert_eq!(error.kind(), &ScanErrorKind::NoMatch);
+    /// ```
+    pub fn try_next_token<F>(&mut self, predicate: F) -> Result<&'a str, ScanError>
     where
         F: Fn(char, usize) -> bool,
     {
@@ -68,7 +291,7 @@ impl<'a> Scanner<'a> {
         for (i, c) in remaining.char_indices() {
             if predicate(c, i) {
                 valid_chars_count += 1;
-                token_len = i + 1;
+                token_len = i + c.len_utf8();
             } else {
                 if valid_chars_count > 0 {
                     break;
@@ -77,19 +300,29 @@ impl<'a> Scanner<'a> {
             }
         }
 
-        if valid_chars_count > 0 {
-            self.position += token_len;
-            Some(remaining[..token_len].trim_start())
-        } else {
-            None
+        if valid_chars_count == 0 {
+            let trimmed = remaining.trim_start();
+            let kind = if trimmed.is_empty() {
+                ScanErrorKind::EndOfInput
+            } else {
+                ScanErrorKind::NoMatch
+            };
+            return Err(ScanError::new(kind, self.input.len() - trimmed.len()));
         }
+
+        let token = remaining[..token_len].trim_start();
+        let end = self.position + token_len;
+        self.consume(end - token.len(), end, end)?;
+        Ok(token)
     }
 
     /// Scans for the next number in the input string.
     ///
     /// Parses a contiguous sequence of digits, including an optional leading
-    /// minus sign for negative numbers. Consumes the number from the input
-    /// and updates the scanner's position.
+    /// minus sign for negative numbers and the `_` separators allowed by the
+    /// [`NumberFormat`]. Consumes the number from the input
+    /// and updates the scanner's position. Whether a minus sign directly
+    /// after another token is a sign is controlled by [`SignPolicy`].
     ///
     /// # Returns
     ///
@@ -107,15 +340,77 @@ impl<'a> Scanner<'a> {
     where
         T: FromStr,
     {
-        let position = self.position;
-        self.next_token(|c, i| c.is_digit(10) || (c == '-' && i == 0))
-            .and_then(|token| match token.parse::<T>() {
-                Ok(number) => Some(number),
-                Err(_) => {
-                    self.position = position;
-                    None
-                }
-            })
+        self.try_next_number().ok()
+    }
+
+    /// Scans for the next number in the input string.
+    ///
+    /// This is the `Result` returning counterpart of [`Scanner::next_number`].
+    /// Nothing is consumed on failure.
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only whitespace remains.
+    /// * [`ScanErrorKind::InvalidNumber`] if the next token does not start
+    ///   with digits, or is negative while `T` is unsigned.
+    /// * [`ScanErrorKind::Overflow`] if the digits do not fit in `T`.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{ScanErrorKind, Scanner};
+    /// let mut scanner = Scanner::new("x1");
+    /// let error = scanner.try_next_number::<i32>().unwrap_err();
+    /// assert_eq!(error.kind(), &ScanErrorKind::InvalidNumber);
+    /// assert_eq!(error.position(), 0);
+    ///
+    /// let mut scanner = Scanner::new("+42 99999999999");
+    /// assert_eq!(scanner.try_next_number::<i32>(), Ok(42));
+    /// let error = scanner.try_next_number::<i32>().unwrap_err();
+    /// assert_eq!(error.kind(), &ScanErrorKind::Overflow);
+    /// assert_eq!(error.position(), 4);
+    /// ```
+    pub fn try_next_number<T>(&mut self) -> Result<T, ScanError>
+    where
+        T: FromStr,
+    {
+        let remaining = self.get_remaining();
+        if remaining.starts_with(['-', '+'])
+            && !self.sign_policy.is_sign_after(self.previous_char())
+        {
+            return Err(self.error(ScanErrorKind::InvalidNumber));
+        }
+
+        let trimmed = remaining.trim_start();
+        let start = self.input.len() - trimmed.len();
+        if trimmed.is_empty() {
+            return Err(ScanError::new(ScanErrorKind::EndOfInput, start));
+        }
+
+        let sign = usize::from(trimmed.starts_with(['-', '+']));
+        let (len, digits) = self.number_format.digits(trimmed, sign, 10);
+        if digits.is_empty() {
+            return Err(ScanError::new(ScanErrorKind::InvalidNumber, start));
+        }
+
+        let value = match digits {
+            Cow::Borrowed(_) => trimmed[..len].parse::<T>(),
+            Cow::Owned(digits) => format!("{}{digits}", &trimmed[..sign]).parse(),
+        };
+        let Ok(value) = value else {
+            self.record_failure();
+            let unsigned = trimmed.starts_with('-') && "-0".parse::<T>().is_err();
+            let kind = if unsigned {
+                ScanErrorKind::InvalidNumber
+            } else {
+                ScanErrorKind::Overflow
+            };
+            return Err(ScanError::new(kind, start));
+        };
+
+        self.consume(start, start + len, start + len)?;
+        Ok(value)
     }
 
     /// Scans for the next word in the input string.
@@ -136,13 +431,27 @@ impl<'a> Scanner<'a> {
     /// assert_eq!(scanner.next_word(), Some("Hello,"));
     /// ```
     pub fn next_word(&mut self) -> Option<&'a str> {
-        self.next_token(|c, _| !c.is_whitespace())
+        self.try_next_word().ok()
+    }
+
+    /// Scans for the next word in the input string.
+    ///
+    /// This is the `Result` returning counterpart of [`Scanner::next_word`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only whitespace remains.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_word(&mut self) -> Result<&'a str, ScanError> {
+        self.try_next_token(|c, _| !c.is_whitespace())
     }
 
     /// Scans for the next line from the input string.
     ///
     /// A line is defined as a sequence of characters terminating with a newline
-    /// (`\n`). Consumes the line from the input and updates the scanner's
+    /// (`\n`, or the terminators of the [`NewlinePolicy`] set with
+    /// [`Scanner::with_newline_policy`]). Trailing whitespace is trimmed from
+    /// the line. Consumes the line from the input and updates the scanner's
     /// position.
     ///
     /// # Returns
@@ -158,20 +467,176 @@ impl<'a> Scanner<'a> {
     /// assert_eq!(scanner.next_line(), Some("First line"));
     /// ```
     pub fn next_line(&mut self) -> Option<&'a str> {
+        self.try_next_line().ok()
+    }
+
+    /// Scans for the next line from the input string.
+    ///
+    /// This is the `Result` returning counterpart of [`Scanner::next_line`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if no input remains.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_line(&mut self) -> Result<&'a str, ScanError> {
         let remaining = self.get_remaining();
 
-        if let Some(newline_pos) = remaining.find('\n') {
-            let line = &remaining[..newline_pos];
-
-            self.position += newline_pos + 1;
-            Some(line.trim_end())
-        } else if !remaining.is_empty() {
-            let line = remaining;
-            self.position = self.input.len();
-            Some(line.trim_end())
-        } else {
-            None
+        if remaining.is_empty() {
+            return Err(self.error(ScanErrorKind::EndOfInput));
+        }
+
+        let (line, consumed) = match self.newline_policy.find(remaining) {
+            Some((newline_pos, len)) => (&remaining[..newline_pos], newline_pos + len),
+            None => (remaining, remaining.len()),
+        };
+        let line = line.trim_end();
+
+        self.consume(
+            self.position,
+            self.position + line.len(),
+            self.position + consumed,
+        )?;
+        Ok(line)
+    }
+
+    /// Consumes the next line and returns a new `Scanner` over just that line.
+    ///
+    /// This makes per-line parsing safe: scanning methods on the returned
+    /// scanner can never run past the end of the line. The returned scanner
+    /// has the settings, remaining budget and source map of this one, and
+    /// its positions and spans are offsets in the whole input.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Scanner)` over the next line.
+    /// * `None` if no line is found (i.e., end of input).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("1 2\n3\n");
+    /// let mut line = scanner.scan_line().unwrap();
+    /// assert_eq!(line.next_number(), Some(1));
+    /// assert_eq!(line.next_number(), Some(2));
+    /// assert_eq!(line.next_number::<i32>(), None);
+    /// assert_eq!(scanner.next_number(), Some(3));
+    /// ```
+    pub fn scan_line(&mut self) -> Option<Scanner<'a>> {
+        self.next_line()?;
+        let (start, end) = self.last?;
+        Some(self.child(start, end))
+    }
+
+    /// Consumes the remaining input and splits it into sections separated by
+    /// `separator`, returning a new `Scanner` over each section.
+    ///
+    /// Sections are split exactly like [`str::split`], so a separator at the
+    /// start or the end of the input yields an empty section. Like
+    /// [`Scanner::scan_line`], the returned scanners keep the settings of
+    /// this one.
+    ///
+    /// # Arguments
+    ///
+    /// * `separator` - The string separating two sections.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("name: a\n---\nname: b\n");
+    /// let mut sections = scanner.split_sections("---");
+    /// assert_eq!(sections.len(), 2);
+    /// assert_eq!(sections[1].next_word(), Some("name:"));
+    /// assert_eq!(sections[1].next_word(), Some("b"));
+    /// assert_eq!(scanner.get_remaining(), "");
+    /// ```
+    pub fn split_sections(&mut self, separator: &str) -> Vec<Scanner<'a>> {
+        let remaining = self.get_remaining();
+
+        let base = self.position;
+        if self
+            .consume(base, self.input.len(), self.input.len())
+            .is_err()
+        {
+            return Vec::new();
+        }
+
+        let mut start = base;
+        let mut sections = Vec::new();
+        for (i, _) in remaining.match_indices(separator) {
+            sections.push(self.child(start, base + i));
+            start = base + i + separator.len();
+        }
+        sections.push(self.child(start, self.input.len()));
+        sections
+    }
+
+    /// Scans for the next paragraph from the input string.
+    ///
+    /// A paragraph is a run of non-blank lines terminated by a blank line
+    /// (empty or whitespace only) or the end of the input. Leading blank lines
+    /// are skipped, and the terminating blank line is consumed along with the
+    /// paragraph.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` with the lines of the paragraph, without the trailing newline.
+    /// * `None` if only blank lines remain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("1000\n2000\n\n3000\n");
+    /// assert_eq!(scanner.next_paragraph(), Some("1000\n2000"));
+    /// assert_eq!(scanner.next_paragraph(), Some("3000"));
+    /// assert_eq!(scanner.next_paragraph(), None);
+    /// ```
+    pub fn next_paragraph(&mut self) -> Option<&'a str> {
+        let remaining = self.get_remaining();
+
+        let mut start = None;
+        let mut end = 0;
+        let mut consumed = 0;
+
+        for line in self.newline_policy.split_inclusive(remaining) {
+            let blank = line.trim().is_empty();
+            consumed += line.len();
+
+            match start {
+                None if blank => {}
+                None => {
+                    start = Some(consumed - line.len());
+                    end = consumed;
+                }
+                Some(_) if blank => break,
+                Some(_) => end = consumed,
+            }
         }
+
+        let start = start?;
+        let paragraph = remaining[start..end].trim_end();
+        let start = self.position + start;
+        self.consume(start, start + paragraph.len(), self.position + consumed)
+            .ok()?;
+        Some(paragraph)
+    }
+
+    /// Returns an iterator over the remaining paragraphs of the input.
+    ///
+    /// See [`Scanner::next_paragraph`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("a\nb\n\nc\n\n\nd");
+    /// let paragraphs: Vec<_> = scanner.paragraphs().collect();
+    /// assert_eq!(paragraphs, ["a\nb", "c", "d"]);
+    /// ```
+    pub fn paragraphs(&mut self) -> Paragraphs<'_, 'a> {
+        Paragraphs { scanner: self }
     }
 
     /// Returns the remaining unscanned input as a string slice.
@@ -191,6 +656,200 @@ impl<'a> Scanner<'a> {
     pub fn get_remaining(&self) -> &'a str {
         &self.input[self.position..]
     }
+
+    /// Returns the byte offset of the cursor in the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("Hello world!");
+    /// scanner.next_word();
+    /// assert_eq!(scanner.position(), 5);
+    /// ```
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Moves the cursor to the byte offset `position`, to rewind or fast-forward
+    /// the scanner.
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::InvalidPosition`] if `position` is past the end of
+    ///   the input or not on a character boundary. The cursor is left untouched.
+    /// * [`ScanErrorKind::BudgetExceeded`] if `position` is past the byte budget.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("añb");
+    /// assert!(scanner.set_position(2).is_err());
+    /// assert!(scanner.set_position(3).is_ok());
+    /// assert_eq!(scanner.get_remaining(), "b");
+    /// ```
+    pub fn set_position(&mut self, position: usize) -> Result<(), ScanError> {
+        if !self.input.is_char_boundary(position) {
+            return Err(ScanError::new(ScanErrorKind::InvalidPosition, position));
+        }
+
+        if position > self.budget.bytes {
+            return Err(ScanError::new(ScanErrorKind::BudgetExceeded, position));
+        }
+
+        self.position = position;
+        Ok(())
+    }
+
+    /// Consumes the next `n` characters, or all remaining characters if fewer
+    /// than `n` are left.
+    ///
+    /// # Returns
+    ///
+    /// The consumed slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("für alle");
+    /// assert_eq!(scanner.advance_chars(3), "für");
+    /// assert_eq!(scanner.get_remaining(), " alle");
+    /// ```
+    pub fn advance_chars(&mut self, n: usize) -> &'a str {
+        let remaining = self.get_remaining();
+        let len = remaining
+            .char_indices()
+            .nth(n)
+            .map_or(remaining.len(), |(i, _)| i);
+
+        self.take(len)
+    }
+
+    /// Moves the cursor to `position`, recording `start..end` as the last
+    /// consumed token.
+    ///
+    /// Every scanning method consumes input through here, so that the budget
+    /// is enforced in a single place.
+    fn consume(&mut self, start: usize, end: usize, position: usize) -> Result<(), ScanError> {
+        if self.tokens >= self.budget.tokens || position > self.budget.bytes {
+            return Err(self.error(ScanErrorKind::BudgetExceeded));
+        }
+
+        if let Some(stats) = &mut self.stats {
+            stats.record(self.input, start, end, self.position, position);
+        }
+        #[cfg(feature = "tracing")]
+        self.trace_consumed(start, end, position);
+        self.tokens += 1;
+        self.last = Some((start, end));
+        self.position = position;
+        self.report_progress();
+        Ok(())
+    }
+
+    /// Scans the next word and converts it with `parse`.
+    ///
+    /// If `parse` returns `None`, nothing is consumed and the error of the
+    /// given `kind` points at the start of the word.
+    fn parse_next_word<T>(
+        &mut self,
+        kind: ScanErrorKind,
+        parse: impl FnOnce(&'a str) -> Option<T>,
+    ) -> Result<T, ScanError> {
+        let mut fork = self.fork();
+        let word = fork.try_next_word()?;
+        let start = fork.position - word.len();
+
+        let Some(value) = parse(word) else {
+            self.record_failure();
+            return Err(ScanError::new(kind, start));
+        };
+
+        self.consume(start, fork.position, fork.position)?;
+        Ok(value)
+    }
+
+    /// Returns a copy of the scanner's cursor for lookahead, leaving out the
+    /// interned strings.
+    ///
+    /// What the copy scans is neither reported, counted nor traced.
+    fn fork(&self) -> Scanner<'a> {
+        Scanner {
+            interner: Interner::default(),
+            progress: None,
+            stats: None,
+            #[cfg(feature = "tracing")]
+            traced: false,
+            ..*self
+        }
+    }
+
+    /// Returns a scanner over `start..end` of the input, with the settings,
+    /// remaining budget and source map of this one.
+    ///
+    /// The input before `start` is kept, so that positions and spans remain
+    /// offsets in the whole input.
+    fn child(&self, start: usize, end: usize) -> Scanner<'a> {
+        Scanner {
+            input: &self.input[..end],
+            position: start,
+            last: None,
+            interner: Interner::default(),
+            progress: None,
+            stats: None,
+            ..*self
+        }
+    }
+
+    fn checkpoint(&self) -> Checkpoint<'a> {
+        Checkpoint {
+            position: self.position,
+            last: self.last,
+            tokens: self.tokens,
+            stats: self.stats,
+            progress: self.progress,
+        }
+    }
+
+    fn restore(&mut self, checkpoint: Checkpoint<'a>) {
+        #[cfg(feature = "tracing")]
+        let from = self.position;
+        self.position = checkpoint.position;
+        self.last = checkpoint.last;
+        self.tokens = checkpoint.tokens;
+        self.stats = checkpoint.stats;
+        self.progress = checkpoint.progress;
+        self.record_failure();
+        #[cfg(feature = "tracing")]
+        self.trace_rewound(from);
+    }
+
+    /// Counts a scan that read a token but failed to parse it.
+    fn record_failure(&mut self) {
+        self.stats = self.stats.map(Stats::failed);
+    }
+
+    /// Builds an error of the given kind at the current position.
+    fn error(&self, kind: ScanErrorKind) -> ScanError {
+        ScanError::new(kind, self.position)
+    }
+}
+
+/// An iterator over the paragraphs of a [`Scanner`].
+///
+/// Created by [`Scanner::paragraphs`].
+pub struct Paragraphs<'s, 'a> {
+    scanner: &'s mut Scanner<'a>,
+}
+
+impl<'a> Iterator for Paragraphs<'_, 'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.scanner.next_paragraph()
+    }
 }
 
 #[cfg(test)]
@@ -341,4 +1000,100 @@ mod tests {
 
         assert_eq!(scanner.next_line(), None);
     }
+
+    #[test]
+    fn test_scan_line() {
+        let mut scanner = Scanner::new("3 4 5\n\n6 7\n");
+        let mut sums = Vec::new();
+        while let Some(mut line) = scanner.scan_line() {
+            let mut sum = 0;
+            while let Some(n) = line.next_number::<i32>() {
+                sum += n;
+            }
+            sums.push(sum);
+        }
+        assert_eq!(sums, [12, 0, 13]);
+        assert_eq!(scanner.get_remaining(), "");
+    }
+
+    #[test]
+    fn test_scan_line_keeps_settings() {
+        let mut scanner = Scanner::new("x\n1,5 2\n")
+            .with_number_format(NumberFormat::EUROPEAN)
+            .with_budget(3, usize::MAX);
+        scanner.next_line();
+        let mut line = scanner.scan_line().unwrap();
+        assert_eq!(line.position(), 2);
+        assert_eq!(line.next_float(), Some(1.5));
+        assert_eq!(line.last_span().unwrap().range(), 2..5);
+        assert_eq!(line.next_float(), None);
+
+        let sources = SourceMap::chain(["a\n", "b c\n"]);
+        let mut scanner = sources.scanner();
+        scanner.next_line();
+        let mut line = scanner.scan_line().unwrap();
+        line.next_word();
+        let source = line.last_span().unwrap().source.unwrap();
+        assert_eq!(source.index(), 1);
+    }
+
+    #[test]
+    fn test_split_sections() {
+        let mut scanner = Scanner::new("header\n%%\n1 2\n%%\n3");
+        assert_eq!(scanner.next_line(), Some("header"));
+
+        let mut sections = scanner.split_sections("%%");
+        assert_eq!(sections.len(), 3);
+        assert_eq!(sections[0].next_word(), None);
+        assert_eq!(sections[1].next_number(), Some(1));
+        assert_eq!(sections[1].next_number(), Some(2));
+        assert_eq!(sections[1].next_number::<i32>(), None);
+        assert_eq!(sections[2].next_number(), Some(3));
+
+        let mut scanner = Scanner::new("1,5;2,5").with_number_format(NumberFormat::EUROPEAN);
+        let mut sections = scanner.split_sections(";");
+        assert_eq!(sections[1].position(), 4);
+        assert_eq!(sections[0].next_float(), Some(1.5));
+        assert_eq!(sections[1].next_float(), Some(2.5));
+        assert_eq!(Scanner::new("ab").split_sections("").len(), 4);
+    }
+
+    #[test]
+    fn test_position_rewind() {
+        let mut scanner = Scanner::new("10 20 30");
+        let start = scanner.position();
+        assert_eq!(scanner.next_number(), Some(10));
+        assert_eq!(scanner.next_number(), Some(20));
+        assert_eq!(scanner.position(), 5);
+
+        scanner.set_position(start).unwrap();
+        assert_eq!(scanner.next_number(), Some(10));
+
+        let error = scanner.set_position(9).unwrap_err();
+        assert_eq!(error.kind(), &ScanErrorKind::InvalidPosition);
+        assert_eq!(scanner.position(), 2);
+
+        assert_eq!(scanner.advance_chars(100), " 20 30");
+        assert_eq!(scanner.advance_chars(1), "");
+    }
+
+    #[test]
+    fn test_next_paragraph() {
+        let mut scanner = Scanner::new("\n\nfirst\nblock  \n   \nsecond block\n\n\n");
+        assert_eq!(scanner.next_paragraph(), Some("first\nblock"));
+        assert_eq!(scanner.get_remaining(), "second block\n\n\n");
+        assert_eq!(scanner.next_paragraph(), Some("second block"));
+        assert_eq!(scanner.get_remaining(), "\n");
+        assert_eq!(scanner.next_paragraph(), None);
+    }
+
+    #[test]
+    fn test_next_paragraph_then_numbers() {
+        let mut scanner = Scanner::new("1\n2\n\n3\n4");
+        let sums: Vec<i32> = scanner
+            .paragraphs()
+            .map(|p| p.lines().map(|l| l.parse::<i32>().unwrap()).sum())
+            .collect();
+        assert_eq!(sums, [3, 7]);
+    }
 }
@@ -0,0 +1,79 @@
+use super::Scanner;
+
+impl<'a> Scanner<'a> {
+    /// Runs `f` on the scanner, rewinding everything it consumed if it
+    /// returns `None`.
+    ///
+    /// Attempts nest, so alternatives can be tried one after the other
+    /// without saving and restoring the position by hand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("move 3 left");
+    /// let step = scanner.attempt(|s| {
+    ///     s.expect("move").ok()?;
+    ///     let n = s.next_number::<u32>()?;
+    ///     s.expect("right").ok()?;
+    ///     Some(n)
+    /// });
+    /// assert_eq!(step, None);
+    /// assert_eq!(scanner.position(), 0);
+    /// ```
+    pub fn attempt<T, F>(&mut self, f: F) -> Option<T>
+    where
+        F: FnOnce(&mut Scanner<'a>) -> Option<T>,
+    {
+        self.try_attempt(|scanner| f(scanner).ok_or(())).ok()
+    }
+
+    /// Runs `f` on the scanner, rewinding everything it consumed if it
+    /// returns an error.
+    ///
+    /// # Errors
+    ///
+    /// The error returned by `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{ScanError, Scanner};
+    /// let mut scanner = Scanner::new("12 apples");
+    /// let pair: Result<_, ScanError> = scanner.try_attempt(|s| {
+    ///     let a = s.try_next_number::<u32>()?;
+    ///     let b = s.try_next_number::<u32>()?;
+    ///     Ok((a, b))
+    /// });
+    /// assert_eq!(pair.unwrap_err().position(), 3);
+    /// assert_eq!(scanner.next_number::<u32>(), Some(12));
+    /// ```
+    pub fn try_attempt<T, E, F>(&mut self, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&mut Scanner<'a>) -> Result<T, E>,
+    {
+        let checkpoint = self.checkpoint();
+        let result = f(self);
+        if result.is_err() {
+            self.restore(checkpoint);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_attempts() {
+        let mut scanner = Scanner::new("a b c");
+        let outer = scanner.attempt(|s| {
+            s.next_word()?;
+            assert_eq!(s.attempt(|s| s.next_word().filter(|w| *w == "x")), None);
+            s.next_word()
+        });
+        assert_eq!(outer, Some("b"));
+        assert_eq!(scanner.get_remaining(), " c");
+    }
+}
@@ -0,0 +1,183 @@
+use super::{Scanner, Span};
+
+/// A balanced pair of delimiters found by [`Scanner::next_group`], with the
+/// pairs nested inside it.
+///
+/// # Examples
+///
+/// ```
+/// use scanner::scanner::Scanner;
+/// let mut scanner = Scanner::new("(max(a, b), min(c, d))");
+/// let group = scanner.next_group('(', ')').unwrap();
+/// assert_eq!(group.args(','), ["max(a, b)", "min(c, d)"]);
+/// assert_eq!(group.children()[1].args(','), ["c", "d"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group<'a> {
+    inner: &'a str,
+    span: Span,
+    children: Vec<Group<'a>>,
+}
+
+impl<'a> Group<'a> {
+    /// Returns the text between the delimiters.
+    pub fn inner(&self) -> &'a str {
+        self.inner
+    }
+
+    /// Returns the span of the group, delimiters included.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Returns the span of the text between the delimiters.
+    pub fn inner_span(&self) -> Span {
+        Span {
+            start: self.span.start + 1,
+            end: self.span.start + 1 + self.inner.len(),
+            ..self.span
+        }
+    }
+
+    /// Returns the groups directly nested in this one, in input order.
+    pub fn children(&self) -> &[Group<'a>] {
+        &self.children
+    }
+
+    /// Splits the inner text at every `separator` outside of nested groups,
+    /// trimming whitespace around each piece.
+    ///
+    /// An empty inner text gives no pieces.
+    pub fn args(&self, separator: char) -> Vec<&'a str> {
+        if self.inner.trim().is_empty() {
+            return Vec::new();
+        }
+
+        let offset = self.inner_span().start;
+        let mut children = self.children.iter().peekable();
+        let mut pieces = Vec::new();
+        let mut start = 0;
+
+        for (i, c) in self.inner.char_indices() {
+            while children
+                .peek()
+                .is_some_and(|child| child.span.end <= offset + i)
+            {
+                children.next();
+            }
+
+            let nested = children
+                .peek()
+                .is_some_and(|child| child.span.start <= offset + i);
+            if c == separator && !nested {
+                pieces.push(self.inner[start..i].trim());
+                start = i + c.len_utf8();
+            }
+        }
+
+        pieces.push(self.inner[start..].trim());
+        pieces
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Scans a balanced pair of delimiters, skipping leading whitespace, and
+    /// returns the text between them.
+    ///
+    /// Nested pairs of the same delimiters are part of the text.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` with the text between the outermost delimiters.
+    /// * `None` if the remaining input does not start with `open` or the
+    ///   delimiters are not balanced. Nothing is consumed then.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new(" (a (b) c) d");
+    /// assert_eq!(scanner.next_between('(', ')'), Some("a (b) c"));
+    /// assert_eq!(scanner.get_remaining(), " d");
+    /// ```
+    pub fn next_between(&mut self, open: char, close: char) -> Option<&'a str> {
+        self.next_group(open, close).map(|group| group.inner)
+    }
+
+    /// Scans a balanced pair of delimiters like [`Scanner::next_between`],
+    /// returning the offsets of every nested pair as well, so nested
+    /// arguments can be extracted without scanning the text again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{Scanner, Span};
+    /// let mut scanner = Scanner::new("[1, [2, [3]]]");
+    /// let group = scanner.next_group('[', ']').unwrap();
+    /// assert_eq!(group.children()[0].span(), Span::new(4, 12));
+    /// assert_eq!(group.children()[0].children()[0].inner(), "3");
+    /// ```
+    pub fn next_group(&mut self, open: char, close: char) -> Option<Group<'a>> {
+        let remaining = self.get_remaining();
+        let trimmed = remaining.trim_start();
+        let start = self.position + remaining.len() - trimmed.len();
+
+        if !trimmed.starts_with(open) {
+            return None;
+        }
+
+        // Open groups as `(start offset, children)`.
+        let mut stack: Vec<(usize, Vec<Group<'a>>)> = Vec::new();
+
+        for (i, c) in trimmed.char_indices() {
+            let offset = start + i;
+
+            if c == open {
+                stack.push((offset, Vec::new()));
+            } else if c == close {
+                let (group_start, children) = stack.pop()?;
+                let end = offset + c.len_utf8();
+                let group = Group {
+                    inner: &self.input[group_start + open.len_utf8()..offset],
+                    span: self.span(group_start, end),
+                    children,
+                };
+
+                match stack.last_mut() {
+                    Some((_, siblings)) => siblings.push(group),
+                    None => return self.consume(start, end, end).ok().map(|()| group),
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_between_unbalanced() {
+        let mut scanner = Scanner::new("{a {b}");
+        assert_eq!(scanner.next_between('{', '}'), None);
+        assert_eq!(scanner.get_remaining(), "{a {b}");
+        assert_eq!(scanner.next_between('(', ')'), None);
+    }
+
+    #[test]
+    fn test_next_group_call_arguments() {
+        let mut scanner = Scanner::new("f(g(x, h(y)), \"s\", ()) tail");
+        scanner.take_while(|c| c != '(');
+        let group = scanner.next_group('(', ')').unwrap();
+
+        assert_eq!(group.args(','), ["g(x, h(y))", "\"s\"", "()"]);
+        assert_eq!(group.children().len(), 2);
+        assert_eq!(group.children()[0].args(','), ["x", "h(y)"]);
+        assert_eq!(group.children()[1].args(','), Vec::<&str>::new());
+        assert_eq!(group.inner_span(), Span::new(2, 21));
+        assert_eq!(scanner.last_span(), Some(Span::new(1, 22)));
+        assert_eq!(scanner.get_remaining(), " tail");
+    }
+}
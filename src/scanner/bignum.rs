@@ -0,0 +1,86 @@
+use num_bigint::{BigInt, BigUint};
+
+use super::{ScanError, Scanner};
+
+impl Scanner<'_> {
+    /// Scans for the next integer of any size.
+    ///
+    /// Digits and signs are scanned as by [`Scanner::next_number`], so the
+    /// [`SignPolicy`](super::SignPolicy) and the underscores of the
+    /// [`NumberFormat`](super::NumberFormat) apply.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(BigInt)` if an integer is found.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("-340282366920938463463374607431768211457");
+    /// let expected: BigInt = "-340282366920938463463374607431768211457".parse().unwrap();
+    /// assert_eq!(scanner.next_bigint(), Some(expected));
+    /// ```
+    pub fn next_bigint(&mut self) -> Option<BigInt> {
+        self.try_next_bigint().ok()
+    }
+
+    /// Scans for the next integer of any size.
+    ///
+    /// See [`Scanner::next_bigint`] and [`Scanner::try_next_number`] for the
+    /// errors.
+    pub fn try_next_bigint(&mut self) -> Result<BigInt, ScanError> {
+        self.try_next_number()
+    }
+
+    /// Scans for the next non-negative integer of any size.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(BigUint)` if a non-negative integer is found.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("2 ** 521 -1");
+    /// assert_eq!(scanner.next_biguint(), Some(BigUint::from(2u8)));
+    /// ```
+    pub fn next_biguint(&mut self) -> Option<BigUint> {
+        self.try_next_biguint().ok()
+    }
+
+    /// Scans for the next non-negative integer of any size.
+    ///
+    /// See [`Scanner::next_biguint`] and [`Scanner::try_next_number`] for the
+    /// errors. A negative integer is reported as
+    /// [`ScanErrorKind::InvalidNumber`](super::ScanErrorKind::InvalidNumber).
+    pub fn try_next_biguint(&mut self) -> Result<BigUint, ScanError> {
+        self.try_next_number()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::{NumberFormat, ScanErrorKind};
+
+    #[test]
+    fn test_bigint_beyond_u128() {
+        let digits = "9".repeat(60);
+        let input = format!("{digits} -1_000 -5");
+        let mut scanner =
+            Scanner::new(&input).with_number_format(NumberFormat::PLAIN.with_underscores());
+
+        assert_eq!(scanner.next_biguint(), Some(digits.parse().unwrap()));
+        assert_eq!(scanner.next_bigint(), Some(BigInt::from(-1000)));
+
+        let error = scanner.try_next_biguint().unwrap_err();
+        assert_eq!(error.kind(), &ScanErrorKind::InvalidNumber);
+        assert_eq!(scanner.get_remaining(), " -5");
+    }
+}
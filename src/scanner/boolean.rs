@@ -0,0 +1,93 @@
+use super::{ScanError, ScanErrorKind, Scanner};
+
+/// The `(true, false)` spellings accepted by [`Scanner::next_bool`].
+pub const BOOL_SPELLINGS: &[(&str, &str)] =
+    &[("true", "false"), ("yes", "no"), ("on", "off"), ("1", "0")];
+
+impl<'a> Scanner<'a> {
+    /// Scans for the next boolean.
+    ///
+    /// The next word must be one of the [`BOOL_SPELLINGS`], compared
+    /// case-insensitively: `true`/`false`, `yes`/`no`, `on`/`off` or
+    /// `1`/`0`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(bool)` if the next word is a boolean.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("Yes off maybe");
+    /// assert_eq!(scanner.next_bool(), Some(true));
+    /// assert_eq!(scanner.next_bool(), Some(false));
+    /// assert_eq!(scanner.next_bool(), None);
+    /// assert_eq!(scanner.next_word(), Some("maybe"));
+    /// ```
+    pub fn next_bool(&mut self) -> Option<bool> {
+        self.try_next_bool_with(BOOL_SPELLINGS).ok()
+    }
+
+    /// Scans for the next boolean written with one of the given spellings.
+    ///
+    /// # Arguments
+    ///
+    /// * `spellings` - `(true, false)` pairs of accepted words, compared
+    ///   case-insensitively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("enabled 1");
+    /// let spellings = [("enabled", "disabled")];
+    /// assert_eq!(scanner.next_bool_with(&spellings), Some(true));
+    /// assert_eq!(scanner.next_bool_with(&spellings), None);
+    /// ```
+    pub fn next_bool_with(&mut self, spellings: &[(&str, &str)]) -> Option<bool> {
+        self.try_next_bool_with(spellings).ok()
+    }
+
+    /// Scans for the next boolean written with one of the given spellings.
+    ///
+    /// See [`Scanner::next_bool_with`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only whitespace remains.
+    /// * [`ScanErrorKind::InvalidFormat`] if the next word is not one of the
+    ///   spellings.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_bool_with(&mut self, spellings: &[(&str, &str)]) -> Result<bool, ScanError> {
+        self.parse_next_word(ScanErrorKind::InvalidFormat { format: "boolean" }, |word| {
+            spellings.iter().find_map(|(yes, no)| {
+                if word.eq_ignore_ascii_case(yes) {
+                    Some(true)
+                } else if word.eq_ignore_ascii_case(no) {
+                    Some(false)
+                } else {
+                    None
+                }
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_bool_spellings() {
+        let mut scanner = Scanner::new("TRUE False ON 0 1 no");
+        let values: Vec<_> = std::iter::from_fn(|| scanner.next_bool()).collect();
+        assert_eq!(values, [true, false, true, false, true, false]);
+
+        let mut scanner = Scanner::new("  2");
+        let error = scanner.try_next_bool_with(BOOL_SPELLINGS).unwrap_err();
+        assert_eq!(error.to_string(), "invalid boolean at offset 2");
+        assert_eq!(scanner.position(), 0);
+    }
+}
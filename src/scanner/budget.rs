@@ -0,0 +1,83 @@
+use super::Scanner;
+
+/// Limits on how much a [`Scanner`] may consume.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Budget {
+    /// Maximum number of tokens, lines or paragraphs to consume.
+    pub(super) tokens: usize,
+    /// Maximum byte offset the cursor may be moved to.
+    pub(super) bytes: usize,
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Budget {
+            tokens: usize::MAX,
+            bytes: usize::MAX,
+        }
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Limits how much the scanner may consume.
+    ///
+    /// Once `tokens` tokens (words, numbers, lines, ...) have been consumed, or
+    /// consuming the next one would move past the first `bytes` bytes of the
+    /// input, scanning methods stop: the `try_` methods return a
+    /// [`ScanErrorKind::BudgetExceeded`](super::ScanErrorKind::BudgetExceeded)
+    /// error and the others return `None`. Pass `usize::MAX` to leave a limit
+    /// unset.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - The maximum number of tokens to consume.
+    /// * `bytes` - The maximum number of bytes to consume.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{ScanErrorKind, Scanner};
+    /// let mut scanner = Scanner::new("1 2 3").with_budget(2, usize::MAX);
+    /// assert_eq!(scanner.next_number(), Some(1));
+    /// assert_eq!(scanner.next_number(), Some(2));
+    /// let error = scanner.try_next_number::<i32>().unwrap_err();
+    /// assert_eq!(error.kind(), &ScanErrorKind::BudgetExceeded);
+    /// ```
+    pub fn with_budget(mut self, tokens: usize, bytes: usize) -> Self {
+        self.budget = Budget { tokens, bytes };
+        self
+    }
+
+    /// Returns the number of tokens consumed so far.
+    pub fn tokens_consumed(&self) -> usize {
+        self.tokens
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scanner::{ScanErrorKind, Scanner};
+
+    #[test]
+    fn test_byte_budget() {
+        let mut scanner = Scanner::new("first line\nsecond line\n").with_budget(usize::MAX, 17);
+        assert_eq!(scanner.next_line(), Some("first line"));
+        assert_eq!(
+            scanner.try_next_line().unwrap_err().kind(),
+            &ScanErrorKind::BudgetExceeded
+        );
+        assert_eq!(scanner.get_remaining(), "second line\n");
+        assert_eq!(scanner.next_word(), Some("second"));
+        assert_eq!(scanner.next_word(), None);
+    }
+
+    #[test]
+    fn test_failed_scans_do_not_use_budget() {
+        let mut scanner = Scanner::new("a 1").with_budget(1, usize::MAX);
+        assert_eq!(scanner.next_number::<i32>(), None);
+        assert_eq!(scanner.tokens_consumed(), 0);
+        assert_eq!(scanner.next_word(), Some("a"));
+        assert_eq!(scanner.next_word(), None);
+        assert_eq!(scanner.tokens_consumed(), 1);
+    }
+}
@@ -0,0 +1,79 @@
+use super::budget::Budget;
+use super::{NewlinePolicy, NumberFormat, PointFormat, Scanner, SignPolicy};
+
+/// A reusable configuration for creating [`Scanner`]s.
+///
+/// # Examples
+///
+/// ```
+/// use scanner::scanner::{Scanner, SignPolicy};
+/// let builder = Scanner::builder()
+///     .budget(2, usize::MAX)
+///     .sign_policy(SignPolicy::Contextual);
+///
+/// let mut scanner = builder.build("1-2 3");
+/// assert_eq!(scanner.next_number(), Some(1));
+/// assert_eq!(scanner.next_number::<i32>(), None);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ScannerBuilder {
+    budget: Budget,
+    sign_policy: SignPolicy,
+    newline_policy: NewlinePolicy,
+    number_format: NumberFormat,
+    point_format: PointFormat,
+}
+
+impl ScannerBuilder {
+    /// Creates a `ScannerBuilder` with the default configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the token and byte budget. See [`Scanner::with_budget`].
+    pub fn budget(mut self, tokens: usize, bytes: usize) -> Self {
+        self.budget = Budget { tokens, bytes };
+        self
+    }
+
+    /// Sets the sign policy. See [`Scanner::with_sign_policy`].
+    pub fn sign_policy(mut self, policy: SignPolicy) -> Self {
+        self.sign_policy = policy;
+        self
+    }
+
+    /// Sets the newline policy. See [`Scanner::with_newline_policy`].
+    pub fn newline_policy(mut self, policy: NewlinePolicy) -> Self {
+        self.newline_policy = policy;
+        self
+    }
+
+    /// Sets the number format. See [`Scanner::with_number_format`].
+    pub fn number_format(mut self, format: NumberFormat) -> Self {
+        self.number_format = format;
+        self
+    }
+
+    /// Sets the point format. See [`Scanner::with_point_format`].
+    pub fn point_format(mut self, format: PointFormat) -> Self {
+        self.point_format = format;
+        self
+    }
+
+    /// Creates a new `Scanner` for `input` with this configuration.
+    pub fn build<'a>(&self, input: &'a str) -> Scanner<'a> {
+        Scanner::new(input)
+            .with_budget(self.budget.tokens, self.budget.bytes)
+            .with_sign_policy(self.sign_policy)
+            .with_newline_policy(self.newline_policy)
+            .with_number_format(self.number_format)
+            .with_point_format(self.point_format)
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Returns a [`ScannerBuilder`] to configure scanners.
+    pub fn builder() -> ScannerBuilder {
+        ScannerBuilder::new()
+    }
+}
@@ -0,0 +1,181 @@
+use std::str;
+
+use super::{ScanError, ScanErrorKind, Scanner};
+
+/// A scanner over raw bytes that may contain malformed UTF-8.
+///
+/// Instead of refusing the whole input, a `ByteScanner` hands out the valid
+/// UTF-8 parts as `&str` and reports every malformed sequence as a
+/// [`ScanErrorKind::InvalidUtf8`] error carrying its byte offset and bytes.
+/// Malformed parts are skipped once reported, so scanning can continue.
+///
+/// # Examples
+///
+/// ```
+/// use scanner::scanner::{ByteScanner, ScanErrorKind};
+/// let mut bytes = ByteScanner::new(b"ok 1\nbad \xff\nok 2\n");
+/// assert_eq!(bytes.next_line(), Some(Ok("ok 1")));
+///
+/// let error = bytes.next_line().unwrap().unwrap_err();
+/// assert_eq!(error.position(), 9);
+/// assert_eq!(error.kind(), &ScanErrorKind::InvalidUtf8 { bytes: vec![0xff] });
+///
+/// assert_eq!(bytes.next_line(), Some(Ok("ok 2")));
+/// assert_eq!(bytes.next_line(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ByteScanner<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> ByteScanner<'a> {
+    /// Creates a new `ByteScanner` for the given bytes.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        ByteScanner { bytes, position: 0 }
+    }
+
+    /// Returns the byte offset of the cursor.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the bytes not scanned yet.
+    pub fn get_remaining(&self) -> &'a [u8] {
+        &self.bytes[self.position..]
+    }
+
+    /// Scans the next chunk of the input: either the longest valid UTF-8
+    /// prefix of the remaining bytes, or the malformed sequence at the cursor.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Ok(&str))` with a valid chunk.
+    /// * `Some(Err(ScanError))` for a malformed sequence, which is skipped.
+    /// * `None` at the end of the input.
+    pub fn next_chunk(&mut self) -> Option<Result<&'a str, ScanError>> {
+        let remaining = self.get_remaining();
+        if remaining.is_empty() {
+            return None;
+        }
+
+        let (valid, error) = match str::from_utf8(remaining) {
+            Ok(valid) => (valid, None),
+            Err(error) => {
+                let (valid, _) = remaining.split_at(error.valid_up_to());
+                (str::from_utf8(valid).unwrap_or_default(), Some(error))
+            }
+        };
+
+        if !valid.is_empty() {
+            self.position += valid.len();
+            return Some(Ok(valid));
+        }
+
+        let len = error
+            .and_then(|error| error.error_len())
+            .unwrap_or(remaining.len());
+        let error = self.invalid(self.position, &remaining[..len]);
+        self.position += len;
+        Some(Err(error))
+    }
+
+    /// Scans the next line, terminated by `\n` or the end of the input.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Ok(&str))` with the line, without its trailing whitespace.
+    /// * `Some(Err(ScanError))` for the first malformed sequence of the line.
+    ///   The whole line is skipped.
+    /// * `None` at the end of the input.
+    pub fn next_line(&mut self) -> Option<Result<&'a str, ScanError>> {
+        let remaining = self.get_remaining();
+        if remaining.is_empty() {
+            return None;
+        }
+
+        let (line, consumed) = match remaining.iter().position(|&b| b == b'\n') {
+            Some(newline) => (&remaining[..newline], newline + 1),
+            None => (remaining, remaining.len()),
+        };
+
+        let start = self.position;
+        self.position += consumed;
+
+        Some(match str::from_utf8(line) {
+            Ok(line) => Ok(line.trim_end()),
+            Err(error) => {
+                let offset = error.valid_up_to();
+                let len = error.error_len().unwrap_or(line.len() - offset);
+                Err(self.invalid(start + offset, &line[offset..offset + len]))
+            }
+        })
+    }
+
+    /// Scans the next line and returns a new `Scanner` over it.
+    ///
+    /// See [`ByteScanner::next_line`].
+    pub fn scan_line(&mut self) -> Option<Result<Scanner<'a>, ScanError>> {
+        self.next_line().map(|line| line.map(Scanner::new))
+    }
+
+    fn invalid(&self, offset: usize, bytes: &[u8]) -> ScanError {
+        ScanError::new(
+            ScanErrorKind::InvalidUtf8 {
+                bytes: bytes.to_vec(),
+            },
+            offset,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_chunk() {
+        let mut bytes = ByteScanner::new(b"abc\xe2\x82\xffdef\xf0\x9f");
+        assert_eq!(bytes.next_chunk(), Some(Ok("abc")));
+
+        let error = bytes.next_chunk().unwrap().unwrap_err();
+        assert_eq!(error.position(), 3);
+        assert_eq!(
+            error.kind(),
+            &ScanErrorKind::InvalidUtf8 {
+                bytes: vec![0xe2, 0x82]
+            }
+        );
+
+        let error = bytes.next_chunk().unwrap().unwrap_err();
+        assert_eq!(error.position(), 5);
+        assert_eq!(bytes.next_chunk(), Some(Ok("def")));
+
+        let error = bytes.next_chunk().unwrap().unwrap_err();
+        assert_eq!(
+            error.kind(),
+            &ScanErrorKind::InvalidUtf8 {
+                bytes: vec![0xf0, 0x9f]
+            }
+        );
+        assert_eq!(bytes.next_chunk(), None);
+    }
+
+    #[test]
+    fn test_scan_line_skips_corrupt_lines() {
+        let mut bytes = ByteScanner::new(b"1 2\n\xc03\n4\n");
+        let mut sum = 0;
+        let mut errors = 0;
+        while let Some(line) = bytes.scan_line() {
+            match line {
+                Ok(mut line) => {
+                    while let Some(n) = line.next_number::<i32>() {
+                        sum += n;
+                    }
+                }
+                Err(_) => errors += 1,
+            }
+        }
+        assert_eq!((sum, errors), (7, 1));
+    }
+}
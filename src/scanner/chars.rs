@@ -0,0 +1,224 @@
+use super::Scanner;
+
+/// An iterator over the characters at the cursor of a [`Scanner`], with
+/// their byte offsets in the input.
+///
+/// Created by [`Scanner::char_indices`]. Iterating does not move the
+/// scanner's cursor unless [`commit`](CharIndices::commit) is called, in which
+/// case every character yielded so far is consumed when the iterator is
+/// dropped.
+pub struct CharIndices<'s, 'a> {
+    scanner: &'s mut Scanner<'a>,
+    /// Bytes yielded so far, relative to the cursor.
+    offset: usize,
+    commit: bool,
+}
+
+impl<'a> CharIndices<'_, 'a> {
+    /// Consumes the characters yielded so far, and those yielded later, when
+    /// the iterator is dropped.
+    pub fn commit(&mut self) {
+        self.commit = true;
+    }
+
+    /// Discards the progress of the iterator instead of committing it.
+    pub fn rollback(&mut self) {
+        self.commit = false;
+    }
+
+    /// Returns the part of the input not yet yielded by the iterator.
+    pub fn as_str(&self) -> &'a str {
+        &self.scanner.get_remaining()[self.offset..]
+    }
+
+    /// Returns the part of the input yielded so far.
+    pub fn yielded(&self) -> &'a str {
+        &self.scanner.get_remaining()[..self.offset]
+    }
+}
+
+impl Iterator for CharIndices<'_, '_> {
+    type Item = (usize, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.as_str().chars().next()?;
+        let offset = self.scanner.position + self.offset;
+        self.offset += c.len_utf8();
+        Some((offset, c))
+    }
+}
+
+impl Drop for CharIndices<'_, '_> {
+    fn drop(&mut self) {
+        if self.commit {
+            self.scanner.take(self.offset);
+        }
+    }
+}
+
+/// An iterator over the characters at the cursor of a [`Scanner`].
+///
+/// Created by [`Scanner::chars`]. See [`CharIndices`] for how progress is
+/// committed back to the scanner.
+pub struct Chars<'s, 'a>(CharIndices<'s, 'a>);
+
+impl<'a> Chars<'_, 'a> {
+    /// See [`CharIndices::commit`].
+    pub fn commit(&mut self) {
+        self.0.commit();
+    }
+
+    /// See [`CharIndices::rollback`].
+    pub fn rollback(&mut self) {
+        self.0.rollback();
+    }
+
+    /// Returns the part of the input not yet yielded by the iterator.
+    pub fn as_str(&self) -> &'a str {
+        self.0.as_str()
+    }
+
+    /// Returns the part of the input yielded so far.
+    pub fn yielded(&self) -> &'a str {
+        self.0.yielded()
+    }
+}
+
+impl Iterator for Chars<'_, '_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, c)| c)
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Returns an iterator over the characters at the cursor.
+    ///
+    /// The cursor only moves if the iterator's progress is committed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("0x1F rest");
+    ///
+    /// let mut chars = scanner.chars();
+    /// assert_eq!(chars.next(), Some('0'));
+    /// drop(chars);
+    /// assert_eq!(scanner.get_remaining(), "0x1F rest");
+    ///
+    /// let mut chars = scanner.chars();
+    /// chars.by_ref().take(4).for_each(drop);
+    /// chars.commit();
+    /// drop(chars);
+    /// assert_eq!(scanner.get_remaining(), " rest");
+    /// ```
+    pub fn chars(&mut self) -> Chars<'_, 'a> {
+        Chars(self.char_indices())
+    }
+
+    /// Returns an iterator over the characters at the cursor and their byte
+    /// offsets in the input.
+    ///
+    /// The cursor only moves if the iterator's progress is committed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("ab");
+    /// scanner.next_token(|c, _| c == 'a');
+    /// assert_eq!(scanner.char_indices().next(), Some((1, 'b')));
+    /// ```
+    pub fn char_indices(&mut self) -> CharIndices<'_, 'a> {
+        CharIndices {
+            scanner: self,
+            offset: 0,
+            commit: false,
+        }
+    }
+
+    /// Skips whitespace and consumes exactly one character.
+    ///
+    /// To consume the very next character, whitespace included, use
+    /// [`Scanner::advance_chars`] with `1`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(char)` with the consumed character.
+    /// * `None` if only whitespace remains or the budget is used up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new(" (ab)");
+    /// assert_eq!(scanner.peek_char(), Some('('));
+    /// assert_eq!(scanner.next_char(), Some('('));
+    /// assert_eq!(scanner.next_char(), Some('a'));
+    /// assert_eq!(scanner.get_remaining(), "b)");
+    /// ```
+    pub fn next_char(&mut self) -> Option<char> {
+        let remaining = self.get_remaining();
+        let (offset, c) = remaining.char_indices().find(|(_, c)| !c.is_whitespace())?;
+
+        let start = self.position + offset;
+        let end = start + c.len_utf8();
+        self.consume(start, end, end).ok()?;
+        Some(c)
+    }
+
+    /// Returns the character [`Scanner::next_char`] would consume, without
+    /// consuming anything.
+    pub fn peek_char(&self) -> Option<char> {
+        self.get_remaining().chars().find(|c| !c.is_whitespace())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_token_rule() {
+        let mut scanner = Scanner::new("«quoted» tail");
+
+        {
+            let mut chars = scanner.char_indices();
+            assert_eq!(chars.next(), Some((0, '«')));
+            for (_, c) in chars.by_ref() {
+                if c == '»' {
+                    break;
+                }
+            }
+            assert_eq!(chars.yielded(), "«quoted»");
+            chars.commit();
+        }
+
+        assert_eq!(scanner.get_remaining(), " tail");
+        assert_eq!(scanner.last_span().unwrap().range(), 0..10);
+    }
+
+    #[test]
+    fn test_next_char_span_and_end() {
+        let mut scanner = Scanner::new("\t€ \n");
+        assert_eq!(scanner.next_char(), Some('€'));
+        assert_eq!(scanner.last_span().unwrap().range(), 1..4);
+        assert_eq!(scanner.peek_char(), None);
+        assert_eq!(scanner.next_char(), None);
+        assert_eq!(scanner.get_remaining(), " \n");
+    }
+
+    #[test]
+    fn test_rollback() {
+        let mut scanner = Scanner::new("abc");
+        let mut chars = scanner.chars();
+        chars.next();
+        chars.commit();
+        chars.rollback();
+        assert_eq!(chars.as_str(), "bc");
+        drop(chars);
+        assert_eq!(scanner.get_remaining(), "abc");
+    }
+}
@@ -0,0 +1,134 @@
+use std::str::FromStr;
+
+use super::Scanner;
+
+/// A scanner over text arriving in chunks, such as rope pieces, channel
+/// messages or lines read from stdin.
+///
+/// Chunks are pulled from the iterator only when the next token or line is
+/// not complete yet, and only the text of the unfinished token is kept
+/// between calls, so the whole input is never concatenated. Tokens may span
+/// chunk boundaries.
+///
+/// # Examples
+///
+/// ```
+/// use scanner::scanner::ChunkScanner;
+/// let mut scanner = ChunkScanner::new(["12", "3 hel", "lo\nwor", "ld"]);
+/// assert_eq!(scanner.next_number(), Some(123));
+/// assert_eq!(scanner.next_word(), Some("hello"));
+/// assert_eq!(scanner.next_line(), Some(""));
+/// assert_eq!(scanner.next_line(), Some("world"));
+/// assert_eq!(scanner.next_line(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChunkScanner<I> {
+    chunks: I,
+    buffer: String,
+    /// Offset of the cursor in `buffer`.
+    cursor: usize,
+    /// Offset of `buffer` in the whole input.
+    offset: usize,
+    exhausted: bool,
+}
+
+impl<I> ChunkScanner<I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    /// Creates a new `ChunkScanner` over the given chunks.
+    pub fn new(chunks: impl IntoIterator<IntoIter = I>) -> Self {
+        ChunkScanner {
+            chunks: chunks.into_iter(),
+            buffer: String::new(),
+            cursor: 0,
+            offset: 0,
+            exhausted: false,
+        }
+    }
+
+    /// Returns the byte offset of the cursor in the whole input.
+    pub fn position(&self) -> usize {
+        self.offset + self.cursor
+    }
+
+    /// Scans for the next word, which may span several chunks.
+    ///
+    /// See [`Scanner::next_word`].
+    pub fn next_word(&mut self) -> Option<&str> {
+        self.fill(|rest| {
+            let word = rest.trim_start();
+            !word.is_empty() && word.contains(char::is_whitespace)
+        });
+        let base = self.cursor;
+        let span = self.scan(|scanner| scanner.next_word().and(scanner.last_span()))?;
+        Some(&self.buffer[base + span.start..base + span.end])
+    }
+
+    /// Scans for the next number, which may span several chunks.
+    ///
+    /// See [`Scanner::next_number`].
+    pub fn next_number<T>(&mut self) -> Option<T>
+    where
+        T: FromStr,
+    {
+        self.fill(|rest| {
+            let number = rest.trim_start();
+            !number.is_empty() && number.contains(char::is_whitespace)
+        });
+        self.scan(|scanner| scanner.next_number())
+    }
+
+    /// Scans for the next line, which may span several chunks.
+    ///
+    /// See [`Scanner::next_line`].
+    pub fn next_line(&mut self) -> Option<&str> {
+        self.fill(|rest| rest.contains('\n'));
+        let base = self.cursor;
+        let span = self.scan(|scanner| scanner.next_line().and(scanner.last_span()))?;
+        Some(&self.buffer[base + span.start..base + span.end])
+    }
+
+    /// Pulls chunks until `complete` holds for the unscanned text or the
+    /// chunks run out. Scanned text is dropped before a chunk is added.
+    fn fill(&mut self, complete: impl Fn(&str) -> bool) {
+        while !complete(&self.buffer[self.cursor..]) && !self.exhausted {
+            match self.chunks.next() {
+                Some(chunk) => {
+                    self.buffer.drain(..self.cursor);
+                    self.offset += self.cursor;
+                    self.cursor = 0;
+                    self.buffer.push_str(chunk.as_ref());
+                }
+                None => self.exhausted = true,
+            }
+        }
+    }
+
+    /// Runs `f` on a `Scanner` over the unscanned text and advances the
+    /// cursor past what it consumed.
+    fn scan<T>(&mut self, f: impl FnOnce(&mut Scanner<'_>) -> Option<T>) -> Option<T> {
+        let mut scanner = Scanner::new(&self.buffer[self.cursor..]);
+        let value = f(&mut scanner)?;
+        self.cursor += scanner.position();
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens_span_chunks() {
+        let lines = "first li\nne\n\n 4".chars().map(String::from);
+        let mut scanner = ChunkScanner::new(lines);
+        assert_eq!(scanner.next_line(), Some("first li"));
+        assert_eq!(scanner.next_word(), Some("ne"));
+        assert_eq!(scanner.position(), 11);
+        assert_eq!(scanner.next_number::<u8>(), Some(4));
+        assert_eq!(scanner.next_word(), None);
+        assert_eq!(scanner.position(), 15);
+    }
+}
@@ -0,0 +1,183 @@
+use super::{ScanError, ScanErrorKind, Scanner};
+
+/// An access log record in the Common or Combined Log Format, as written by
+/// Apache and Nginx.
+///
+/// Fields logged as `-` are `None`. Quoted fields are kept as written,
+/// without their quotes and with any escapes left in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClfRecord<'a> {
+    /// The address or name of the client.
+    pub host: &'a str,
+    /// The RFC 1413 identity of the client.
+    pub ident: Option<&'a str>,
+    /// The authenticated user.
+    pub user: Option<&'a str>,
+    /// The time of the request, such as `10/Oct/2000:13:55:36 -0700`.
+    pub timestamp: &'a str,
+    /// The request line, such as `GET /index.html HTTP/1.0`.
+    pub request: &'a str,
+    /// The status code of the response.
+    pub status: u16,
+    /// The size of the response body.
+    pub bytes: Option<u64>,
+    /// The `Referer` header, in the Combined Log Format.
+    pub referer: Option<&'a str>,
+    /// The `User-Agent` header, in the Combined Log Format.
+    pub user_agent: Option<&'a str>,
+}
+
+/// Returns `None` for a field logged as `-`.
+fn field(text: &str) -> Option<&str> {
+    (text != "-").then_some(text)
+}
+
+/// Splits the next space-delimited field off `text`.
+fn word(text: &str) -> Option<(&str, &str)> {
+    let text = text.trim_start_matches(' ');
+    let end = text.find(' ').unwrap_or(text.len());
+    (end > 0).then(|| (&text[..end], &text[end..]))
+}
+
+/// Splits the next field enclosed in `open` and `close` off `text`,
+/// skipping characters escaped with a backslash.
+fn enclosed(text: &str, open: char, close: char) -> Option<(&str, &str)> {
+    let text = text.trim_start_matches(' ').strip_prefix(open)?;
+    let mut escaped = false;
+
+    for (i, c) in text.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            c if c == close => return Some((&text[..i], &text[i + 1..])),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+impl<'a> ClfRecord<'a> {
+    /// Parses a whole log line.
+    fn parse(line: &'a str) -> Option<ClfRecord<'a>> {
+        let (host, rest) = word(line)?;
+        let (ident, rest) = word(rest)?;
+        let (user, rest) = word(rest)?;
+        let (timestamp, rest) = enclosed(rest, '[', ']')?;
+        let (request, rest) = enclosed(rest, '"', '"')?;
+        let (status, rest) = word(rest)?;
+        let (bytes, rest) = word(rest)?;
+
+        let (referer, user_agent, rest) = match enclosed(rest, '"', '"') {
+            Some((referer, rest)) => {
+                let (user_agent, rest) = enclosed(rest, '"', '"')?;
+                (field(referer), field(user_agent), rest)
+            }
+            None => (None, None, rest),
+        };
+
+        if !rest.trim().is_empty() || !status.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        Some(ClfRecord {
+            host,
+            ident: field(ident),
+            user: field(user),
+            timestamp,
+            request,
+            status: status.parse().ok()?,
+            bytes: match field(bytes) {
+                Some(bytes) => Some(bytes.parse().ok()?),
+                None => None,
+            },
+            referer,
+            user_agent,
+        })
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Scans the next line as an access log record in the Common or
+    /// Combined Log Format.
+    ///
+    /// The bracketed timestamp and the quoted request, referer and user
+    /// agent may contain spaces; quotes escaped with a backslash do not end
+    /// a quoted field. The referer and user agent of the Combined Log Format
+    /// are optional.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(ClfRecord)` for a valid record.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new(concat!(
+    ///     "127.0.0.1 - frank [10/Oct/2000:13:55:36 -0700] \"GET /a.gif HTTP/1.0\" 200 2326 ",
+    ///     "\"http://example.com/\" \"Mozilla/4.08 [en] (Win98; I ;Nav)\"\n",
+    /// ));
+    /// let record = scanner.next_clf_record().unwrap();
+    /// assert_eq!(record.host, "127.0.0.1");
+    /// assert_eq!(record.user, Some("frank"));
+    /// assert_eq!(record.timestamp, "10/Oct/2000:13:55:36 -0700");
+    /// assert_eq!(record.request, "GET /a.gif HTTP/1.0");
+    /// assert_eq!((record.status, record.bytes), (200, Some(2326)));
+    /// assert_eq!(record.user_agent, Some("Mozilla/4.08 [en] (Win98; I ;Nav)"));
+    /// ```
+    pub fn next_clf_record(&mut self) -> Option<ClfRecord<'a>> {
+        self.try_next_clf_record().ok()
+    }
+
+    /// Scans the next line as an access log record.
+    ///
+    /// See [`Scanner::next_clf_record`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if no input remains.
+    /// * [`ScanErrorKind::InvalidFormat`] if the next line is not a valid
+    ///   record.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_clf_record(&mut self) -> Result<ClfRecord<'a>, ScanError> {
+        let checkpoint = self.checkpoint();
+        let start = self.position;
+        let line = self.try_next_line()?;
+
+        ClfRecord::parse(line).ok_or_else(|| {
+            self.restore(checkpoint);
+            let format = "access log record";
+            ScanError::new(ScanErrorKind::InvalidFormat { format }, start)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_common_log_format() {
+        let record =
+            ClfRecord::parse(r#"::1 - - [01/Jan/2024:00:00:00 +0000] "GET /\"q\" HTTP/1.1" 304 -"#)
+                .unwrap();
+        assert_eq!(
+            (record.ident, record.user, record.bytes),
+            (None, None, None)
+        );
+        assert_eq!(record.request, r#"GET /\"q\" HTTP/1.1"#);
+        assert_eq!(record.referer, None);
+
+        for invalid in [
+            r#"h - - [t] "r" 200"#,
+            r#"h - - [t "r" 200 1"#,
+            r#"h - - [t] "r" 2x0 1"#,
+            r#"h - - [t] "r" 200 1 "ref""#,
+            r#"h - - [t] "r" 200 1 trailing"#,
+        ] {
+            assert_eq!(ClfRecord::parse(invalid), None, "{invalid}");
+        }
+    }
+}
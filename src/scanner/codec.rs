@@ -0,0 +1,164 @@
+use super::{ScanError, ScanErrorKind, Scanner};
+
+/// Decodes a whole hexadecimal string of either case, two digits per byte.
+fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    if text.is_empty() || !text.len().is_multiple_of(2) {
+        return None;
+    }
+
+    text.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            let high = char::from(pair[0]).to_digit(16)?;
+            let low = char::from(pair[1]).to_digit(16)?;
+            Some((high * 16 + low) as u8)
+        })
+        .collect()
+}
+
+/// Decodes a whole padded Base64 string of the standard alphabet.
+fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    fn sextet(byte: u8) -> Option<u32> {
+        let value = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+        Some(u32::from(value))
+    }
+
+    if text.is_empty() || !text.len().is_multiple_of(4) {
+        return None;
+    }
+
+    let data = text.trim_end_matches('=');
+    let padding = text.len() - data.len();
+    if padding > 2 {
+        return None;
+    }
+
+    let mut bytes = Vec::with_capacity(data.len() * 3 / 4);
+    for chunk in data.as_bytes().chunks(4) {
+        let mut bits = 0;
+        for (i, &byte) in chunk.iter().enumerate() {
+            bits |= sextet(byte)? << (18 - 6 * i);
+        }
+        let decoded = [(bits >> 16) as u8, (bits >> 8) as u8, bits as u8];
+        bytes.extend_from_slice(&decoded[..chunk.len() - 1]);
+    }
+
+    Some(bytes)
+}
+
+impl Scanner<'_> {
+    /// Scans for the next hexadecimal token, such as `deadbeef`, and decodes
+    /// it.
+    ///
+    /// The token is the next whitespace-delimited word, made of an even
+    /// number of hexadecimal digits of either case.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Vec<u8>)` with the decoded bytes.
+    /// * `None` if the next word is not valid hexadecimal, in which case
+    ///   nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("key DEADbeef abc");
+    /// scanner.next_word();
+    /// assert_eq!(scanner.next_hex_bytes(), Some(vec![0xde, 0xad, 0xbe, 0xef]));
+    /// assert_eq!(scanner.next_hex_bytes(), None);
+    /// assert_eq!(scanner.get_remaining(), " abc");
+    /// ```
+    pub fn next_hex_bytes(&mut self) -> Option<Vec<u8>> {
+        self.try_next_hex_bytes().ok()
+    }
+
+    /// Scans for the next hexadecimal token and decodes it.
+    ///
+    /// See [`Scanner::next_hex_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only whitespace remains.
+    /// * [`ScanErrorKind::InvalidFormat`] if the next word is not valid
+    ///   hexadecimal.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_hex_bytes(&mut self) -> Result<Vec<u8>, ScanError> {
+        self.parse_next_word(ScanErrorKind::InvalidFormat { format: "hex" }, decode_hex)
+    }
+
+    /// Scans for the next Base64 token and decodes it.
+    ///
+    /// The token is the next whitespace-delimited word, in the standard
+    /// alphabet of [RFC 4648](https://www.rfc-editor.org/rfc/rfc4648) and
+    /// padded with `=` to a multiple of four characters.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Vec<u8>)` with the decoded bytes.
+    /// * `None` if the next word is not valid Base64, in which case nothing
+    ///   is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("aGVsbG8= aGk");
+    /// assert_eq!(scanner.next_base64(), Some(b"hello".to_vec()));
+    /// assert_eq!(scanner.next_base64(), None);
+    /// assert_eq!(scanner.get_remaining(), " aGk");
+    /// ```
+    pub fn next_base64(&mut self) -> Option<Vec<u8>> {
+        self.try_next_base64().ok()
+    }
+
+    /// Scans for the next Base64 token and decodes it.
+    ///
+    /// See [`Scanner::next_base64`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only whitespace remains.
+    /// * [`ScanErrorKind::InvalidFormat`] if the next word is not valid
+    ///   Base64.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_base64(&mut self) -> Result<Vec<u8>, ScanError> {
+        self.parse_next_word(
+            ScanErrorKind::InvalidFormat { format: "Base64" },
+            decode_base64,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_base64() {
+        assert_eq!(decode_base64("TWFu"), Some(b"Man".to_vec()));
+        assert_eq!(decode_base64("TWE="), Some(b"Ma".to_vec()));
+        assert_eq!(decode_base64("TQ=="), Some(b"M".to_vec()));
+        assert_eq!(decode_base64("+/+/"), Some(vec![0xfb, 0xff, 0xbf]));
+
+        for invalid in ["", "TQ", "T===", "TQ=A", "TW-u", "TWFu===="] {
+            assert_eq!(decode_base64(invalid), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_hex_error() {
+        let mut scanner = Scanner::new(" abc 0g");
+        let error = scanner.try_next_hex_bytes().unwrap_err();
+        assert_eq!(error.to_string(), "invalid hex at offset 1");
+        assert_eq!(decode_hex("0g"), None);
+        assert_eq!(decode_hex("00ff"), Some(vec![0, 255]));
+    }
+}
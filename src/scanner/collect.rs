@@ -0,0 +1,85 @@
+use std::any;
+use std::str::FromStr;
+
+use super::{ScanError, ScanErrorKind, Scanner};
+
+impl Scanner<'_> {
+    /// Parses the remaining words as `T` for as long as they parse.
+    ///
+    /// # Returns
+    ///
+    /// The parsed values. Scanning stops before the first word that is not a
+    /// valid `T`, which is left in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("1.5 -2 3e2 end");
+    /// assert_eq!(scanner.collect_remaining::<f64>(), [1.5, -2.0, 300.0]);
+    /// assert_eq!(scanner.next_word(), Some("end"));
+    /// ```
+    pub fn collect_remaining<T>(&mut self) -> Vec<T>
+    where
+        T: FromStr,
+    {
+        std::iter::from_fn(|| self.next_map(|word| word.parse().ok())).collect()
+    }
+
+    /// Parses every remaining word as `T`.
+    ///
+    /// This is the `Result` returning counterpart of
+    /// [`Scanner::collect_remaining`]. On error, nothing is consumed.
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::InvalidFormat`] naming `T`, at the first word that
+    ///   is not a valid `T`.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up
+    ///   before the end of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("4 8 15\n16 23 42\n");
+    /// assert_eq!(scanner.try_collect_remaining::<u8>(), Ok(vec![4, 8, 15, 16, 23, 42]));
+    ///
+    /// let mut scanner = Scanner::new("1 2 x");
+    /// let error = scanner.try_collect_remaining::<u8>().unwrap_err();
+    /// assert_eq!(error.to_string(), "invalid u8 at offset 4");
+    /// assert_eq!(scanner.position(), 0);
+    /// ```
+    pub fn try_collect_remaining<T>(&mut self) -> Result<Vec<T>, ScanError>
+    where
+        T: FromStr,
+    {
+        let kind = ScanErrorKind::InvalidFormat {
+            format: any::type_name::<T>(),
+        };
+
+        self.try_attempt(|scanner| {
+            let mut values = Vec::new();
+            loop {
+                match scanner.parse_next_word(kind.clone(), |word| word.parse().ok()) {
+                    Ok(value) => values.push(value),
+                    Err(error) if *error.kind() == ScanErrorKind::EndOfInput => return Ok(values),
+                    Err(error) => return Err(error),
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_collect_remaining_budget() {
+        let mut scanner = Scanner::new("1 2 3").with_budget(2, usize::MAX);
+        let error = scanner.try_collect_remaining::<i32>().unwrap_err();
+        assert_eq!(*error.kind(), ScanErrorKind::BudgetExceeded);
+        assert_eq!(scanner.collect_remaining::<i32>(), [1, 2]);
+    }
+}
@@ -0,0 +1,203 @@
+use std::ops::Range;
+
+use super::Scanner;
+
+/// An iterator splitting the remaining lines of a [`Scanner`] into
+/// fixed-width columns.
+///
+/// Created by [`Scanner::columns`].
+pub struct Columns<'s, 'a> {
+    scanner: &'s mut Scanner<'a>,
+    ranges: Vec<Range<usize>>,
+}
+
+impl<'a> Iterator for Columns<'_, 'a> {
+    type Item = Vec<&'a str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.scanner.next_line_columns(&self.ranges)
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Consumes exactly the next `width` characters, whitespace included.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` with the `width` characters.
+    /// * `None` if fewer than `width` characters remain, in which case
+    ///   nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("ACME    0042");
+    /// assert_eq!(scanner.next_fixed(8), Some("ACME    "));
+    /// assert_eq!(scanner.next_fixed(5), None);
+    /// assert_eq!(scanner.next_fixed(4), Some("0042"));
+    /// ```
+    pub fn next_fixed(&mut self, width: usize) -> Option<&'a str> {
+        let remaining = self.get_remaining();
+        let mut boundaries = remaining.char_indices().map(|(i, _)| i);
+        let len = match boundaries.nth(width) {
+            Some(len) => len,
+            None if remaining.chars().count() == width => remaining.len(),
+            None => return None,
+        };
+
+        let start = self.position;
+        self.consume(start, start + len, start + len).ok()?;
+        Some(&remaining[..len])
+    }
+
+    /// Returns an iterator splitting each remaining line into columns of
+    /// the given widths in characters, as in mainframe-style and
+    /// `printf`-aligned reports.
+    ///
+    /// Each line is split as by [`Scanner::next_line_columns`], with the
+    /// columns laid out one after the other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("ID      NAME        QTY\n00000001widget      12\n");
+    /// let rows: Vec<_> = scanner.columns(&[8, 12, 3]).collect();
+    /// assert_eq!(rows[1], ["00000001", "widget      ", "12"]);
+    /// ```
+    pub fn columns(&mut self, widths: &[usize]) -> Columns<'_, 'a> {
+        let ranges = widths
+            .iter()
+            .scan(0, |start, &width| {
+                let range = *start..*start + width;
+                *start += width;
+                Some(range)
+            })
+            .collect();
+
+        Columns {
+            scanner: self,
+            ranges,
+        }
+    }
+
+    /// Consumes the next line and splits it into its tab-separated fields.
+    ///
+    /// Unlike [`Scanner::next_line`], trailing whitespace is kept, so that
+    /// empty trailing fields are not lost. A `\r` before the newline is
+    /// dropped.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Vec<&str>)` with the fields of the line.
+    /// * `None` if no input remains.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("a\tb c\t\r\nd");
+    /// assert_eq!(scanner.next_tsv_record(), Some(vec!["a", "b c", ""]));
+    /// assert_eq!(scanner.next_tsv_record(), Some(vec!["d"]));
+    /// assert_eq!(scanner.next_tsv_record(), None);
+    /// ```
+    pub fn next_tsv_record(&mut self) -> Option<Vec<&'a str>> {
+        let remaining = self.get_remaining();
+
+        if remaining.is_empty() {
+            return None;
+        }
+
+        let (line, consumed) = match self.newline_policy.find(remaining) {
+            Some((newline, len)) => (&remaining[..newline], newline + len),
+            None => (remaining, remaining.len()),
+        };
+        let line = line.strip_suffix('\r').unwrap_or(line);
+
+        let start = self.position;
+        self.consume(start, start + line.len(), start + consumed)
+            .ok()?;
+        Some(line.split('\t').collect())
+    }
+
+    /// Consumes the next line and returns the given character ranges of it,
+    /// like `cut -c`.
+    ///
+    /// Ranges count characters, not bytes, so multibyte characters are
+    /// never split. Ranges reaching past the end of the line are clamped to
+    /// it, and ranges starting past it give an empty string.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - The character ranges to extract, in any order.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Vec<&str>)` with one slice per range, in the order of `columns`.
+    /// * `None` if no line is found (i.e., end of input).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("2024-01-02 café   12\nshort\n");
+    /// assert_eq!(
+    ///     scanner.next_line_columns(&[0..10, 11..15, 18..20]),
+    ///     Some(vec!["2024-01-02", "café", "12"])
+    /// );
+    /// assert_eq!(
+    ///     scanner.next_line_columns(&[0..10, 11..15]),
+    ///     Some(vec!["short", ""])
+    /// );
+    /// ```
+    pub fn next_line_columns(&mut self, columns: &[Range<usize>]) -> Option<Vec<&'a str>> {
+        let line = self.next_line()?;
+        let boundaries: Vec<usize> = line
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain(std::iter::once(line.len()))
+            .collect();
+        let byte_at = |n: usize| boundaries.get(n).copied().unwrap_or(line.len());
+
+        Some(
+            columns
+                .iter()
+                .map(|range| {
+                    let start = byte_at(range.start);
+                    &line[start..byte_at(range.end).max(start)]
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_columns_clamping() {
+        let mut scanner = Scanner::new("αβγδ");
+        let reversed = Range { start: 3, end: 1 };
+        assert_eq!(
+            scanner.next_line_columns(&[2..3, 3..100, 10..12, reversed, 0..0]),
+            Some(vec!["γ", "δ", "", "", ""])
+        );
+        assert_eq!(scanner.next_line_columns(&[0..1, 1..2]), None);
+    }
+
+    #[test]
+    fn test_fixed_width_multibyte() {
+        let mut scanner = Scanner::new("éa\nb");
+        assert_eq!(scanner.next_fixed(0), Some(""));
+        assert_eq!(scanner.next_fixed(3), Some("éa\n"));
+        assert_eq!(scanner.last_span().unwrap().range(), 0..4);
+        assert_eq!(scanner.next_fixed(1), Some("b"));
+        assert_eq!(scanner.next_fixed(1), None);
+
+        let mut scanner = Scanner::new("abcdef\nxy");
+        let rows: Vec<_> = scanner.columns(&[2, 0, 3]).collect();
+        assert_eq!(rows, [["ab", "", "cde"], ["xy", "", ""]]);
+    }
+}
@@ -0,0 +1,102 @@
+use num_complex::Complex;
+
+use super::{ScanError, ScanErrorKind, Scanner};
+
+impl Scanner<'_> {
+    /// Scans for the next complex number, such as `3+4i`, `-2.5i` or `1-1j`.
+    ///
+    /// Leading whitespace is skipped. The real part, the imaginary part or
+    /// both may be given, with `i` or `j` marking the imaginary part. Each
+    /// part is read as by [`Scanner::next_float`], so the
+    /// [`NumberFormat`](super::NumberFormat) and
+    /// [`SignPolicy`](super::SignPolicy) apply.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Complex<f64>)` if a complex number is found.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex;
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("3+4i -2.5i 1-1j 7");
+    /// assert_eq!(scanner.next_complex(), Some(Complex::new(3.0, 4.0)));
+    /// assert_eq!(scanner.next_complex(), Some(Complex::new(0.0, -2.5)));
+    /// assert_eq!(scanner.next_complex(), Some(Complex::new(1.0, -1.0)));
+    /// assert_eq!(scanner.next_complex(), Some(Complex::new(7.0, 0.0)));
+    /// ```
+    pub fn next_complex(&mut self) -> Option<Complex<f64>> {
+        self.try_next_complex().ok()
+    }
+
+    /// Scans for the next complex number.
+    ///
+    /// See [`Scanner::next_complex`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only whitespace remains.
+    /// * [`ScanErrorKind::InvalidNumber`] if no number starts at the next
+    ///   non-whitespace character.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_complex(&mut self) -> Result<Complex<f64>, ScanError> {
+        let remaining = self.get_remaining();
+        let trimmed = remaining.trim_start();
+        let start = self.position + remaining.len() - trimmed.len();
+
+        if trimmed.is_empty() {
+            return Err(self.error(ScanErrorKind::EndOfInput));
+        }
+
+        let previous = self.input[..start].chars().next_back();
+        let invalid = ScanError::new(ScanErrorKind::InvalidNumber, start);
+        if trimmed.starts_with(['-', '+']) && !self.sign_policy.is_sign_after(previous) {
+            return Err(invalid);
+        }
+
+        let format = self.number_format;
+        let is_imaginary = |rest: &str| rest.starts_with(['i', 'j']);
+        let (mut len, first) = format.read(trimmed).ok_or(invalid.clone())?;
+        let first = first.parse().map_err(|_| invalid)?;
+
+        let value = if is_imaginary(&trimmed[len..]) {
+            len += 1;
+            Complex::new(0.0, first)
+        } else {
+            let imaginary = Some(&trimmed[len..])
+                .filter(|rest| rest.starts_with(['-', '+']))
+                .and_then(|rest| format.read(rest))
+                .filter(|(second, _)| is_imaginary(&trimmed[len + second..]));
+
+            match imaginary.and_then(|(second, text)| Some((second, text.parse().ok()?))) {
+                Some((second, imaginary)) => {
+                    len += second + 1;
+                    Complex::new(first, imaginary)
+                }
+                None => Complex::new(first, 0.0),
+            }
+        };
+
+        self.consume(start, start + len, start + len)?;
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complex_parts() {
+        let mut scanner = Scanner::new("1e2+5e-1j 2-x 4i");
+        assert_eq!(scanner.next_complex(), Some(Complex::new(100.0, 0.5)));
+        assert_eq!(scanner.next_complex(), Some(Complex::new(2.0, 0.0)));
+        assert_eq!(scanner.get_remaining(), "-x 4i");
+
+        let error = scanner.try_next_complex().unwrap_err();
+        assert_eq!(error.kind(), &ScanErrorKind::InvalidNumber);
+        assert_eq!(error.position(), 11);
+    }
+}
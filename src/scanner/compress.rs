@@ -0,0 +1,109 @@
+use std::io::{self, Read};
+
+#[cfg(feature = "gzip")]
+use flate2::read::MultiGzDecoder;
+
+use super::Scanner;
+
+impl<'a> Scanner<'a> {
+    /// Creates a new `Scanner` over gzip-compressed input, such as a
+    /// `.log.gz` file.
+    ///
+    /// The input is decompressed into `buffer`, replacing its contents, and
+    /// scanned from there; reusing the buffer across inputs avoids an
+    /// allocation per input. Concatenated gzip members are decompressed one
+    /// after the other, as `gzip -d` does.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The compressed input, such as a [`File`](std::fs::File).
+    /// * `buffer` - The buffer receiving the decompressed text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `reader` fails, if the input is not valid gzip,
+    /// or if the decompressed text is not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Write;
+    ///
+    /// use flate2::write::GzEncoder;
+    /// use flate2::Compression;
+    /// use scanner::scanner::Scanner;
+    ///
+    /// let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    /// encoder.write_all(b"GET / 200\n").unwrap();
+    /// let compressed = encoder.finish().unwrap();
+    ///
+    /// let mut buffer = String::new();
+    /// let mut scanner = Scanner::from_gzip(compressed.as_slice(), &mut buffer).unwrap();
+    /// assert_eq!(scanner.next_word(), Some("GET"));
+    /// ```
+    #[cfg(feature = "gzip")]
+    pub fn from_gzip(reader: impl Read, buffer: &'a mut String) -> io::Result<Self> {
+        decompress(MultiGzDecoder::new(reader), buffer)
+    }
+
+    /// Creates a new `Scanner` over Zstandard-compressed input, such as a
+    /// `.log.zst` file.
+    ///
+    /// See [`Scanner::from_gzip`]. Concatenated frames are decompressed one
+    /// after the other.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `reader` fails, if the input is not valid
+    /// Zstandard, or if the decompressed text is not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let compressed = zstd::encode_all(&b"level=info msg=ready\n"[..], 0).unwrap();
+    ///
+    /// let mut buffer = String::new();
+    /// let mut scanner = Scanner::from_zstd(compressed.as_slice(), &mut buffer).unwrap();
+    /// assert_eq!(scanner.next_word(), Some("level=info"));
+    /// ```
+    #[cfg(feature = "zstd")]
+    pub fn from_zstd(reader: impl Read, buffer: &'a mut String) -> io::Result<Self> {
+        decompress(zstd::Decoder::new(reader)?, buffer)
+    }
+}
+
+/// Reads all of `decoder` into `buffer`, replacing its contents, and returns
+/// a `Scanner` over it.
+fn decompress(mut decoder: impl Read, buffer: &mut String) -> io::Result<Scanner<'_>> {
+    buffer.clear();
+    decoder.read_to_string(buffer)?;
+    Ok(Scanner::new(buffer))
+}
+
+#[cfg(all(test, feature = "gzip"))]
+mod tests {
+    use std::io::Write;
+
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+
+    use super::*;
+
+    #[test]
+    fn test_concatenated_members_and_errors() {
+        let mut compressed = Vec::new();
+        for part in ["1 2\n", "3\n"] {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::fast());
+            encoder.write_all(part.as_bytes()).unwrap();
+            compressed.extend(encoder.finish().unwrap());
+        }
+
+        let mut buffer = String::from("stale");
+        let scanner = Scanner::from_gzip(compressed.as_slice(), &mut buffer).unwrap();
+        assert_eq!(scanner.get_remaining(), "1 2\n3\n");
+
+        let mut buffer = String::new();
+        assert!(Scanner::from_gzip(&b"plain text"[..], &mut buffer).is_err());
+    }
+}
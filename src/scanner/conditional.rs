@@ -0,0 +1,66 @@
+use super::{ScanErrorKind, Scanner};
+
+impl<'a> Scanner<'a> {
+    /// Scans the next word only if `predicate` holds for the whole word.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` with the word.
+    /// * `None` if there is no next word or `predicate` rejects it, in which
+    ///   case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("--verbose file.txt");
+    /// assert_eq!(scanner.next_if(|word| word.starts_with("--")), Some("--verbose"));
+    /// assert_eq!(scanner.next_if(|word| word.starts_with("--")), None);
+    /// assert_eq!(scanner.next_word(), Some("file.txt"));
+    /// ```
+    pub fn next_if<F>(&mut self, predicate: F) -> Option<&'a str>
+    where
+        F: FnOnce(&str) -> bool,
+    {
+        self.next_map(|word| predicate(word).then_some(word))
+    }
+
+    /// Scans the next word and converts it with `f`, consuming it only if
+    /// the conversion succeeds.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` returned by `f`.
+    /// * `None` if there is no next word or `f` returns `None`, in which
+    ///   case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("#ff8000 red");
+    /// let hex = |word: &str| u32::from_str_radix(word.strip_prefix('#')?, 16).ok();
+    /// assert_eq!(scanner.next_map(hex), Some(0xff8000));
+    /// assert_eq!(scanner.next_map(hex), None);
+    /// assert_eq!(scanner.next_word(), Some("red"));
+    /// ```
+    pub fn next_map<T, F>(&mut self, f: F) -> Option<T>
+    where
+        F: FnOnce(&'a str) -> Option<T>,
+    {
+        self.parse_next_word(ScanErrorKind::NoMatch, f).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejected_word_is_not_counted() {
+        let mut scanner = Scanner::new("a b").with_stats();
+        assert_eq!(scanner.next_if(|word| word == "b"), None);
+        assert_eq!(scanner.next_if(|word| word == "a"), Some("a"));
+        assert_eq!(scanner.stats().unwrap().tokens, 1);
+    }
+}
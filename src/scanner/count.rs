@@ -0,0 +1,62 @@
+use super::Scanner;
+
+impl Scanner<'_> {
+    /// Counts the words left in the input, without consuming them.
+    ///
+    /// # Returns
+    ///
+    /// The number of times [`Scanner::next_word`] would succeed, within the
+    /// scanner's budget.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("3\n10 20 30\n");
+    /// let n = scanner.next_number::<usize>().unwrap();
+    /// assert_eq!(scanner.count_tokens(), n);
+    /// let mut values = Vec::with_capacity(scanner.count_tokens());
+    /// while let Some(value) = scanner.next_number::<i32>() {
+    ///     values.push(value);
+    /// }
+    /// assert_eq!(values, [10, 20, 30]);
+    /// ```
+    pub fn count_tokens(&self) -> usize {
+        let mut fork = self.fork();
+        std::iter::from_fn(|| fork.next_word()).count()
+    }
+
+    /// Counts the lines left in the input, without consuming them.
+    ///
+    /// # Returns
+    ///
+    /// The number of times [`Scanner::next_line`] would succeed, within the
+    /// scanner's budget. A final line without a terminator is counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let scanner = Scanner::new("a\n\nb");
+    /// assert_eq!(scanner.count_lines(), 3);
+    /// ```
+    pub fn count_lines(&self) -> usize {
+        let mut fork = self.fork();
+        std::iter::from_fn(|| fork.next_line()).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts_leave_scanner_untouched() {
+        let mut scanner = Scanner::new("x y\nz\n").with_stats();
+        scanner.next_word();
+        assert_eq!(scanner.count_tokens(), 2);
+        assert_eq!(scanner.count_lines(), 2);
+        assert_eq!(scanner.position(), 1);
+        assert_eq!(scanner.stats().unwrap().tokens, 1);
+    }
+}
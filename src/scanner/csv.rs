@@ -0,0 +1,228 @@
+use std::borrow::Cow;
+
+use super::{ScanError, ScanErrorKind, Scanner};
+
+/// Splits a raw CSV `record` into its fields at `delimiter`, unquoting
+/// double-quoted fields.
+///
+/// Returns `None` if a quoted field is unterminated or followed by anything
+/// but a delimiter.
+fn fields(record: &str, delimiter: char) -> Option<Vec<Cow<'_, str>>> {
+    let mut fields = Vec::new();
+    let mut rest = record;
+
+    loop {
+        let Some(quoted) = rest.strip_prefix('"') else {
+            let end = rest.find(delimiter).unwrap_or(rest.len());
+            fields.push(Cow::Borrowed(&rest[..end]));
+            match rest[end..].chars().next() {
+                Some(c) => rest = &rest[end + c.len_utf8()..],
+                None => return Some(fields),
+            }
+            continue;
+        };
+
+        let mut field = Cow::Borrowed("");
+        let mut text = quoted;
+        loop {
+            let close = text.find('"')?;
+            let after = &text[close + 1..];
+            match after.strip_prefix('"') {
+                Some(after) => {
+                    field.to_mut().push_str(&text[..=close]);
+                    text = after;
+                }
+                None if field.is_empty() => {
+                    field = Cow::Borrowed(&text[..close]);
+                    text = after;
+                    break;
+                }
+                None => {
+                    field.to_mut().push_str(&text[..close]);
+                    text = after;
+                    break;
+                }
+            }
+        }
+
+        fields.push(field);
+        match text.chars().next() {
+            None => return Some(fields),
+            Some(c) if c == delimiter => rest = &text[c.len_utf8()..],
+            Some(_) => return None,
+        }
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Scans for the next logical CSV record, which may span several
+    /// physical lines when a quoted field contains newlines.
+    ///
+    /// Physical lines keep being read for as long as a double-quoted field is
+    /// left open. Doubled quotes (`""`) inside a quoted field are escapes and
+    /// do not close it. A record still open at the end of the input extends to
+    /// the end of the input.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` with the raw record, without its line terminator (`\n`
+    ///   or `\r\n`).
+    /// * `None` if no input remains.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("1,\"two\nlines\",3\n4,5,6\n");
+    /// assert_eq!(scanner.next_csv_record_multiline(), Some("1,\"two\nlines\",3"));
+    /// assert_eq!(scanner.next_csv_record_multiline(), Some("4,5,6"));
+    /// assert_eq!(scanner.next_csv_record_multiline(), None);
+    /// ```
+    pub fn next_csv_record_multiline(&mut self) -> Option<&'a str> {
+        let remaining = self.get_remaining();
+
+        if remaining.is_empty() {
+            return None;
+        }
+
+        let mut quoted = false;
+        let (mut end, mut consumed) = (remaining.len(), remaining.len());
+
+        for (i, c) in remaining.char_indices() {
+            match c {
+                '"' => quoted = !quoted,
+                '\n' if !quoted => {
+                    end = i;
+                    consumed = i + 1;
+                    break;
+                }
+                _ => {}
+            }
+        }
+
+        let record = remaining[..end]
+            .strip_suffix('\r')
+            .unwrap_or(&remaining[..end]);
+        let start = self.position;
+        self.consume(start, start + record.len(), start + consumed)
+            .ok()?;
+        Some(record)
+    }
+
+    /// Scans for the next CSV record, as defined by
+    /// [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180), and splits it into
+    /// its fields.
+    ///
+    /// Records are read as by [`Scanner::next_csv_record_multiline`], so
+    /// quoted fields may contain commas and newlines. Quoted fields are
+    /// unquoted and their doubled quotes unescaped; other fields are
+    /// borrowed from the input as they are.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Vec<Cow<str>>)` with the fields of the record.
+    /// * `None` if no input remains or the record is malformed, in which case
+    ///   nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("name,quote\r\n\"Doe, J.\",\"He said \"\"hi\"\"\nand left\"\r\n");
+    /// assert_eq!(scanner.next_csv_record().unwrap(), ["name", "quote"]);
+    /// assert_eq!(
+    ///     scanner.next_csv_record().unwrap(),
+    ///     ["Doe, J.", "He said \"hi\"\nand left"]
+    /// );
+    /// assert_eq!(scanner.next_csv_record(), None);
+    /// ```
+    pub fn next_csv_record(&mut self) -> Option<Vec<Cow<'a, str>>> {
+        self.try_next_csv_record_with(',').ok()
+    }
+
+    /// Scans for the next CSV record with fields separated by `delimiter`,
+    /// such as `;` or a tab.
+    ///
+    /// See [`Scanner::next_csv_record`].
+    pub fn next_csv_record_with(&mut self, delimiter: char) -> Option<Vec<Cow<'a, str>>> {
+        self.try_next_csv_record_with(delimiter).ok()
+    }
+
+    /// Scans for the next CSV record with fields separated by `delimiter`.
+    ///
+    /// See [`Scanner::next_csv_record`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if no input remains.
+    /// * [`ScanErrorKind::InvalidFormat`] if a quoted field is unterminated or
+    ///   followed by anything but a delimiter.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_csv_record_with(
+        &mut self,
+        delimiter: char,
+    ) -> Result<Vec<Cow<'a, str>>, ScanError> {
+        if self.get_remaining().is_empty() {
+            return Err(self.error(ScanErrorKind::EndOfInput));
+        }
+
+        let checkpoint = self.checkpoint();
+        let start = self.position;
+        let record = self
+            .next_csv_record_multiline()
+            .ok_or_else(|| ScanError::new(ScanErrorKind::BudgetExceeded, start))?;
+
+        fields(record, delimiter).ok_or_else(|| {
+            self.restore(checkpoint);
+            ScanError::new(
+                ScanErrorKind::InvalidFormat {
+                    format: "CSV record",
+                },
+                start,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multiline_record_crlf() {
+        let mut scanner = Scanner::new("id,note\r\n7,\"say \"\"hi\"\"\r\nbye\"\r\n8,x");
+        assert_eq!(scanner.next_csv_record_multiline(), Some("id,note"));
+        assert_eq!(
+            scanner.next_csv_record_multiline(),
+            Some("7,\"say \"\"hi\"\"\r\nbye\"")
+        );
+        assert_eq!(scanner.next_csv_record_multiline(), Some("8,x"));
+        assert_eq!(scanner.next_csv_record_multiline(), None);
+    }
+
+    #[test]
+    fn test_unterminated_quote() {
+        let mut scanner = Scanner::new("a,\"open\nstill open\n");
+        assert_eq!(
+            scanner.next_csv_record_multiline(),
+            Some("a,\"open\nstill open\n")
+        );
+        assert_eq!(scanner.get_remaining(), "");
+    }
+
+    #[test]
+    fn test_csv_fields() {
+        let fields = |record| fields(record, ';');
+        assert_eq!(fields("").unwrap(), [""]);
+        assert_eq!(fields("a;;\"\"").unwrap(), ["a", "", ""]);
+        assert!(matches!(fields("\"x\";y").unwrap()[0], Cow::Borrowed("x")));
+        assert_eq!(fields("\"\"\"\";\"a\"\"\"").unwrap(), ["\"", "a\""]);
+        assert_eq!(fields("\"open"), None);
+        assert_eq!(fields("\"a\"b;c"), None);
+
+        let mut scanner = Scanner::new("\"a\"b,c\n");
+        let error = scanner.try_next_csv_record_with(',').unwrap_err();
+        assert_eq!(error.to_string(), "invalid CSV record at offset 0");
+        assert_eq!(scanner.get_remaining(), "\"a\"b,c\n");
+    }
+}
@@ -0,0 +1,134 @@
+use chrono::format::ParseResult;
+use chrono::{DateTime, FixedOffset, NaiveDateTime};
+
+use super::{ScanError, ScanErrorKind, Scanner};
+
+impl<'a> Scanner<'a> {
+    /// Scans for the next date and time in the given `strftime`-like format.
+    ///
+    /// Leading whitespace is skipped. Unlike a word, the timestamp may
+    /// contain spaces and colons: exactly the characters matched by `fmt`
+    /// are consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `fmt` - The format, as described in [`chrono::format::strftime`].
+    ///
+    /// # Returns
+    ///
+    /// * `Some(NaiveDateTime)` if a timestamp in the format is next.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("[2024-03-01 12:30:00] GET /");
+    /// scanner.expect("[").unwrap();
+    /// let datetime = scanner.next_datetime("%Y-%m-%d %H:%M:%S").unwrap();
+    /// assert_eq!(datetime.date(), NaiveDate::from_ymd_opt(2024, 3, 1).unwrap());
+    /// assert_eq!(scanner.get_remaining(), "] GET /");
+    /// ```
+    pub fn next_datetime(&mut self, fmt: &str) -> Option<NaiveDateTime> {
+        self.try_next_datetime(fmt).ok()
+    }
+
+    /// Scans for the next date and time in the given format.
+    ///
+    /// See [`Scanner::next_datetime`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only whitespace remains.
+    /// * [`ScanErrorKind::InvalidFormat`] if no timestamp in the format is
+    ///   next.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_datetime(&mut self, fmt: &str) -> Result<NaiveDateTime, ScanError> {
+        self.parse_next_timestamp("date and time", |text| {
+            NaiveDateTime::parse_and_remainder(text, fmt)
+        })
+    }
+
+    /// Scans for the next [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339)
+    /// timestamp, such as `2024-03-01T12:30:00.5+01:00`.
+    ///
+    /// Leading whitespace is skipped. A `Z` offset and a space instead of
+    /// the `T` are accepted.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(DateTime<FixedOffset>)` if a timestamp is next.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("ts=2024-03-01 12:30:00Z level=info");
+    /// scanner.expect("ts=").unwrap();
+    /// let timestamp = scanner.next_rfc3339().unwrap();
+    /// assert_eq!(timestamp.timestamp(), 1_709_296_200);
+    /// assert_eq!(scanner.get_remaining(), " level=info");
+    /// ```
+    pub fn next_rfc3339(&mut self) -> Option<DateTime<FixedOffset>> {
+        self.try_next_rfc3339().ok()
+    }
+
+    /// Scans for the next RFC 3339 timestamp.
+    ///
+    /// See [`Scanner::next_rfc3339`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only whitespace remains.
+    /// * [`ScanErrorKind::InvalidFormat`] if no timestamp is next.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_rfc3339(&mut self) -> Result<DateTime<FixedOffset>, ScanError> {
+        self.parse_next_timestamp("RFC 3339 timestamp", |text| {
+            DateTime::parse_and_remainder(text, "%+")
+        })
+    }
+
+    /// Skips whitespace and consumes what `parse` matches, erroring with an
+    /// [`ScanErrorKind::InvalidFormat`] of `format` at the start otherwise.
+    fn parse_next_timestamp<T>(
+        &mut self,
+        format: &'static str,
+        parse: impl FnOnce(&'a str) -> ParseResult<(T, &'a str)>,
+    ) -> Result<T, ScanError> {
+        let remaining = self.get_remaining();
+        let trimmed = remaining.trim_start();
+        let start = self.position + remaining.len() - trimmed.len();
+
+        if trimmed.is_empty() {
+            return Err(self.error(ScanErrorKind::EndOfInput));
+        }
+
+        let (value, rest) = parse(trimmed)
+            .map_err(|_| ScanError::new(ScanErrorKind::InvalidFormat { format }, start))?;
+        let end = start + trimmed.len() - rest.len();
+        self.consume(start, end, end)?;
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timestamp_errors_and_spans() {
+        let mut scanner = Scanner::new("  2024-13-01T00:00:00Z 2024-12-01T00:00:00.25-05:00");
+        let error = scanner.try_next_rfc3339().unwrap_err();
+        assert_eq!(error.to_string(), "invalid RFC 3339 timestamp at offset 2");
+        assert_eq!(scanner.position(), 0);
+
+        scanner.next_word();
+        let timestamp = scanner.next_rfc3339().unwrap();
+        assert_eq!(timestamp.offset().utc_minus_local(), 5 * 3600);
+        assert_eq!(timestamp.timestamp_subsec_millis(), 250);
+        assert_eq!(scanner.last_span().unwrap().range(), 23..51);
+        assert_eq!(scanner.get_remaining(), "");
+    }
+}
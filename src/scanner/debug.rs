@@ -0,0 +1,71 @@
+use std::fmt;
+
+use super::Scanner;
+
+/// The number of characters of context shown on each side of the cursor.
+const CONTEXT_CHARS: usize = 16;
+
+impl fmt::Debug for Scanner<'_> {
+    /// Shows the cursor position and a window of the input around it, with
+    /// `⟨here⟩` marking the cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("let answer = 42;\nprint(answer);\n");
+    /// scanner.next_word();
+    /// scanner.next_word();
+    /// assert_eq!(
+    ///     format!("{scanner:?}"),
+    ///     r#"Scanner { position: 10, line: 1, column: 11, context: "let answer⟨here⟩ = 42;\nprint(ans…" }"#
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let before = &self.input[..self.position];
+        let after = &self.input[self.position..];
+
+        let start = before
+            .char_indices()
+            .rev()
+            .nth(CONTEXT_CHARS - 1)
+            .map_or(0, |(i, _)| i);
+        let end = after
+            .char_indices()
+            .nth(CONTEXT_CHARS)
+            .map_or(after.len(), |(i, _)| i);
+
+        let context = format!(
+            "{}{}⟨here⟩{}{}",
+            if start > 0 { "…" } else { "" },
+            &before[start..],
+            &after[..end],
+            if end < after.len() { "…" } else { "" },
+        );
+        let (line, column) = self.line_column(self.position);
+
+        f.debug_struct("Scanner")
+            .field("position", &self.position)
+            .field("line", &line)
+            .field("column", &column)
+            .field("context", &context)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_context_is_truncated_on_both_sides() {
+        let input = format!("{}é{}", "a".repeat(20), "b".repeat(20));
+        let mut scanner = Scanner::new(&input);
+        scanner.set_position(20).unwrap();
+
+        let debug = format!("{scanner:?}");
+        let context = format!("…{}⟨here⟩é{}…", "a".repeat(16), "b".repeat(15));
+        assert!(debug.ends_with(&format!("column: 21, context: {context:?} }}")));
+        assert!(format!("{:?}", Scanner::new("")).contains(r#"context: "⟨here⟩""#));
+    }
+}
@@ -0,0 +1,70 @@
+use rust_decimal::Decimal;
+
+use super::{ScanError, Scanner};
+
+impl Scanner<'_> {
+    /// Scans for the next number as an exact decimal.
+    ///
+    /// Numbers are read as by [`Scanner::next_float`], so the
+    /// [`NumberFormat`](super::NumberFormat) and
+    /// [`SignPolicy`](super::SignPolicy) apply, but no precision is lost to
+    /// binary floating point.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Decimal)` if a number is found.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use scanner::scanner::{NumberFormat, Scanner};
+    /// let mut scanner = Scanner::new("0,10 0,20").with_number_format(NumberFormat::EUROPEAN);
+    /// let sum = scanner.next_decimal().unwrap() + scanner.next_decimal().unwrap();
+    /// assert_eq!(sum, Decimal::new(30, 2));
+    /// ```
+    pub fn next_decimal(&mut self) -> Option<Decimal> {
+        self.try_next_decimal().ok()
+    }
+
+    /// Scans for the next number as an exact decimal.
+    ///
+    /// See [`Scanner::next_decimal`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`](super::ScanErrorKind::EndOfInput) if
+    ///   only whitespace remains.
+    /// * [`ScanErrorKind::InvalidNumber`](super::ScanErrorKind::InvalidNumber)
+    ///   if no number starts at the next non-whitespace character.
+    /// * [`ScanErrorKind::Overflow`](super::ScanErrorKind::Overflow) if the
+    ///   number does not fit in a [`Decimal`].
+    /// * [`ScanErrorKind::BudgetExceeded`](super::ScanErrorKind::BudgetExceeded)
+    ///   if the scanner's budget is used up.
+    pub fn try_next_decimal(&mut self) -> Result<Decimal, ScanError> {
+        self.parse_next_float(|number| match number.contains(['e', 'E']) {
+            true => Decimal::from_scientific(number).ok(),
+            false => number.parse().ok(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::{NumberFormat, ScanErrorKind};
+
+    #[test]
+    fn test_next_decimal() {
+        let mut scanner =
+            Scanner::new("1,234.56 -2.5e-3 1e40 x").with_number_format(NumberFormat::US);
+        assert_eq!(scanner.next_decimal(), Some(Decimal::new(123_456, 2)));
+        assert_eq!(scanner.next_decimal(), Some(Decimal::new(-25, 4)));
+
+        let error = scanner.try_next_decimal().unwrap_err();
+        assert_eq!(error.kind(), &ScanErrorKind::Overflow);
+        assert_eq!(error.position(), 17);
+        assert_eq!(scanner.get_remaining(), " 1e40 x");
+    }
+}
@@ -0,0 +1,263 @@
+use std::str;
+
+use super::Scanner;
+
+/// Controls how bytes that are not valid UTF-8 are decoded by [`DecodedInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Fallback {
+    /// Decode every invalid byte as a Windows-1252 character.
+    #[default]
+    Windows1252,
+    /// Decode every invalid byte as a Latin-1 (ISO 8859-1) character.
+    Latin1,
+    /// Replace every invalid sequence with `U+FFFD REPLACEMENT CHARACTER`.
+    Replace,
+}
+
+/// A sequence of bytes that was not valid UTF-8 in the original input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidSequence {
+    /// Byte offset of the sequence in the original input.
+    pub offset: usize,
+    /// Byte offset of the replacement text in the decoded text.
+    pub decoded_offset: usize,
+    /// The offending bytes.
+    pub bytes: Vec<u8>,
+}
+
+/// Owned text decoded from bytes that may not be entirely valid UTF-8.
+///
+/// Real-world files often mix encodings, e.g. a UTF-8 log with a few
+/// Windows-1252 lines. `DecodedInput` keeps every valid UTF-8 sequence as is,
+/// decodes the rest according to a [`Fallback`] and remembers where the
+/// invalid bytes were, so they can be reported.
+///
+/// # Examples
+///
+/// ```
+/// use scanner::scanner::{DecodedInput, Fallback};
+/// let input = DecodedInput::lenient(b"caf\xe9 ok", Fallback::Windows1252);
+/// let mut scanner = input.scanner();
+/// assert_eq!(scanner.next_word(), Some("café"));
+/// assert_eq!(input.invalid()[0].offset, 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedInput {
+    pub(super) text: String,
+    pub(super) invalid: Vec<InvalidSequence>,
+}
+
+impl DecodedInput {
+    /// Decodes `bytes` as UTF-8, decoding invalid sequences with `fallback`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The raw input.
+    /// * `fallback` - How to decode the bytes that are not valid UTF-8.
+    pub fn lenient(bytes: &[u8], fallback: Fallback) -> Self {
+        let mut text = String::with_capacity(bytes.len());
+        let mut invalid = Vec::new();
+        decode_lenient_into(
+            bytes,
+            fallback,
+            &mut text,
+            |offset, decoded_offset, bytes| {
+                invalid.push(InvalidSequence {
+                    offset,
+                    decoded_offset,
+                    bytes: bytes.to_vec(),
+                });
+            },
+        );
+
+        DecodedInput { text, invalid }
+    }
+
+    /// Returns the decoded text.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the invalid sequences found while decoding, in input order.
+    pub fn invalid(&self) -> &[InvalidSequence] {
+        &self.invalid
+    }
+
+    /// Returns `true` if the whole input was valid UTF-8.
+    pub fn is_valid(&self) -> bool {
+        self.invalid.is_empty()
+    }
+
+    /// Creates a new `Scanner` over the decoded text.
+    pub fn scanner(&self) -> Scanner<'_> {
+        Scanner::new(&self.text)
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Creates a new `Scanner` over bytes that may not be valid UTF-8,
+    /// replacing every invalid sequence with `U+FFFD REPLACEMENT CHARACTER`.
+    ///
+    /// A `Scanner` borrows its input and cannot own the decoded text, so the
+    /// caller provides `buffer` to hold it. Valid input is scanned in place
+    /// and leaves `buffer` untouched. Otherwise the text is decoded straight
+    /// into `buffer`, replacing its contents, and scanned from there; a
+    /// buffer reused across inputs only grows when an input needs more room
+    /// than it already has.
+    ///
+    /// [`DecodedInput::lenient`] with [`Fallback::Replace`] owns the decoded
+    /// text instead, and also tells where the invalid sequences were.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut buffer = String::new();
+    /// let mut scanner = Scanner::from_bytes_lossy(b"GET /\xff 200", &mut buffer);
+    /// assert_eq!(scanner.next_word(), Some("GET"));
+    /// assert_eq!(scanner.next_word(), Some("/\u{FFFD}"));
+    /// assert_eq!(scanner.next_number(), Some(200));
+    /// ```
+    pub fn from_bytes_lossy(bytes: &'a [u8], buffer: &'a mut String) -> Self {
+        if let Ok(text) = str::from_utf8(bytes) {
+            return Scanner::new(text);
+        }
+
+        buffer.clear();
+        decode_lenient_into(bytes, Fallback::Replace, buffer, |_, _, _| {});
+        Scanner::new(buffer)
+    }
+}
+
+/// Decodes `bytes` as UTF-8 onto the end of `text`, decoding invalid
+/// sequences with `fallback`.
+///
+/// `on_invalid` is called with the offset of every invalid sequence in
+/// `bytes`, its offset in `text` and the sequence itself.
+pub(super) fn decode_lenient_into(
+    bytes: &[u8],
+    fallback: Fallback,
+    text: &mut String,
+    mut on_invalid: impl FnMut(usize, usize, &[u8]),
+) {
+    let mut offset = 0;
+
+    while offset < bytes.len() {
+        match str::from_utf8(&bytes[offset..]) {
+            Ok(valid) => {
+                text.push_str(valid);
+                break;
+            }
+            Err(error) => {
+                let valid_len = error.valid_up_to();
+                let (valid, _) = bytes[offset..].split_at(valid_len);
+                text.push_str(str::from_utf8(valid).unwrap_or_default());
+                offset += valid_len;
+
+                let invalid_len = error.error_len().unwrap_or(bytes.len() - offset);
+                let sequence = &bytes[offset..offset + invalid_len];
+                on_invalid(offset, text.len(), sequence);
+
+                match fallback {
+                    Fallback::Windows1252 => text.extend(sequence.iter().map(|&b| windows_1252(b))),
+                    Fallback::Latin1 => text.extend(sequence.iter().map(|&b| char::from(b))),
+                    Fallback::Replace => text.push(char::REPLACEMENT_CHARACTER),
+                }
+
+                offset += invalid_len;
+            }
+        }
+    }
+}
+
+/// Decodes a single byte as Windows-1252.
+///
+/// The five bytes left undefined by Windows-1252 decode to the matching C1
+/// control character, as browsers do.
+fn windows_1252(byte: u8) -> char {
+    const HIGH: [char; 32] = [
+        '\u{20AC}', '\u{81}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}',
+        '\u{2021}', '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{8D}',
+        '\u{017D}', '\u{8F}', '\u{90}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}',
+        '\u{2013}', '\u{2014}', '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}', '\u{0153}',
+        '\u{9D}', '\u{017E}', '\u{0178}',
+    ];
+
+    match byte {
+        0x80..=0x9F => HIGH[usize::from(byte - 0x80)],
+        _ => char::from(byte),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lenient_valid_utf8() {
+        let input = DecodedInput::lenient("naïve ✓".as_bytes(), Fallback::Windows1252);
+        assert_eq!(input.as_str(), "naïve ✓");
+        assert!(input.is_valid());
+    }
+
+    #[test]
+    fn test_lenient_windows_1252() {
+        let input = DecodedInput::lenient(b"\x93quoted\x94 \x80 5", Fallback::Windows1252);
+        assert_eq!(input.as_str(), "\u{201C}quoted\u{201D} € 5");
+        assert_eq!(
+            input.invalid(),
+            [
+                InvalidSequence {
+                    offset: 0,
+                    decoded_offset: 0,
+                    bytes: vec![0x93]
+                },
+                InvalidSequence {
+                    offset: 7,
+                    decoded_offset: 9,
+                    bytes: vec![0x94]
+                },
+                InvalidSequence {
+                    offset: 9,
+                    decoded_offset: 13,
+                    bytes: vec![0x80]
+                },
+            ]
+        );
+
+        let mut scanner = input.scanner();
+        assert_eq!(scanner.next_word(), Some("\u{201C}quoted\u{201D}"));
+        assert_eq!(scanner.next_word(), Some("€"));
+        assert_eq!(scanner.next_number(), Some(5));
+    }
+
+    #[test]
+    fn test_lenient_latin1_and_replace() {
+        let bytes = b"\x80 truncated \xe2\x82";
+
+        let input = DecodedInput::lenient(bytes, Fallback::Latin1);
+        assert_eq!(input.as_str(), "\u{80} truncated \u{e2}\u{82}");
+
+        let input = DecodedInput::lenient(bytes, Fallback::Replace);
+        assert_eq!(input.as_str(), "\u{FFFD} truncated \u{FFFD}");
+        assert_eq!(input.invalid()[1].bytes, [0xe2, 0x82]);
+    }
+
+    #[test]
+    fn test_from_bytes_lossy_borrows_valid_input() {
+        let mut buffer = String::from("untouched");
+        let scanner = Scanner::from_bytes_lossy(b"valid", &mut buffer);
+        assert_eq!(scanner.get_remaining(), "valid");
+        assert_eq!(buffer, "untouched");
+
+        let scanner = Scanner::from_bytes_lossy(b"\xe2\x82 x", &mut buffer);
+        assert_eq!(scanner.get_remaining(), "\u{FFFD} x");
+
+        let mut buffer = String::with_capacity(64);
+        let allocation = buffer.as_ptr();
+        let scanner = Scanner::from_bytes_lossy(b"a\xff\xfe b", &mut buffer);
+        assert_eq!(scanner.get_remaining(), "a\u{FFFD}\u{FFFD} b");
+        assert_eq!(buffer.as_ptr(), allocation);
+        assert_eq!(String::from_utf8_lossy(b"a\xff\xfe b"), buffer);
+    }
+}
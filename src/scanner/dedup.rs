@@ -0,0 +1,68 @@
+use super::Scanner;
+
+/// An iterator collapsing consecutive duplicate lines of a [`Scanner`].
+///
+/// Created by [`Scanner::dedup_lines`].
+pub struct DedupLines<'s, 'a> {
+    scanner: &'s mut Scanner<'a>,
+    /// The first line of the next run, already consumed.
+    pending: Option<&'a str>,
+}
+
+impl<'a> Scanner<'a> {
+    /// Returns an iterator over the remaining lines that collapses runs of
+    /// identical consecutive lines into one, with the length of the run,
+    /// like `uniq -c`.
+    ///
+    /// Lines are compared after trailing whitespace is trimmed, as returned
+    /// by [`Scanner::next_line`]. Only the current run is kept in memory; to
+    /// find the end of a run, the iterator consumes the first line of the
+    /// next one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("a\na\nb\na\na\na\n");
+    /// let runs: Vec<_> = scanner.dedup_lines().collect();
+    /// assert_eq!(runs, [(2, "a"), (1, "b"), (3, "a")]);
+    /// ```
+    pub fn dedup_lines(&mut self) -> DedupLines<'_, 'a> {
+        DedupLines {
+            scanner: self,
+            pending: None,
+        }
+    }
+}
+
+impl<'a> Iterator for DedupLines<'_, 'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = self.pending.take().or_else(|| self.scanner.next_line())?;
+        let mut count = 1;
+
+        while let Some(next) = self.scanner.next_line() {
+            if next != line {
+                self.pending = Some(next);
+                break;
+            }
+            count += 1;
+        }
+
+        Some((count, line))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dedup_lines_trailing_whitespace_and_blank_lines() {
+        let mut scanner = Scanner::new("x \r\nx\n\n\n\ny");
+        let runs: Vec<_> = scanner.dedup_lines().collect();
+        assert_eq!(runs, [(2, "x"), (3, ""), (1, "y")]);
+        assert_eq!(scanner.dedup_lines().next(), None);
+    }
+}
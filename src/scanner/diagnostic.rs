@@ -0,0 +1,211 @@
+use std::fmt;
+
+use super::{NewlinePolicy, ScanError, Scanner, Span};
+
+/// An error message pointing at a span of the input, rendered like a
+/// compiler diagnostic.
+///
+/// ```text
+/// error: expected ")"
+///  --> 1:8
+///   |
+/// 1 | (1 + 2 ]
+///   |        ^
+/// ```
+///
+/// Lines are told apart by `\n`, or by the terminators of the
+/// [`NewlinePolicy`] of the scanner that built the diagnostic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic<'a> {
+    input: &'a str,
+    span: Span,
+    message: String,
+    origin: Option<(String, usize, usize)>,
+    newline_policy: NewlinePolicy,
+}
+
+impl<'a> Diagnostic<'a> {
+    /// Creates a new `Diagnostic` for `span` of `input`.
+    pub fn new(input: &'a str, span: Span, message: impl Into<String>) -> Self {
+        Diagnostic {
+            input,
+            span,
+            message: message.into(),
+            origin: None,
+            newline_policy: NewlinePolicy::default(),
+        }
+    }
+
+    /// Returns the line of the start of the span, starting at 1.
+    pub fn line(&self) -> usize {
+        line_column(self.newline_policy, self.input, self.span.start).0
+    }
+
+    /// Returns the column of the start of the span in characters, starting at 1.
+    pub fn column(&self) -> usize {
+        line_column(self.newline_policy, self.input, self.span.start).1
+    }
+}
+
+impl fmt::Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let start = self.span.start.min(self.input.len());
+        let (line, line_start) = line_start(self.newline_policy, self.input, start);
+        let line_end = self
+            .newline_policy
+            .find(&self.input[start..])
+            .map_or(self.input.len(), |(i, _)| start + i);
+        let text = self.input[line_start..line_end].trim_end_matches('\r');
+        let column = self.input[line_start..start].chars().count() + 1;
+        let gutter = line.to_string().len();
+
+        writeln!(f, "error: {}", self.message)?;
+        match &self.origin {
+            Some((name, line, column)) => writeln!(f, "{:gutter$}--> {name}:{line}:{column}", "")?,
+            None => writeln!(f, "{:gutter$}--> {line}:{column}", "")?,
+        }
+        writeln!(f, "{:gutter$} |", "")?;
+        writeln!(f, "{line} | {text}")?;
+
+        let padding: String = text
+            .chars()
+            .take(column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let end = self.span.end.clamp(start, line_start + text.len());
+        let width = self.input[start..end].chars().count().max(1);
+
+        write!(f, "{:gutter$} | {padding}{}", "", "^".repeat(width))
+    }
+}
+
+/// Returns the 1-based line of `offset` in `input` and the offset its line
+/// starts at.
+fn line_start(policy: NewlinePolicy, input: &str, offset: usize) -> (usize, usize) {
+    let before = &input[..offset.min(input.len())];
+    let mut line = 1;
+    let mut line_start = 0;
+    while let Some((i, len)) = policy.find(&before[line_start..]) {
+        line += 1;
+        line_start += i + len;
+    }
+
+    (line, line_start)
+}
+
+/// Returns the 1-based line and character column of `offset` in `input`.
+fn line_column(policy: NewlinePolicy, input: &str, offset: usize) -> (usize, usize) {
+    let offset = offset.min(input.len());
+    let (line, line_start) = line_start(policy, input, offset);
+
+    (line, input[line_start..offset].chars().count() + 1)
+}
+
+impl<'a> Scanner<'a> {
+    /// Returns the 1-based line and character column of the byte `offset` in
+    /// the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let scanner = Scanner::new("ab\ncd");
+    /// assert_eq!(scanner.line_column(4), (2, 2));
+    /// ```
+    pub fn line_column(&self, offset: usize) -> (usize, usize) {
+        line_column(self.newline_policy, self.input, offset)
+    }
+
+    /// Builds a [`Diagnostic`] pointing at `span` of the input.
+    ///
+    /// When the scanner was created from a [`SourceMap`](super::SourceMap),
+    /// the location is reported in the original source.
+    pub fn diagnostic(&self, span: Span, message: impl Into<String>) -> Diagnostic<'a> {
+        let mut diagnostic = Diagnostic::new(self.input, span, message);
+        diagnostic.newline_policy = self.newline_policy;
+        diagnostic.origin = self
+            .sources
+            .and_then(|sources| sources.resolve_span(span))
+            .map(|resolved| (resolved.name.to_string(), resolved.line, resolved.column));
+        diagnostic
+    }
+
+    /// Renders `message` as a caret-style diagnostic pointing at `span`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("x = 1\ny = oops\n");
+    /// scanner.next_line();
+    /// scanner.next_word();
+    /// scanner.next_word();
+    /// scanner.next_word();
+    /// let span = scanner.last_span().unwrap();
+    /// assert_eq!(
+    ///     scanner.render_error(span, "expected a number"),
+    ///     "error: expected a number\n --> 2:5\n  |\n2 | y = oops\n  |     ^^^^",
+    /// );
+    /// ```
+    pub fn render_error(&self, span: Span, message: impl Into<String>) -> String {
+        self.diagnostic(span, message).to_string()
+    }
+
+    /// Renders a [`ScanError`] as a caret-style diagnostic pointing at the
+    /// offset it occurred at.
+    pub fn render_scan_error(&self, error: &ScanError) -> String {
+        let start = error.position();
+        let end = self.input[start.min(self.input.len())..]
+            .find(char::is_whitespace)
+            .map_or(self.input.len(), |i| start + i);
+
+        self.render_error(self.span(start, end), error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::SourceMap;
+
+    #[test]
+    fn test_render_multiline_span() {
+        let scanner = Scanner::new("\tfoo(bar\n  baz)");
+        let rendered = scanner.render_error(Span::new(5, 14), "unclosed call");
+        assert_eq!(
+            rendered,
+            "error: unclosed call\n --> 1:6\n  |\n1 | \tfoo(bar\n  | \t    ^^^"
+        );
+    }
+
+    #[test]
+    fn test_render_scan_error() {
+        let mut scanner = Scanner::new("1 two");
+        scanner.next_number::<i32>();
+        let error = scanner.try_next_number::<i32>().unwrap_err();
+        assert_eq!(
+            scanner.render_scan_error(&error),
+            "error: invalid number at offset 2\n --> 1:3\n  |\n1 | 1 two\n  |   ^^^"
+        );
+    }
+
+    #[test]
+    fn test_render_with_sources() {
+        let mut sources = SourceMap::new();
+        sources.append("a.txt", "ok\n");
+        sources.append("b.txt", "bad\n");
+        let scanner = sources.scanner();
+        let diagnostic = scanner.diagnostic(Span::new(3, 6), "oops");
+        assert_eq!((diagnostic.line(), diagnostic.column()), (2, 1));
+        assert!(diagnostic.to_string().contains("--> b.txt:1:1"));
+    }
+
+    #[test]
+    fn test_render_end_of_input() {
+        let scanner = Scanner::new("abc");
+        assert_eq!(
+            scanner.render_error(Span::new(3, 3), "unexpected end"),
+            "error: unexpected end\n --> 1:4\n  |\n1 | abc\n  |    ^"
+        );
+    }
+}
@@ -0,0 +1,337 @@
+use std::time::Duration;
+
+use super::{ScanError, ScanErrorKind, Scanner};
+
+/// A duration written in the ISO 8601 notation, such as `P3DT4H30M`.
+///
+/// Years and months have no fixed length, so the components are kept as
+/// written rather than folded into a [`Duration`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct IsoDuration {
+    /// The `Y` component.
+    pub years: u64,
+    /// The `M` component before the `T`.
+    pub months: u64,
+    /// The `W` component.
+    pub weeks: u64,
+    /// The `D` component.
+    pub days: u64,
+    /// The `H` component.
+    pub hours: u64,
+    /// The `M` component after the `T`.
+    pub minutes: u64,
+    /// The whole seconds of the `S` component.
+    pub seconds: u64,
+    /// The fractional seconds of the `S` component, in nanoseconds.
+    pub nanoseconds: u32,
+}
+
+impl IsoDuration {
+    /// Converts the duration to a [`Duration`], counting a week as 7 days
+    /// and a day as 24 hours.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Duration)` with the total length.
+    /// * `None` if the duration has years or months, or overflows.
+    pub fn to_std(&self) -> Option<Duration> {
+        if self.years > 0 || self.months > 0 {
+            return None;
+        }
+
+        let days = self.weeks.checked_mul(7)?.checked_add(self.days)?;
+        let seconds = days
+            .checked_mul(24)?
+            .checked_add(self.hours)?
+            .checked_mul(60)?
+            .checked_add(self.minutes)?
+            .checked_mul(60)?
+            .checked_add(self.seconds)?;
+
+        Some(Duration::new(seconds, self.nanoseconds))
+    }
+
+    /// Parses a whole ISO 8601 duration.
+    fn parse(text: &str) -> Option<IsoDuration> {
+        let rest = text.strip_prefix('P')?;
+        let (date, time) = match rest.split_once('T') {
+            Some((date, time)) => (date, Some(time)),
+            None => (rest, None),
+        };
+
+        let mut duration = IsoDuration::default();
+        let mut date_fields = [
+            ('Y', &mut duration.years),
+            ('M', &mut duration.months),
+            ('W', &mut duration.weeks),
+            ('D', &mut duration.days),
+        ];
+        let mut found = components(date, &mut date_fields, None)?;
+
+        if let Some(time) = time {
+            let mut time_fields = [
+                ('H', &mut duration.hours),
+                ('M', &mut duration.minutes),
+                ('S', &mut duration.seconds),
+            ];
+            let time_found = components(time, &mut time_fields, Some(&mut duration.nanoseconds))?;
+            if time_found == 0 {
+                return None;
+            }
+            found += time_found;
+        }
+
+        (found > 0).then_some(duration)
+    }
+}
+
+/// Parses `<number><designator>` components in the order of `fields`,
+/// returning how many were found.
+///
+/// Only the `S` designator accepts a fraction, stored in `nanoseconds`.
+fn components(
+    mut text: &str,
+    fields: &mut [(char, &mut u64)],
+    mut nanoseconds: Option<&mut u32>,
+) -> Option<usize> {
+    let mut next_field = 0;
+    let mut found = 0;
+
+    while !text.is_empty() {
+        let digits = text.find(|c: char| !c.is_ascii_digit())?;
+        let mut value_end = digits;
+        let mut fraction = None;
+
+        if text[digits..].starts_with(['.', ',']) {
+            let fraction_digits = text[digits + 1..]
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(text.len() - digits - 1);
+            fraction = Some(&text[digits + 1..digits + 1 + fraction_digits]);
+            value_end = digits + 1 + fraction_digits;
+        }
+
+        let designator = text[value_end..].chars().next()?;
+        let offset = fields[next_field..]
+            .iter()
+            .position(|&(field, _)| field == designator)?;
+        let index = next_field + offset;
+
+        if digits == 0 || (fraction.is_some() && designator != 'S') {
+            return None;
+        }
+
+        *fields[index].1 = text[..digits].parse().ok()?;
+        if let (Some(fraction), Some(nanoseconds)) = (fraction, nanoseconds.as_deref_mut()) {
+            if fraction.is_empty() {
+                return None;
+            }
+            let padded = format!("{fraction:0<9}");
+            *nanoseconds = padded[..9].parse().ok()?;
+        }
+
+        next_field = index + 1;
+        found += 1;
+        text = &text[value_end + 1..];
+    }
+
+    Some(found)
+}
+
+/// The units of human-readable durations, from the longest to the shortest,
+/// with their length in nanoseconds.
+const UNITS: [(&str, u128); 8] = [
+    ("d", 86_400_000_000_000),
+    ("h", 3_600_000_000_000),
+    ("m", 60_000_000_000),
+    ("s", 1_000_000_000),
+    ("ms", 1_000_000),
+    ("us", 1_000),
+    ("µs", 1_000),
+    ("ns", 1),
+];
+
+/// Parses a whole human-readable duration such as `1h15m` or `250ms`.
+///
+/// Each unit may appear once, from the longest to the shortest.
+fn parse_human(mut text: &str) -> Option<Duration> {
+    let mut next_unit = 0;
+    let mut nanoseconds: u128 = 0;
+
+    if text.is_empty() {
+        return None;
+    }
+
+    while !text.is_empty() {
+        let digits = text.find(|c: char| !c.is_ascii_digit())?;
+        let rest = &text[digits..];
+        let unit_len = rest
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(rest.len());
+
+        let offset = UNITS[next_unit..]
+            .iter()
+            .position(|&(unit, _)| unit == &rest[..unit_len])?;
+        let (_, length) = UNITS[next_unit + offset];
+        let value: u128 = text[..digits].parse().ok()?;
+
+        nanoseconds = nanoseconds.checked_add(value.checked_mul(length)?)?;
+        next_unit += offset + 1;
+        text = &rest[unit_len..];
+    }
+
+    let seconds = u64::try_from(nanoseconds / 1_000_000_000).ok()?;
+    Some(Duration::new(seconds, (nanoseconds % 1_000_000_000) as u32))
+}
+
+impl<'a> Scanner<'a> {
+    /// Scans for the next ISO 8601 duration, such as `P3DT4H30M` or `PT0.5S`.
+    ///
+    /// The duration is the next whitespace-delimited word. Components must
+    /// appear in the order `Y`, `M`, `W`, `D`, then `T` and `H`, `M`, `S`;
+    /// only seconds may have a fraction.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(IsoDuration)` if the next word is a valid duration.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// use std::time::Duration;
+    /// let mut scanner = Scanner::new("DURATION P3DT4H30M");
+    /// scanner.next_word();
+    /// let duration = scanner.next_duration_iso8601().unwrap();
+    /// assert_eq!((duration.days, duration.hours, duration.minutes), (3, 4, 30));
+    /// assert_eq!(duration.to_std(), Some(Duration::from_secs(275_400)));
+    /// ```
+    pub fn next_duration_iso8601(&mut self) -> Option<IsoDuration> {
+        self.try_next_duration_iso8601().ok()
+    }
+
+    /// Scans for the next ISO 8601 duration.
+    ///
+    /// See [`Scanner::next_duration_iso8601`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only whitespace remains.
+    /// * [`ScanErrorKind::InvalidFormat`] if the next word is not a valid
+    ///   duration.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_duration_iso8601(&mut self) -> Result<IsoDuration, ScanError> {
+        self.parse_next_word(
+            ScanErrorKind::InvalidFormat {
+                format: "ISO 8601 duration",
+            },
+            IsoDuration::parse,
+        )
+    }
+
+    /// Scans for the next human-readable duration, such as `90s`, `5m30s`,
+    /// `1h15m` or `250ms`.
+    ///
+    /// The duration is the next whitespace-delimited word, made of whole
+    /// numbers each followed by one of the units `d`, `h`, `m`, `s`, `ms`,
+    /// `us` (or `µs`) and `ns`. Each unit may appear once, from the longest
+    /// to the shortest.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Duration)` if the next word is a valid duration.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// use std::time::Duration;
+    /// let mut scanner = Scanner::new("took 1h15m then 250ms");
+    /// scanner.next_word();
+    /// assert_eq!(scanner.next_duration(), Some(Duration::from_secs(4_500)));
+    /// assert_eq!(scanner.next_duration(), None);
+    /// scanner.next_word();
+    /// assert_eq!(scanner.next_duration(), Some(Duration::from_millis(250)));
+    /// ```
+    pub fn next_duration(&mut self) -> Option<Duration> {
+        self.try_next_duration().ok()
+    }
+
+    /// Scans for the next human-readable duration.
+    ///
+    /// See [`Scanner::next_duration`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only whitespace remains.
+    /// * [`ScanErrorKind::InvalidFormat`] if the next word is not a valid
+    ///   duration, or it overflows.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_duration(&mut self) -> Result<Duration, ScanError> {
+        self.parse_next_word(
+            ScanErrorKind::InvalidFormat { format: "duration" },
+            parse_human,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_iso_durations() {
+        let duration = IsoDuration::parse("P1Y2M10DT2H30M1.25S").unwrap();
+        assert_eq!((duration.years, duration.months, duration.days), (1, 2, 10));
+        assert_eq!((duration.hours, duration.minutes), (2, 30));
+        assert_eq!((duration.seconds, duration.nanoseconds), (1, 250_000_000));
+        assert_eq!(duration.to_std(), None);
+
+        assert_eq!(
+            IsoDuration::parse("P2W").unwrap().to_std(),
+            Some(Duration::from_secs(1_209_600))
+        );
+        assert_eq!(IsoDuration::parse("PT1M").unwrap().minutes, 1);
+        assert_eq!(IsoDuration::parse("P1M").unwrap().months, 1);
+
+        for invalid in [
+            "P", "PT", "P1", "1D", "P1DT", "PD", "P1H", "P1D2Y", "P1.5D", "PT1.S", "P1D1D",
+        ] {
+            assert_eq!(IsoDuration::parse(invalid), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_parse_human_durations() {
+        assert_eq!(parse_human("90s"), Some(Duration::from_secs(90)));
+        assert_eq!(parse_human("5m30s"), Some(Duration::from_secs(330)));
+        assert_eq!(parse_human("2d1µs"), Some(Duration::new(172_800, 1_000)));
+        assert_eq!(
+            parse_human("1s500ms7ns"),
+            Some(Duration::new(1, 500_000_007))
+        );
+
+        for invalid in ["", "5", "s", "5x", "30s5m", "1m1m", "1.5s", "5 s"] {
+            assert_eq!(parse_human(invalid), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_next_duration_iso8601_error() {
+        let mut scanner = Scanner::new("  P1X PT5S");
+        let error = scanner.try_next_duration_iso8601().unwrap_err();
+        assert_eq!(
+            error.kind(),
+            &ScanErrorKind::InvalidFormat {
+                format: "ISO 8601 duration"
+            }
+        );
+        assert_eq!(error.position(), 2);
+        assert_eq!(error.to_string(), "invalid ISO 8601 duration at offset 2");
+        assert_eq!(scanner.position(), 0);
+
+        scanner.next_word();
+        assert_eq!(scanner.next_duration_iso8601().unwrap().seconds, 5);
+    }
+}
@@ -0,0 +1,151 @@
+use super::{DecodedInput, Fallback, InvalidSequence};
+
+/// A text encoding supported by [`DecodedInput::decode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// UTF-8. Invalid sequences are decoded as Windows-1252.
+    Utf8,
+    /// UTF-16, little endian.
+    Utf16Le,
+    /// UTF-16, big endian.
+    Utf16Be,
+    /// Latin-1 (ISO 8859-1), where every byte is a character.
+    Latin1,
+}
+
+impl Encoding {
+    /// Detects the encoding of `bytes` from its byte order mark, defaulting
+    /// to [`Encoding::Utf8`].
+    ///
+    /// # Returns
+    ///
+    /// The encoding and the length of the byte order mark, `0` if there is
+    /// none.
+    pub fn detect(bytes: &[u8]) -> (Encoding, usize) {
+        match bytes {
+            [0xEF, 0xBB, 0xBF, ..] => (Encoding::Utf8, 3),
+            [0xFF, 0xFE, ..] => (Encoding::Utf16Le, 2),
+            [0xFE, 0xFF, ..] => (Encoding::Utf16Be, 2),
+            _ => (Encoding::Utf8, 0),
+        }
+    }
+}
+
+impl DecodedInput {
+    /// Decodes `bytes`, honoring a byte order mark and defaulting to UTF-8.
+    ///
+    /// The byte order mark is not part of the decoded text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::DecodedInput;
+    /// let input = DecodedInput::detect(b"\xFF\xFEo\0k\0 \x001\0\r\0\n\0");
+    /// let mut scanner = input.scanner();
+    /// assert_eq!(scanner.next_word(), Some("ok"));
+    /// assert_eq!(scanner.next_number(), Some(1));
+    /// ```
+    pub fn detect(bytes: &[u8]) -> Self {
+        let (encoding, bom) = Encoding::detect(bytes);
+        let mut input = Self::decode(&bytes[bom..], encoding);
+        for sequence in &mut input.invalid {
+            sequence.offset += bom;
+        }
+        input
+    }
+
+    /// Decodes `bytes` from `encoding`.
+    ///
+    /// A byte order mark is decoded as `U+FEFF`. Sequences that are invalid
+    /// in the encoding are replaced and reported by
+    /// [`invalid`](DecodedInput::invalid).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{DecodedInput, Encoding};
+    /// let input = DecodedInput::decode(b"\0c\0a\0f\0\xe9", Encoding::Utf16Be);
+    /// assert_eq!(input.as_str(), "café");
+    /// ```
+    pub fn decode(bytes: &[u8], encoding: Encoding) -> Self {
+        match encoding {
+            Encoding::Utf8 => Self::lenient(bytes, Fallback::Windows1252),
+            Encoding::Latin1 => DecodedInput {
+                text: bytes.iter().map(|&b| char::from(b)).collect(),
+                invalid: Vec::new(),
+            },
+            Encoding::Utf16Le => utf16(bytes, u16::from_le_bytes),
+            Encoding::Utf16Be => utf16(bytes, u16::from_be_bytes),
+        }
+    }
+}
+
+/// Decodes UTF-16 with the given byte order, replacing unpaired surrogates
+/// and a trailing odd byte with `U+FFFD REPLACEMENT CHARACTER`.
+fn utf16(bytes: &[u8], unit: fn([u8; 2]) -> u16) -> DecodedInput {
+    let mut text = String::with_capacity(bytes.len() / 2);
+    let mut invalid = Vec::new();
+    let units = bytes.chunks_exact(2).map(|pair| unit([pair[0], pair[1]]));
+    let mut offset = 0;
+
+    for result in char::decode_utf16(units) {
+        match result {
+            Ok(c) => {
+                text.push(c);
+                offset += c.len_utf16() * 2;
+            }
+            Err(_) => {
+                invalid.push(InvalidSequence {
+                    offset,
+                    decoded_offset: text.len(),
+                    bytes: bytes[offset..offset + 2].to_vec(),
+                });
+                text.push(char::REPLACEMENT_CHARACTER);
+                offset += 2;
+            }
+        }
+    }
+
+    if bytes.len() % 2 == 1 {
+        invalid.push(InvalidSequence {
+            offset,
+            decoded_offset: text.len(),
+            bytes: bytes[offset..].to_vec(),
+        });
+        text.push(char::REPLACEMENT_CHARACTER);
+    }
+
+    DecodedInput { text, invalid }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_bom() {
+        assert_eq!(Encoding::detect(b"\xEF\xBB\xBFa"), (Encoding::Utf8, 3));
+        assert_eq!(Encoding::detect(b"\xFE\xFF"), (Encoding::Utf16Be, 2));
+        assert_eq!(Encoding::detect(b"a"), (Encoding::Utf8, 0));
+
+        let input = DecodedInput::detect(b"\xEF\xBB\xBFna\xefve");
+        assert_eq!(input.as_str(), "naïve");
+        assert_eq!(input.invalid()[0].offset, 5);
+    }
+
+    #[test]
+    fn test_utf16_invalid_units() {
+        // "a", a surrogate pair for U+1F600, an unpaired high surrogate, "b", an odd byte.
+        let bytes = b"a\0\x3D\xD8\x00\xDE\x3D\xD8b\0\x01";
+        let input = DecodedInput::decode(bytes, Encoding::Utf16Le);
+        assert_eq!(input.as_str(), "a\u{1F600}\u{FFFD}b\u{FFFD}");
+
+        let offsets: Vec<_> = input.invalid().iter().map(|s| s.offset).collect();
+        assert_eq!(offsets, [6, 10]);
+        assert_eq!(input.invalid()[0].bytes, [0x3D, 0xD8]);
+
+        let latin1 = DecodedInput::decode(b"\xe9\xff", Encoding::Latin1);
+        assert_eq!(latin1.as_str(), "\u{e9}\u{ff}");
+        assert!(latin1.is_valid());
+    }
+}
@@ -0,0 +1,118 @@
+use std::error::Error;
+use std::fmt;
+
+/// The reason a scanning method failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ScanErrorKind {
+    /// No input is left to scan.
+    EndOfInput,
+    /// The remaining input does not contain the requested token.
+    NoMatch,
+    /// The next token is not a valid number of the requested type, for
+    /// example because it contains an invalid digit.
+    InvalidNumber,
+    /// The next token is a well-formed number that does not fit in the
+    /// requested type.
+    Overflow,
+    /// The token or byte budget of the scanner is used up.
+    BudgetExceeded,
+    /// The next token is not valid in the requested format.
+    InvalidFormat {
+        /// A description of the format, such as `"ISO 8601 duration"`.
+        format: &'static str,
+    },
+    /// A position is past the end of the input or not on a character boundary.
+    InvalidPosition,
+    /// A line is dedented to a width that matches no enclosing level.
+    InconsistentIndentation,
+    /// Bytes of the input are not valid UTF-8.
+    InvalidUtf8 {
+        /// The malformed sequence.
+        bytes: Vec<u8>,
+    },
+    /// A key occurs more than once in a record.
+    DuplicateKey {
+        /// The repeated key.
+        key: String,
+    },
+    /// The input does not continue with any of the expected literals.
+    Expected {
+        /// The literals that would have been accepted.
+        expected: Vec<String>,
+        /// The word found instead, or `None` at the end of the input.
+        found: Option<String>,
+    },
+}
+
+/// An error returned by the `try_` family of scanning methods.
+///
+/// Carries the [`ScanErrorKind`] and the byte offset in the input at which
+/// the failure was detected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanError {
+    kind: ScanErrorKind,
+    position: usize,
+}
+
+impl ScanError {
+    /// Creates a new `ScanError` of the given kind at `position`.
+    pub fn new(kind: ScanErrorKind, position: usize) -> Self {
+        ScanError { kind, position }
+    }
+
+    /// Returns the kind of the error.
+    pub fn kind(&self) -> &ScanErrorKind {
+        &self.kind
+    }
+
+    /// Returns the byte offset in the input at which the error occurred.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ScanErrorKind::EndOfInput => write!(f, "unexpected end of input")?,
+            ScanErrorKind::NoMatch => write!(f, "no matching token")?,
+            ScanErrorKind::InvalidNumber => write!(f, "invalid number")?,
+            ScanErrorKind::Overflow => write!(f, "number out of range")?,
+            ScanErrorKind::BudgetExceeded => write!(f, "scanning budget exceeded")?,
+            ScanErrorKind::InvalidFormat { format } => write!(f, "invalid {format}")?,
+            ScanErrorKind::InvalidPosition => write!(f, "invalid position")?,
+            ScanErrorKind::InconsistentIndentation => write!(f, "inconsistent indentation")?,
+            ScanErrorKind::InvalidUtf8 { bytes } => {
+                write!(f, "invalid UTF-8 sequence")?;
+                for byte in bytes {
+                    write!(f, " {byte:#04x}")?;
+                }
+            }
+            ScanErrorKind::DuplicateKey { key } => write!(f, "duplicate key {key:?}")?,
+            ScanErrorKind::Expected { expected, found } => {
+                match expected.as_slice() {
+                    [literal] => write!(f, "expected {literal:?}")?,
+                    literals => {
+                        write!(f, "expected one of ")?;
+                        for (i, literal) in literals.iter().enumerate() {
+                            if i > 0 {
+                                write!(f, ", ")?;
+                            }
+                            write!(f, "{literal:?}")?;
+                        }
+                    }
+                }
+
+                match found {
+                    Some(found) => write!(f, ", found {found:?}")?,
+                    None => write!(f, ", found end of input")?,
+                }
+            }
+        }
+
+        write!(f, " at offset {}", self.position)
+    }
+}
+
+impl Error for ScanError {}
@@ -0,0 +1,159 @@
+use super::{ScanError, ScanErrorKind, Scanner};
+
+impl<'a> Scanner<'a> {
+    /// Consumes `literal` if the remaining input starts with it, after
+    /// skipping leading whitespace.
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::Expected`] describing what was found instead.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("( 1 )");
+    /// assert!(scanner.expect("(").is_ok());
+    /// assert_eq!(scanner.next_number(), Some(1));
+    ///
+    /// let error = scanner.expect("]").unwrap_err();
+    /// assert_eq!(error.to_string(), r#"expected "]", found ")" at offset 4"#);
+    /// ```
+    pub fn expect(&mut self, literal: &str) -> Result<(), ScanError> {
+        self.expect_one_of(&[literal]).map(|_| ())
+    }
+
+    /// Consumes the longest of `literals` the remaining input starts with,
+    /// after skipping leading whitespace.
+    ///
+    /// # Returns
+    ///
+    /// The literal that was consumed, as a slice of the input.
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::Expected`] describing what was found instead.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("3 * 4");
+    /// assert_eq!(scanner.next_number(), Some(3));
+    /// assert_eq!(scanner.expect_one_of(&["+", "-", "*", "/"]), Ok("*"));
+    /// ```
+    pub fn expect_one_of(&mut self, literals: &[&str]) -> Result<&'a str, ScanError> {
+        let remaining = self.get_remaining();
+        let trimmed = remaining.trim_start();
+        let start = self.position + remaining.len() - trimmed.len();
+
+        let literal = literals
+            .iter()
+            .filter(|literal| !literal.is_empty() && trimmed.starts_with(*literal))
+            .max_by_key(|literal| literal.len());
+
+        match literal {
+            Some(literal) => {
+                let end = start + literal.len();
+                self.consume(start, end, end)?;
+                Ok(&self.input[start..end])
+            }
+            None => Err(ScanError::new(
+                ScanErrorKind::Expected {
+                    expected: literals.iter().map(|literal| literal.to_string()).collect(),
+                    found: trimmed.split_whitespace().next().map(str::to_string),
+                },
+                start,
+            )),
+        }
+    }
+
+    /// Scans for the longest of `operators` at the cursor, after skipping
+    /// leading whitespace.
+    ///
+    /// Longest match means `<=` is never split into `<` and `=` as long as
+    /// both `<` and `<=` are in `operators`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` with the consumed operator.
+    /// * `None` if the input does not continue with any of `operators`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let operators = ["<", "<=", "<<", "<<="];
+    /// let mut scanner = Scanner::new("a <<= 2");
+    /// scanner.next_word();
+    /// assert_eq!(scanner.next_operator(&operators), Some("<<="));
+    /// assert_eq!(scanner.next_operator(&operators), None);
+    /// ```
+    pub fn next_operator(&mut self, operators: &[&str]) -> Option<&'a str> {
+        self.expect_one_of(operators).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expect_sequence() {
+        let mut scanner = Scanner::new("let x = (1, 2);");
+        assert!(scanner.expect("let").is_ok());
+        assert_eq!(scanner.next_token(|c, _| c.is_alphabetic()), Some("x"));
+        assert!(scanner.expect("=").is_ok());
+        assert!(scanner.expect("(").is_ok());
+        assert_eq!(scanner.next_number(), Some(1));
+        assert!(scanner.expect(",").is_ok());
+        assert_eq!(scanner.next_number(), Some(2));
+        assert!(scanner.expect(")").is_ok());
+        assert!(scanner.expect(";").is_ok());
+        assert!(scanner.expect(";").is_err());
+    }
+
+    #[test]
+    fn test_expect_one_of_longest() {
+        let mut scanner = Scanner::new("<<= 1");
+        assert_eq!(scanner.expect_one_of(&["<", "<<=", "<<"]), Ok("<<="));
+        assert_eq!(scanner.get_remaining(), " 1");
+    }
+
+    #[test]
+    fn test_next_operator_sequence() {
+        let operators = ["<", "<=", "=", "=="];
+        let mut scanner = Scanner::new("<==<");
+        assert_eq!(scanner.next_operator(&operators), Some("<="));
+        assert_eq!(scanner.next_operator(&operators), Some("="));
+        assert_eq!(scanner.next_operator(&operators), Some("<"));
+        assert_eq!(scanner.next_operator(&operators), None);
+    }
+
+    #[test]
+    fn test_expect_errors() {
+        let mut scanner = Scanner::new("  foo bar");
+        let error = scanner.expect_one_of(&["+", "-"]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"expected one of "+", "-", found "foo" at offset 2"#
+        );
+        assert_eq!(scanner.get_remaining(), "  foo bar");
+
+        let mut scanner = Scanner::new(" ");
+        let error = scanner.expect("x").unwrap_err();
+        assert_eq!(
+            error.kind(),
+            &ScanErrorKind::Expected {
+                expected: vec!["x".to_string()],
+                found: None,
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            r#"expected "x", found end of input at offset 1"#
+        );
+    }
+}
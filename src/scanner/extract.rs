@@ -0,0 +1,93 @@
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use super::{ScanErrorKind, Scanner};
+
+/// An iterator over the integers embedded in the remaining input of a
+/// [`Scanner`].
+///
+/// Created by [`Scanner::extract_numbers`].
+pub struct ExtractNumbers<'s, 'a, T> {
+    scanner: &'s mut Scanner<'a>,
+    item: PhantomData<fn() -> T>,
+}
+
+impl<T> Iterator for ExtractNumbers<'_, '_, T>
+where
+    T: FromStr,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            let remaining = self.scanner.get_remaining();
+            let mut previous = self.scanner.previous_char();
+            let (offset, _) = remaining.char_indices().find(|&(i, c)| {
+                let sign = matches!(c, '-' | '+')
+                    && remaining[i + 1..].starts_with(|c: char| c.is_ascii_digit())
+                    && self.scanner.sign_policy.is_sign_after(previous);
+                previous = Some(c);
+                c.is_ascii_digit() || sign
+            })?;
+
+            self.scanner.position += offset;
+            match self.scanner.try_next_number() {
+                Ok(number) => return Some(number),
+                Err(error) if error.kind() == &ScanErrorKind::BudgetExceeded => return None,
+                Err(_) => {
+                    let token = self.scanner.get_remaining();
+                    let digits = token[1..]
+                        .find(|c: char| !(c.is_ascii_digit() || c == '_'))
+                        .map_or(token.len(), |end| end + 1);
+                    self.scanner.position += digits;
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Returns an iterator over every integer embedded in the remaining
+    /// input, skipping anything else.
+    ///
+    /// A `-` or `+` directly in front of digits is a sign as decided by the
+    /// [`SignPolicy`](super::SignPolicy). Numbers that do not parse as `T`,
+    /// such as ones too large for it or negative ones for an unsigned `T`,
+    /// are skipped. The cursor moves past each yielded number, and iteration
+    /// stops when the scanner's budget is used up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("Game 12: 3 red, -4 blue; x=99999999999");
+    /// let numbers: Vec<i32> = scanner.extract_numbers().collect();
+    /// assert_eq!(numbers, [12, 3, -4]);
+    /// ```
+    pub fn extract_numbers<T>(&mut self) -> ExtractNumbers<'_, 'a, T>
+    where
+        T: FromStr,
+    {
+        ExtractNumbers {
+            scanner: self,
+            item: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::SignPolicy;
+
+    #[test]
+    fn test_extract_numbers_signs() {
+        let mut scanner = Scanner::new("a-1 5-3 é7 -x +2");
+        let numbers: Vec<i64> = scanner.extract_numbers().collect();
+        assert_eq!(numbers, [-1, 5, -3, 7, 2]);
+
+        let mut scanner = Scanner::new("a-1 5-3 u-8").with_sign_policy(SignPolicy::Contextual);
+        let numbers: Vec<u8> = scanner.extract_numbers().collect();
+        assert_eq!(numbers, [1, 5, 3, 8]);
+    }
+}
@@ -0,0 +1,465 @@
+use std::borrow::Cow;
+
+use super::{Scanner, Span};
+
+/// A text transformation applied to the input before it is scanned.
+///
+/// Filters are composed in a [`Pipeline`] and applied one line at a time as
+/// the input is consumed, so a large input never has to be preprocessed as a
+/// whole.
+pub trait Filter {
+    /// Applies the filter to `text`, borrowing it when nothing changes.
+    fn apply<'t>(&self, text: &'t str) -> Cow<'t, str>;
+
+    /// Applies the filter to `text`, recording in `map` where the filtered
+    /// text came from.
+    ///
+    /// The default implementation maps the prefix and suffix the filtered
+    /// text has in common with `text` exactly, and approximates offsets in
+    /// between. Filters that remove or shorten text should override it.
+    fn apply_mapped<'t>(&self, text: &'t str, map: &mut OffsetMap) -> Cow<'t, str> {
+        let output = self.apply(text);
+
+        if let Cow::Owned(filtered) = &output {
+            let limit = text.len().min(filtered.len());
+            let prefix = text
+                .char_indices()
+                .zip(filtered.chars())
+                .find(|((_, a), b)| a != b)
+                .map_or(limit, |((i, _), _)| i);
+
+            let mut suffix = 0;
+            for (a, b) in text.chars().rev().zip(filtered.chars().rev()) {
+                if a != b || prefix + suffix + a.len_utf8() > limit {
+                    break;
+                }
+                suffix += a.len_utf8();
+            }
+
+            map.record(filtered.len() - suffix, text.len() - suffix);
+        }
+
+        output
+    }
+}
+
+/// Maps byte offsets in filtered text back to byte offsets in the text the
+/// filters were applied to.
+///
+/// The map is a list of anchors: from each anchor on, the filtered text
+/// continues the original text at the recorded offset, until the next
+/// anchor. Text removed by a filter thus maps to the offset right after it.
+///
+/// # Examples
+///
+/// ```
+/// use scanner::scanner::{Filter, OffsetMap, StripAnsi};
+/// let mut map = OffsetMap::new();
+/// let text = StripAnsi.apply_mapped("\x1b[1mbold\x1b[0m plain", &mut map);
+/// assert_eq!(text, "bold plain");
+/// assert_eq!(map.original_offset(0), 4);
+/// assert_eq!(map.original_offset(5), 13);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct OffsetMap {
+    /// `(filtered offset, original offset)`, sorted by filtered offset.
+    anchors: Vec<(usize, usize)>,
+}
+
+impl OffsetMap {
+    /// Creates an identity `OffsetMap`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records that from `filtered` on, the filtered text continues the
+    /// original text at `original`.
+    ///
+    /// Anchors must be recorded in increasing order of `filtered`; an anchor
+    /// at the same filtered offset as the last one replaces it.
+    pub fn record(&mut self, filtered: usize, original: usize) {
+        if self.original_offset(filtered) == original {
+            return;
+        }
+
+        match self.anchors.last_mut() {
+            Some(last) if last.0 == filtered => *last = (filtered, original),
+            _ => self.anchors.push((filtered, original)),
+        }
+    }
+
+    /// Translates a byte offset in the filtered text to a byte offset in
+    /// the original text.
+    pub fn original_offset(&self, offset: usize) -> usize {
+        match self
+            .anchors
+            .partition_point(|&(filtered, _)| filtered <= offset)
+        {
+            0 => offset,
+            index => {
+                let (filtered, original) = self.anchors[index - 1];
+                original + offset - filtered
+            }
+        }
+    }
+
+    /// Returns the map of applying `self` after the filters `earlier` maps.
+    fn after(&self, earlier: &OffsetMap) -> OffsetMap {
+        let mut composed = OffsetMap::new();
+        let segments: Vec<_> = std::iter::once((0, 0))
+            .chain(self.anchors.iter().copied())
+            .collect();
+
+        for (i, &(filtered, middle)) in segments.iter().enumerate() {
+            let end = segments
+                .get(i + 1)
+                .map(|&(next, _)| middle + next - filtered);
+
+            composed.record(filtered, earlier.original_offset(middle));
+            for &(from, original) in &earlier.anchors {
+                if from > middle && end.is_none_or(|end| from < end) {
+                    composed.record(filtered + from - middle, original);
+                }
+            }
+        }
+
+        composed
+    }
+}
+
+impl<F> Filter for F
+where
+    F: Fn(&str) -> String,
+{
+    fn apply<'t>(&self, text: &'t str) -> Cow<'t, str> {
+        Cow::Owned(self(text))
+    }
+}
+
+/// Removes ANSI escape sequences, such as terminal colors.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StripAnsi;
+
+impl Filter for StripAnsi {
+    fn apply<'t>(&self, text: &'t str) -> Cow<'t, str> {
+        self.apply_mapped(text, &mut OffsetMap::new())
+    }
+
+    fn apply_mapped<'t>(&self, text: &'t str, map: &mut OffsetMap) -> Cow<'t, str> {
+        if !text.contains('\x1b') {
+            return Cow::Borrowed(text);
+        }
+
+        let mut output = String::with_capacity(text.len());
+        let mut chars = text.char_indices().peekable();
+
+        while let Some((_, c)) = chars.next() {
+            if c != '\x1b' {
+                output.push(c);
+                continue;
+            }
+
+            match chars.next() {
+                // CSI: parameters and intermediates, then a final byte in `@..=~`.
+                Some((_, '[')) => {
+                    for (_, c) in chars.by_ref() {
+                        if ('@'..='~').contains(&c) {
+                            break;
+                        }
+                    }
+                }
+                // OSC: terminated by BEL or ST (`ESC \`).
+                Some((_, ']')) => {
+                    while let Some((_, c)) = chars.next() {
+                        if c == '\x07'
+                            || (c == '\x1b' && chars.next_if(|&(_, c)| c == '\\').is_some())
+                        {
+                            break;
+                        }
+                    }
+                }
+                _ => {}
+            }
+
+            map.record(output.len(), chars.peek().map_or(text.len(), |&(i, _)| i));
+        }
+
+        Cow::Owned(output)
+    }
+}
+
+/// Replaces every run of whitespace with a single space.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CollapseWhitespace;
+
+impl Filter for CollapseWhitespace {
+    fn apply<'t>(&self, text: &'t str) -> Cow<'t, str> {
+        self.apply_mapped(text, &mut OffsetMap::new())
+    }
+
+    fn apply_mapped<'t>(&self, text: &'t str, map: &mut OffsetMap) -> Cow<'t, str> {
+        let mut previous_whitespace = false;
+        let collapsed = text.chars().all(|c| {
+            let keep = c == ' ' || !c.is_whitespace();
+            let repeated = previous_whitespace && c.is_whitespace();
+            previous_whitespace = c.is_whitespace();
+            keep && !repeated
+        });
+
+        if collapsed {
+            return Cow::Borrowed(text);
+        }
+
+        let mut output = String::with_capacity(text.len());
+        let mut in_run = false;
+
+        for (i, c) in text.char_indices() {
+            if !c.is_whitespace() {
+                if in_run {
+                    map.record(output.len(), i);
+                }
+                in_run = false;
+                output.push(c);
+            } else if !in_run {
+                in_run = true;
+                output.push(' ');
+            }
+        }
+
+        if in_run {
+            map.record(output.len(), text.len());
+        }
+
+        Cow::Owned(output)
+    }
+}
+
+/// An ordered list of [`Filter`]s.
+///
+/// # Examples
+///
+/// ```
+/// use scanner::scanner::{CollapseWhitespace, Pipeline, Scanner, StripAnsi};
+/// let pipeline = Pipeline::new().with(StripAnsi).with(CollapseWhitespace);
+/// let mut scanner = Scanner::new("\x1b[31mERROR\x1b[0m   disk   full\nok\n");
+/// assert_eq!(scanner.next_filtered_line(&pipeline).as_deref(), Some("ERROR disk full"));
+/// assert_eq!(scanner.get_remaining(), "ok\n");
+/// ```
+#[derive(Default)]
+pub struct Pipeline {
+    filters: Vec<Box<dyn Filter>>,
+}
+
+impl Pipeline {
+    /// Creates an empty `Pipeline`, which leaves the text untouched.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `filter` to the pipeline.
+    pub fn with<F>(mut self, filter: F) -> Self
+    where
+        F: Filter + 'static,
+    {
+        self.filters.push(Box::new(filter));
+        self
+    }
+
+    /// Applies every filter of the pipeline, in order, to `text`, also
+    /// returning the map from the filtered text back to `text`.
+    pub fn apply_mapped<'t>(&self, text: &'t str) -> (Cow<'t, str>, OffsetMap) {
+        let mut map = OffsetMap::new();
+        let mut text = Cow::Borrowed(text);
+
+        for filter in &self.filters {
+            let mut stage = OffsetMap::new();
+            text = match text {
+                Cow::Borrowed(text) => filter.apply_mapped(text, &mut stage),
+                Cow::Owned(text) => Cow::Owned(filter.apply_mapped(&text, &mut stage).into_owned()),
+            };
+            map = stage.after(&map);
+        }
+
+        (text, map)
+    }
+
+    /// Applies every filter of the pipeline, in order, to `text`.
+    pub fn apply<'t>(&self, text: &'t str) -> Cow<'t, str> {
+        self.filters
+            .iter()
+            .fold(Cow::Borrowed(text), |text, filter| match text {
+                Cow::Borrowed(text) => filter.apply(text),
+                Cow::Owned(text) => Cow::Owned(filter.apply(&text).into_owned()),
+            })
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Consumes the next line and returns it with `pipeline` applied.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Cow<str>)` with the filtered line, borrowed from the input when
+    ///   no filter changed it.
+    /// * `None` if no line is found (i.e., end of input).
+    pub fn next_filtered_line(&mut self, pipeline: &Pipeline) -> Option<Cow<'a, str>> {
+        self.next_line().map(|line| pipeline.apply(line))
+    }
+
+    /// Consumes the next line and returns it with `pipeline` applied, along
+    /// with the mapping back to the raw input.
+    ///
+    /// Use this instead of [`Scanner::next_filtered_line`] when positions
+    /// found in the filtered text must be reported against the raw input.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(FilteredLine)` with the filtered line.
+    /// * `None` if no line is found (i.e., end of input).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{CollapseWhitespace, Pipeline, Scanner, Span, StripAnsi};
+    /// let pipeline = Pipeline::new().with(StripAnsi).with(CollapseWhitespace);
+    /// let mut scanner = Scanner::new("ok\n\x1b[31mERROR\x1b[0m   disk   full\n");
+    /// scanner.next_line();
+    ///
+    /// let line = scanner.next_mapped_line(&pipeline).unwrap();
+    /// assert_eq!(line.as_str(), "ERROR disk full");
+    /// assert_eq!(line.original_span(Span::new(0, 5)), Span::new(8, 13));
+    /// assert_eq!(line.original_span(Span::new(11, 15)), Span::new(27, 31));
+    /// ```
+    pub fn next_mapped_line(&mut self, pipeline: &Pipeline) -> Option<FilteredLine<'a>> {
+        let line = self.next_line()?;
+        let start = self.last.map_or(self.position, |(start, _)| start);
+        let (text, map) = pipeline.apply_mapped(line);
+
+        Some(FilteredLine { text, start, map })
+    }
+
+    /// Returns an iterator over the remaining lines with `pipeline` applied.
+    ///
+    /// Lines are filtered lazily, one at a time, as the iterator advances.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{Pipeline, Scanner, StripAnsi};
+    /// let pipeline = Pipeline::new().with(StripAnsi);
+    /// let mut scanner = Scanner::new("\x1b[1m1\x1b[0m 2\n3");
+    /// let sum: i32 = scanner
+    ///     .filtered_lines(&pipeline)
+    ///     .map(|line| {
+    ///         let mut line = Scanner::new(&line);
+    ///         std::iter::from_fn(|| line.next_number::<i32>()).sum::<i32>()
+    ///     })
+    ///     .sum();
+    /// assert_eq!(sum, 6);
+    /// ```
+    pub fn filtered_lines<'s>(&'s mut self, pipeline: &'s Pipeline) -> FilteredLines<'s, 'a> {
+        FilteredLines {
+            scanner: self,
+            pipeline,
+        }
+    }
+}
+
+/// A line with a [`Pipeline`] applied, which can translate offsets back to
+/// the raw input.
+///
+/// Created by [`Scanner::next_mapped_line`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilteredLine<'a> {
+    text: Cow<'a, str>,
+    /// Byte offset of the raw line in the input.
+    start: usize,
+    map: OffsetMap,
+}
+
+impl FilteredLine<'_> {
+    /// Returns the filtered text.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Translates a byte offset in the filtered text to a byte offset in the
+    /// scanner's input.
+    pub fn original_offset(&self, offset: usize) -> usize {
+        self.start + self.map.original_offset(offset)
+    }
+
+    /// Translates a span over the filtered text to a span over the scanner's
+    /// input, suitable for diagnostics.
+    ///
+    /// Text removed by the filters right before or after the span is not
+    /// included.
+    pub fn original_span(&self, span: Span) -> Span {
+        let start = self.original_offset(span.start);
+        let end = match span.end > span.start {
+            true => self.original_offset(span.end - 1) + 1,
+            false => start,
+        };
+
+        Span { start, end, ..span }
+    }
+}
+
+/// An iterator over the filtered lines of a [`Scanner`].
+///
+/// Created by [`Scanner::filtered_lines`].
+pub struct FilteredLines<'s, 'a> {
+    scanner: &'s mut Scanner<'a>,
+    pipeline: &'s Pipeline,
+}
+
+impl<'a> Iterator for FilteredLines<'_, 'a> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.scanner.next_filtered_line(self.pipeline)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_ansi() {
+        assert!(matches!(StripAnsi.apply("plain"), Cow::Borrowed("plain")));
+        assert_eq!(StripAnsi.apply("\x1b[1;32mok\x1b[0m"), "ok");
+        assert_eq!(StripAnsi.apply("\x1b]0;title\x07a\x1b]8;;url\x1b\\b"), "ab");
+    }
+
+    #[test]
+    fn test_collapse_whitespace() {
+        assert!(matches!(CollapseWhitespace.apply("a b"), Cow::Borrowed(_)));
+        assert_eq!(CollapseWhitespace.apply("  a \t\t b  "), " a b ");
+        assert_eq!(CollapseWhitespace.apply("a\tb"), "a b");
+    }
+
+    #[test]
+    fn test_pipeline_with_closure() {
+        let pipeline = Pipeline::new()
+            .with(|text: &str| text.replace(',', " "))
+            .with(CollapseWhitespace);
+        let mut scanner = Scanner::new("1,,2,3\n");
+        let line = scanner.next_filtered_line(&pipeline).unwrap();
+        assert_eq!(line, "1 2 3");
+        assert_eq!(scanner.next_filtered_line(&pipeline), None);
+    }
+
+    #[test]
+    fn test_offset_map_composition() {
+        let pipeline = Pipeline::new()
+            .with(|text: &str| text.replace("\\t", "\t"))
+            .with(CollapseWhitespace)
+            .with(StripAnsi);
+        let (text, map) = pipeline.apply_mapped("a\\t\\t\x1b[1mb  \x1b[0mc");
+        assert_eq!(text, "a b c");
+
+        let originals: Vec<_> = (0..=5).map(|i| map.original_offset(i)).collect();
+        assert_eq!(originals, [0, 1, 9, 10, 16, 17]);
+    }
+}
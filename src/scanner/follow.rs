@@ -0,0 +1,177 @@
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+/// A line reader over a growing file that waits for more lines at the end of
+/// the file, like `tail -f`.
+///
+/// The file is polled for new data. A trailing line without a newline is
+/// held back until it is complete. If the file shrinks, for example because
+/// it was truncated by log rotation, reading restarts from its beginning.
+/// Each line can be scanned with a [`Scanner`](super::Scanner).
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+///
+/// use scanner::scanner::{FollowScanner, Scanner};
+///
+/// let path = std::env::temp_dir().join("scanner-follow-example.log");
+/// std::fs::write(&path, "GET 200\nGET 4").unwrap();
+///
+/// let mut follow = FollowScanner::open(&path).unwrap();
+/// assert_eq!(follow.next_line().unwrap(), "GET 200");
+/// assert_eq!(follow.try_next_line().unwrap(), None);
+///
+/// let mut file = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
+/// file.write_all(b"04\n").unwrap();
+/// let line = follow.next_line().unwrap();
+/// let mut line = Scanner::new(&line);
+/// assert_eq!(line.next_word(), Some("GET"));
+/// assert_eq!(line.next_number(), Some(404));
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct FollowScanner {
+    file: File,
+    /// Bytes read after the last complete line.
+    pending: Vec<u8>,
+    /// Offset in the file up to which bytes have been read.
+    offset: u64,
+    interval: Duration,
+}
+
+impl FollowScanner {
+    /// Opens the file at `path`, to be read from its beginning.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(FollowScanner {
+            file: File::open(path)?,
+            pending: Vec::new(),
+            offset: 0,
+            interval: Duration::from_millis(250),
+        })
+    }
+
+    /// Skips the current contents of the file, so only lines written from
+    /// now on are read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be seeked.
+    pub fn from_end(mut self) -> io::Result<Self> {
+        self.offset = self.file.seek(SeekFrom::End(0))?;
+        self.pending.clear();
+        Ok(self)
+    }
+
+    /// Sets how long to wait before polling the file again at its end. The
+    /// default is 250 milliseconds.
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Returns the next line, waiting for the file to grow if no complete
+    /// line is available.
+    ///
+    /// Trailing whitespace is trimmed from the line, as by
+    /// [`Scanner::next_line`](super::Scanner::next_line).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, or with
+    /// [`io::ErrorKind::InvalidData`] if the line is not valid UTF-8. The
+    /// line is skipped in that case.
+    pub fn next_line(&mut self) -> io::Result<String> {
+        loop {
+            if let Some(line) = self.try_next_line()? {
+                return Ok(line);
+            }
+            thread::sleep(self.interval);
+        }
+    }
+
+    /// Returns the next line if a complete one is available, without
+    /// waiting.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(String))` with the next line.
+    /// * `Ok(None)` if the end of the file was reached.
+    ///
+    /// # Errors
+    ///
+    /// See [`FollowScanner::next_line`].
+    pub fn try_next_line(&mut self) -> io::Result<Option<String>> {
+        if !self.pending.contains(&b'\n') {
+            self.read_available()?;
+        }
+
+        let Some(newline) = self.pending.iter().position(|&b| b == b'\n') else {
+            return Ok(None);
+        };
+
+        let line: Vec<u8> = self.pending.drain(..=newline).collect();
+        match String::from_utf8(line) {
+            Ok(mut line) => {
+                line.truncate(line.trim_end().len());
+                Ok(Some(line))
+            }
+            Err(error) => Err(io::Error::new(io::ErrorKind::InvalidData, error)),
+        }
+    }
+
+    /// Reads whatever was appended to the file since the last read,
+    /// starting over if the file shrank.
+    fn read_available(&mut self) -> io::Result<()> {
+        if self.file.metadata()?.len() < self.offset {
+            self.offset = self.file.seek(SeekFrom::Start(0))?;
+            self.pending.clear();
+        }
+
+        let read = self.file.read_to_end(&mut self.pending)?;
+        self.offset += read as u64;
+        Ok(())
+    }
+}
+
+impl Iterator for FollowScanner {
+    type Item = io::Result<String>;
+
+    /// Returns the next line, waiting for it as long as necessary. The
+    /// iterator never ends.
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_line())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_truncation_restarts() {
+        let path = std::env::temp_dir().join("scanner-follow-test.log");
+        fs::write(&path, "old line\n").unwrap();
+
+        let mut follow = FollowScanner::open(&path).unwrap().from_end().unwrap();
+        assert_eq!(follow.try_next_line().unwrap(), None);
+
+        fs::write(&path, "new\n").unwrap();
+        assert_eq!(follow.try_next_line().unwrap().as_deref(), Some("new"));
+
+        fs::write(&path, b"new\n\xff\nok\n").unwrap();
+        assert!(follow.try_next_line().is_err());
+        assert_eq!(follow.try_next_line().unwrap().as_deref(), Some("ok"));
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,103 @@
+use std::str::FromStr;
+
+use super::{ScanError, ScanErrorKind, Scanner};
+
+impl Scanner<'_> {
+    /// Scans for the next fraction, such as `22/7` or `-3/4`.
+    ///
+    /// Leading whitespace is skipped. The numerator may have a sign,
+    /// according to the [`SignPolicy`](super::SignPolicy), and is directly
+    /// followed by a `/` and the denominator. Digits may be separated by `_`
+    /// if the [`NumberFormat`](super::NumberFormat) allows it.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((numerator, denominator))` if a fraction is found. The
+    ///   denominator is not checked for zero.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("22/7 -3/4 1 / 2");
+    /// assert_eq!(scanner.next_fraction(), Some((22, 7)));
+    /// assert_eq!(scanner.next_fraction::<i32>(), Some((-3, 4)));
+    /// assert_eq!(scanner.next_fraction::<i32>(), None);
+    /// ```
+    pub fn next_fraction<T>(&mut self) -> Option<(T, T)>
+    where
+        T: FromStr,
+    {
+        self.try_next_fraction().ok()
+    }
+
+    /// Scans for the next fraction.
+    ///
+    /// See [`Scanner::next_fraction`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only whitespace remains.
+    /// * [`ScanErrorKind::InvalidNumber`] if no fraction starts at the next
+    ///   non-whitespace character.
+    /// * [`ScanErrorKind::Overflow`] if the numerator or the denominator does
+    ///   not fit in `T`.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_fraction<T>(&mut self) -> Result<(T, T), ScanError>
+    where
+        T: FromStr,
+    {
+        let remaining = self.get_remaining();
+        let trimmed = remaining.trim_start();
+        let start = self.position + remaining.len() - trimmed.len();
+
+        if trimmed.is_empty() {
+            return Err(self.error(ScanErrorKind::EndOfInput));
+        }
+
+        let previous = self.input[..start].chars().next_back();
+        let invalid = ScanError::new(ScanErrorKind::InvalidNumber, start);
+        let sign = usize::from(trimmed.starts_with(['-', '+']));
+        if sign == 1 && !self.sign_policy.is_sign_after(previous) {
+            return Err(invalid);
+        }
+
+        let format = self.number_format;
+        let (slash, numerator) = format.digits(trimmed, sign, 10);
+        if numerator.is_empty() || !trimmed[slash..].starts_with('/') {
+            return Err(invalid);
+        }
+        let (len, denominator) = format.digits(trimmed, slash + 1, 10);
+        if denominator.is_empty() {
+            return Err(invalid);
+        }
+
+        let overflow = || ScanError::new(ScanErrorKind::Overflow, start);
+        let numerator = format!("{}{numerator}", &trimmed[..sign]);
+        let numerator = numerator.parse().map_err(|_| overflow())?;
+        let denominator = denominator.parse().map_err(|_| overflow())?;
+        self.consume(start, start + len, start + len)?;
+        Ok((numerator, denominator))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fraction_errors() {
+        let mut scanner = Scanner::new(" 1/ 300/1 1/2");
+        let error = scanner.try_next_fraction::<u8>().unwrap_err();
+        assert_eq!(error.kind(), &ScanErrorKind::InvalidNumber);
+        assert_eq!(error.position(), 1);
+
+        scanner.next_word();
+        let error = scanner.try_next_fraction::<u8>().unwrap_err();
+        assert_eq!(error.kind(), &ScanErrorKind::Overflow);
+        assert_eq!(error.position(), 4);
+        assert_eq!(scanner.next_fraction::<u16>(), Some((300, 1)));
+        assert_eq!(scanner.last_span().unwrap().range(), 4..9);
+    }
+}
@@ -0,0 +1,215 @@
+use std::str::FromStr;
+
+use super::{ScanError, ScanErrorKind, Scanner};
+
+/// How the edge lists scanned by [`Scanner::next_graph`] and
+/// [`Scanner::next_weighted_graph`] number their vertices, and whether their
+/// edges are one-way.
+///
+/// # Examples
+///
+/// ```
+/// use scanner::scanner::{GraphFormat, Scanner};
+/// let mut scanner = Scanner::new("3 2\n0 1\n1 2\n");
+/// let graph = scanner.next_graph(GraphFormat::ZERO_BASED.directed()).unwrap();
+/// assert_eq!(graph, [vec![1], vec![2], vec![]]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GraphFormat {
+    /// The index of the first vertex, usually `0` or `1`.
+    pub base: usize,
+    /// Whether an edge `u v` only leads from `u` to `v`.
+    pub directed: bool,
+}
+
+impl GraphFormat {
+    /// Undirected edges between vertices numbered from `0`.
+    pub const ZERO_BASED: GraphFormat = GraphFormat {
+        base: 0,
+        directed: false,
+    };
+
+    /// Undirected edges between vertices numbered from `1`.
+    pub const ONE_BASED: GraphFormat = GraphFormat {
+        base: 1,
+        directed: false,
+    };
+
+    /// Returns the format with directed edges.
+    pub const fn directed(mut self) -> Self {
+        self.directed = true;
+        self
+    }
+}
+
+impl Scanner<'_> {
+    /// Scans a graph given as a vertex count `n` and an edge count `m`,
+    /// followed by `m` edges `u v`, into an adjacency list.
+    ///
+    /// Vertices are numbered from `format.base`, and the adjacency list is
+    /// indexed from `0`. An undirected edge is added to the lists of both of
+    /// its ends, and a loop `u u` once. Line breaks are not significant.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The vertex numbering and whether edges are directed.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Vec<Vec<usize>>)` with the neighbours of each vertex, in the
+    ///   order of the edges.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{GraphFormat, Scanner};
+    /// let mut scanner = Scanner::new("3 2\n1 2\n1 3\n");
+    /// let graph = scanner.next_graph(GraphFormat::ONE_BASED).unwrap();
+    /// assert_eq!(graph, [vec![1, 2], vec![0], vec![0]]);
+    /// ```
+    pub fn next_graph(&mut self, format: GraphFormat) -> Option<Vec<Vec<usize>>> {
+        self.try_next_graph(format).ok()
+    }
+
+    /// Scans a graph given as an edge list.
+    ///
+    /// See [`Scanner::next_graph`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if the input ends before the last edge.
+    /// * [`ScanErrorKind::InvalidFormat`] if an edge has a vertex outside
+    ///   the graph.
+    /// * [`ScanErrorKind::InvalidNumber`] or [`ScanErrorKind::Overflow`] if
+    ///   a count or a vertex is not a valid `usize`.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_graph(&mut self, format: GraphFormat) -> Result<Vec<Vec<usize>>, ScanError> {
+        let graph = self.edge_list(format, |_| Ok(()))?;
+
+        Ok(graph
+            .into_iter()
+            .map(|edges| edges.into_iter().map(|(v, ())| v).collect())
+            .collect())
+    }
+
+    /// Scans a graph given as a vertex count `n` and an edge count `m`,
+    /// followed by `m` edges `u v w` with a weight `w`, into an adjacency
+    /// list.
+    ///
+    /// See [`Scanner::next_graph`]. Weights are read as by
+    /// [`Scanner::next_number`].
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Vec<Vec<(usize, W)>>)` with the neighbours of each vertex
+    ///   and the weights of the edges leading to them.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{GraphFormat, Scanner};
+    /// let mut scanner = Scanner::new("2 1\n0 1 7\n");
+    /// let graph = scanner.next_weighted_graph::<u32>(GraphFormat::ZERO_BASED).unwrap();
+    /// assert_eq!(graph, [vec![(1, 7)], vec![(0, 7)]]);
+    /// ```
+    pub fn next_weighted_graph<W>(&mut self, format: GraphFormat) -> Option<Vec<Vec<(usize, W)>>>
+    where
+        W: FromStr + Clone,
+    {
+        self.try_next_weighted_graph(format).ok()
+    }
+
+    /// Scans a graph given as an edge list with weights.
+    ///
+    /// See [`Scanner::next_weighted_graph`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if the input ends before the last edge.
+    /// * [`ScanErrorKind::InvalidFormat`] if an edge has a vertex outside
+    ///   the graph.
+    /// * [`ScanErrorKind::InvalidNumber`] or [`ScanErrorKind::Overflow`] if
+    ///   a count, a vertex or a weight is not valid.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_weighted_graph<W>(
+        &mut self,
+        format: GraphFormat,
+    ) -> Result<Vec<Vec<(usize, W)>>, ScanError>
+    where
+        W: FromStr + Clone,
+    {
+        self.edge_list(format, Scanner::try_next_number)
+    }
+
+    /// Scans the counts and the edges of a graph, reading the weight of each
+    /// edge with `weight`. Nothing is consumed on failure.
+    fn edge_list<W>(
+        &mut self,
+        format: GraphFormat,
+        mut weight: impl FnMut(&mut Self) -> Result<W, ScanError>,
+    ) -> Result<Vec<Vec<(usize, W)>>, ScanError>
+    where
+        W: Clone,
+    {
+        let checkpoint = self.checkpoint();
+        let result = (|| {
+            let n: usize = self.try_next_number()?;
+            let m: usize = self.try_next_number()?;
+            let mut graph = vec![Vec::new(); n];
+
+            for _ in 0..m {
+                let u = self.vertex(format.base, n)?;
+                let v = self.vertex(format.base, n)?;
+                let w = weight(self)?;
+                if !format.directed && u != v {
+                    graph[v].push((u, w.clone()));
+                }
+                graph[u].push((v, w));
+            }
+            Ok(graph)
+        })();
+
+        if result.is_err() {
+            self.restore(checkpoint);
+        }
+        result
+    }
+
+    /// Scans a vertex numbered from `base` in a graph of `n` vertices and
+    /// returns its index from `0`.
+    fn vertex(&mut self, base: usize, n: usize) -> Result<usize, ScanError> {
+        let vertex: usize = self.try_next_number()?;
+
+        match vertex.checked_sub(base).filter(|&index| index < n) {
+            Some(index) => Ok(index),
+            None => {
+                let start = self.last.map_or(self.position, |(start, _)| start);
+                let kind = ScanErrorKind::InvalidFormat { format: "vertex" };
+                Err(ScanError::new(kind, start))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vertex_out_of_range() {
+        let mut scanner = Scanner::new("2 2\n1 2\n2 0\n");
+        let error = scanner.try_next_graph(GraphFormat::ONE_BASED).unwrap_err();
+        assert_eq!(
+            error.kind(),
+            &ScanErrorKind::InvalidFormat { format: "vertex" }
+        );
+        assert_eq!(error.position(), 10);
+        assert_eq!(scanner.get_remaining(), "2 2\n1 2\n2 0\n");
+
+        let mut scanner = Scanner::new("1 1 0 0 -2");
+        let graph = scanner.next_weighted_graph(GraphFormat::ZERO_BASED);
+        assert_eq!(graph, Some(vec![vec![(0, -2)]]));
+    }
+}
@@ -0,0 +1,103 @@
+use super::Scanner;
+
+/// A two-dimensional grid of characters, such as a puzzle map or a maze.
+///
+/// Rows may have different lengths; [`Grid::width`] is the length of the
+/// longest one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid {
+    rows: Vec<Vec<char>>,
+    width: usize,
+}
+
+impl Grid {
+    /// Returns the length of the longest row.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows.
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the character in column `x` of row `y`, or `None` if it is
+    /// outside the grid.
+    pub fn get(&self, x: usize, y: usize) -> Option<char> {
+        self.rows.get(y)?.get(x).copied()
+    }
+
+    /// Returns row `y`, or `None` if it is outside the grid.
+    pub fn row(&self, y: usize) -> Option<&[char]> {
+        self.rows.get(y).map(Vec::as_slice)
+    }
+
+    /// Returns the `(x, y)` position of the first occurrence of `c`, row by
+    /// row.
+    pub fn find(&self, c: char) -> Option<(usize, usize)> {
+        self.rows.iter().enumerate().find_map(|(y, row)| {
+            let x = row.iter().position(|&cell| cell == c)?;
+            Some((x, y))
+        })
+    }
+
+    /// Returns the rows of the grid.
+    pub fn into_rows(self) -> Vec<Vec<char>> {
+        self.rows
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Scans the next block of consecutive non-blank lines into a [`Grid`]
+    /// of characters.
+    ///
+    /// The block is read as by [`Scanner::next_paragraph`], so leading blank
+    /// lines are skipped and the blank line ending the block is consumed.
+    /// Each line is one row, with a trailing `\r` dropped.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Grid)` with one row per line.
+    /// * `None` if only blank lines remain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("#.#\n.S.\n#.#\n\nmoves: 3\n");
+    /// let grid = scanner.next_grid().unwrap();
+    /// assert_eq!((grid.width(), grid.height()), (3, 3));
+    /// assert_eq!(grid.get(1, 1), Some('S'));
+    /// assert_eq!(grid.find('S'), Some((1, 1)));
+    /// assert_eq!(grid.get(3, 0), None);
+    /// assert_eq!(scanner.get_remaining(), "moves: 3\n");
+    /// ```
+    pub fn next_grid(&mut self) -> Option<Grid> {
+        let paragraph = self.next_paragraph()?;
+        let rows: Vec<Vec<char>> = self
+            .newline_policy
+            .split(paragraph)
+            .map(|line| line.strip_suffix('\r').unwrap_or(line).chars().collect())
+            .collect();
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+
+        Some(Grid { rows, width })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ragged_grid() {
+        let mut scanner = Scanner::new("\n\nab\r\nçdef\r\n");
+        let grid = scanner.next_grid().unwrap();
+        assert_eq!((grid.width(), grid.height()), (4, 2));
+        assert_eq!(grid.row(0), Some(&['a', 'b'][..]));
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(3, 1), Some('f'));
+        assert_eq!(grid.find('z'), None);
+        assert_eq!(scanner.next_grid(), None);
+    }
+}
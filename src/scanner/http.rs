@@ -0,0 +1,199 @@
+use std::borrow::Cow;
+
+use super::{NewlinePolicy, ScanError, ScanErrorKind, Scanner};
+
+/// Splits the line at the start of `text` at its first terminator under
+/// `policy`, dropping a trailing `\r`.
+///
+/// Returns the line and the length of it with its terminator.
+fn line(policy: NewlinePolicy, text: &str) -> (&str, usize) {
+    let (line, len) = match policy.find(text) {
+        Some((end, terminator)) => (&text[..end], end + terminator),
+        None => (text, text.len()),
+    };
+    (line.strip_suffix('\r').unwrap_or(line), len)
+}
+
+impl<'a> Scanner<'a> {
+    /// Scans an HTTP/1.x request line, such as `GET /index.html HTTP/1.1`.
+    ///
+    /// The line must be the next one and consist of exactly three parts
+    /// separated by single spaces, the last one starting with `HTTP/`. The
+    /// line terminator, `\r\n` or `\n`, is consumed.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((method, target, version))` for a valid request line.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("GET /search?q=rust HTTP/1.1\r\nHost: example.org\r\n\r\n");
+    /// assert_eq!(
+    ///     scanner.next_request_line(),
+    ///     Some(("GET", "/search?q=rust", "HTTP/1.1"))
+    /// );
+    /// assert_eq!(scanner.get_remaining(), "Host: example.org\r\n\r\n");
+    /// ```
+    pub fn next_request_line(&mut self) -> Option<(&'a str, &'a str, &'a str)> {
+        self.try_next_request_line().ok()
+    }
+
+    /// Scans an HTTP/1.x request line.
+    ///
+    /// See [`Scanner::next_request_line`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if no input remains.
+    /// * [`ScanErrorKind::InvalidFormat`] if the next line is not a request
+    ///   line.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_request_line(&mut self) -> Result<(&'a str, &'a str, &'a str), ScanError> {
+        let remaining = self.get_remaining();
+        let start = self.position;
+
+        if remaining.is_empty() {
+            return Err(self.error(ScanErrorKind::EndOfInput));
+        }
+
+        let (line, len) = line(self.newline_policy, remaining);
+        let mut parts = line.split(' ');
+        let parts = (parts.next(), parts.next(), parts.next(), parts.next());
+
+        let request = match parts {
+            (Some(method), Some(target), Some(version), None)
+                if !method.is_empty() && !target.is_empty() && version.starts_with("HTTP/") =>
+            {
+                (method, target, version)
+            }
+            _ => {
+                let format = "HTTP request line";
+                return Err(ScanError::new(
+                    ScanErrorKind::InvalidFormat { format },
+                    start,
+                ));
+            }
+        };
+
+        self.consume(start, start + line.len(), start + len)?;
+        Ok(request)
+    }
+
+    /// Scans the next HTTP header field, such as `Content-Type: text/html`.
+    ///
+    /// The value is trimmed of surrounding spaces and tabs. Obsolete line
+    /// folding is undone: continuation lines starting with a space or a tab
+    /// are joined to the value with a single space. Line terminators may be
+    /// `\r\n` or `\n`.
+    ///
+    /// The blank line ending the header section is consumed by the call
+    /// that returns `None` there, so that the body follows.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((name, value))` for the next header, with the value borrowed
+    ///   from the input unless it was folded.
+    /// * `None` at the end of the header section, or if the next line is not
+    ///   a header, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("Host: example.org\r\nX-Long: a\r\n  b\r\n\r\nbody");
+    /// assert_eq!(scanner.next_header(), Some(("Host", "example.org".into())));
+    /// assert_eq!(scanner.next_header(), Some(("X-Long", "a b".into())));
+    /// assert_eq!(scanner.next_header(), None);
+    /// assert_eq!(scanner.get_remaining(), "body");
+    /// ```
+    pub fn next_header(&mut self) -> Option<(&'a str, Cow<'a, str>)> {
+        self.try_next_header().ok().flatten()
+    }
+
+    /// Scans the next HTTP header field.
+    ///
+    /// See [`Scanner::next_header`].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some((name, value)))` for the next header.
+    /// * `Ok(None)` at the blank line ending the header section, which is
+    ///   consumed.
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if no input remains.
+    /// * [`ScanErrorKind::InvalidFormat`] if the next line is not a header.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_header(&mut self) -> Result<Option<(&'a str, Cow<'a, str>)>, ScanError> {
+        let remaining = self.get_remaining();
+        let start = self.position;
+
+        if remaining.is_empty() {
+            return Err(self.error(ScanErrorKind::EndOfInput));
+        }
+
+        let (first, mut len) = line(self.newline_policy, remaining);
+        if first.is_empty() {
+            self.consume(start, start, start + len)?;
+            return Ok(None);
+        }
+
+        let invalid = || {
+            let format = "HTTP header";
+            ScanError::new(ScanErrorKind::InvalidFormat { format }, start)
+        };
+        let (name, value) = first.split_once(':').ok_or_else(invalid)?;
+        if name.is_empty() || name.contains([' ', '\t']) {
+            return Err(invalid());
+        }
+
+        let mut value = Cow::Borrowed(value.trim_matches([' ', '\t']));
+        let mut end = first.len();
+        while remaining[len..].starts_with([' ', '\t']) {
+            let (continuation, continuation_len) = line(self.newline_policy, &remaining[len..]);
+            let value = value.to_mut();
+            if !value.is_empty() {
+                value.push(' ');
+            }
+            value.push_str(continuation.trim_matches([' ', '\t']));
+            end = len + continuation.len();
+            len += continuation_len;
+        }
+
+        self.consume(start, start + end, start + len)?;
+        Ok(Some((name, value)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_http_errors() {
+        let mut scanner = Scanner::new("GET  / HTTP/1.1\nbad header\n");
+        let error = scanner.try_next_request_line().unwrap_err();
+        assert_eq!(error.to_string(), "invalid HTTP request line at offset 0");
+
+        scanner.next_line();
+        let error = scanner.try_next_header().unwrap_err();
+        assert_eq!(error.to_string(), "invalid HTTP header at offset 16");
+        assert_eq!(scanner.get_remaining(), "bad header\n");
+    }
+
+    #[test]
+    fn test_header_span_with_folding() {
+        let mut scanner = Scanner::new("A:\n\t1\n 2\nB: x");
+        assert_eq!(scanner.next_header(), Some(("A", "1 2".into())));
+        assert_eq!(scanner.last_span().unwrap().range(), 0..8);
+        assert_eq!(scanner.next_header(), Some(("B", "x".into())));
+        assert_eq!(
+            scanner.try_next_header().unwrap_err().kind(),
+            &ScanErrorKind::EndOfInput
+        );
+    }
+}
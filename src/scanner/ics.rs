@@ -0,0 +1,239 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+use super::{NewlinePolicy, ScanError, ScanErrorKind, Scanner, Span};
+
+/// The byte ranges of a parameter name and value in a content line.
+type Param = (Range<usize>, Range<usize>);
+
+/// A content line of an iCalendar (RFC 5545) or vCard (RFC 6350) file, such
+/// as `DTSTART;TZID=Europe/Lisbon:20240102T090000`.
+///
+/// Produced by [`Scanner::next_ics_property`]. The line is unfolded, so it is
+/// only borrowed from the input when it was not folded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IcsProperty<'a> {
+    line: Cow<'a, str>,
+    name: Range<usize>,
+    params: Vec<Param>,
+    value: Range<usize>,
+    span: Span,
+}
+
+impl IcsProperty<'_> {
+    /// Returns the property name, such as `DTSTART`.
+    pub fn name(&self) -> &str {
+        &self.line[self.name.clone()]
+    }
+
+    /// Returns the value, everything after the first `:` outside of quotes.
+    pub fn value(&self) -> &str {
+        &self.line[self.value.clone()]
+    }
+
+    /// Returns the value of the parameter `name`, compared
+    /// case-insensitively, without its surrounding quotes.
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params()
+            .find(|(param, _)| param.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    }
+
+    /// Returns an iterator over the `(name, value)` pairs of the parameters,
+    /// in order.
+    pub fn params(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.params
+            .iter()
+            .map(|(name, value)| (&self.line[name.clone()], &self.line[value.clone()]))
+    }
+
+    /// Returns the unfolded content line.
+    pub fn as_str(&self) -> &str {
+        &self.line
+    }
+
+    /// Returns the span of the input the content line was scanned from,
+    /// including folds.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Splits an unfolded content line into its parts.
+    fn parse(line: &str) -> Option<(Range<usize>, Vec<Param>, Range<usize>)> {
+        let name_end = line.find([';', ':']).filter(|&end| end > 0)?;
+        let mut params = Vec::new();
+        let mut i = name_end;
+
+        while line[i..].starts_with(';') {
+            let name_start = i + 1;
+            let equals = name_start + line[name_start..].find('=')?;
+            if equals == name_start || line[name_start..equals].contains([';', ':']) {
+                return None;
+            }
+
+            let value_start = equals + 1;
+            let value = match line[value_start..].strip_prefix('"') {
+                Some(quoted) => {
+                    let end = value_start + 1 + quoted.find('"')?;
+                    i = end + 1;
+                    value_start + 1..end
+                }
+                None => {
+                    i = value_start + line[value_start..].find([';', ':'])?;
+                    value_start..i
+                }
+            };
+
+            params.push((name_start..equals, value));
+        }
+
+        line[i..]
+            .starts_with(':')
+            .then(|| (0..name_end, params, i + 1..line.len()))
+    }
+}
+
+/// Removes the line breaks of folded lines, together with the space or tab
+/// starting each continuation.
+fn unfold(policy: NewlinePolicy, raw: &str) -> Cow<'_, str> {
+    if policy.find(raw).is_none() {
+        return Cow::Borrowed(raw);
+    }
+
+    let mut line = String::with_capacity(raw.len());
+    for (i, piece) in policy.split(raw).enumerate() {
+        let piece = if i > 0 { &piece[1..] } else { piece };
+        line.push_str(piece.strip_suffix('\r').unwrap_or(piece));
+    }
+
+    Cow::Owned(line)
+}
+
+impl<'a> Scanner<'a> {
+    /// Scans for the next content line of an iCalendar or vCard file.
+    ///
+    /// A content line has the form `NAME;PARAM=value;...:value`. Lines
+    /// starting with a space or a tab continue the previous line and are
+    /// unfolded. Empty lines are skipped.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(IcsProperty)` if the next line is a valid content line.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new(
+    ///     "BEGIN:VEVENT\r\nSUMMARY;LANGUAGE=en:Team\r\n  meeting\r\nATTENDEE;CN=\"Doe, J\":mailto:j@x.org\r\n",
+    /// );
+    /// assert_eq!(scanner.next_ics_property().unwrap().value(), "VEVENT");
+    ///
+    /// let summary = scanner.next_ics_property().unwrap();
+    /// assert_eq!(summary.name(), "SUMMARY");
+    /// assert_eq!(summary.param("language"), Some("en"));
+    /// assert_eq!(summary.value(), "Team meeting");
+    ///
+    /// let attendee = scanner.next_ics_property().unwrap();
+    /// assert_eq!(attendee.param("CN"), Some("Doe, J"));
+    /// assert_eq!(attendee.value(), "mailto:j@x.org");
+    /// assert!(scanner.next_ics_property().is_none());
+    /// ```
+    pub fn next_ics_property(&mut self) -> Option<IcsProperty<'a>> {
+        self.try_next_ics_property().ok()
+    }
+
+    /// Scans for the next content line of an iCalendar or vCard file.
+    ///
+    /// See [`Scanner::next_ics_property`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only line breaks remain.
+    /// * [`ScanErrorKind::InvalidFormat`] if the next line is not a valid
+    ///   content line.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_ics_property(&mut self) -> Result<IcsProperty<'a>, ScanError> {
+        let remaining = self.get_remaining();
+        let policy = self.newline_policy;
+        let rest = remaining.trim_start_matches(|c| c == '\r' || policy.is_terminator(c));
+        let start = self.position + remaining.len() - rest.len();
+
+        if rest.is_empty() {
+            return Err(self.error(ScanErrorKind::EndOfInput));
+        }
+
+        let mut end = 0;
+        let consumed = loop {
+            match policy.find(&rest[end..]) {
+                Some((i, len)) if rest[end + i + len..].starts_with([' ', '\t']) => end += i + len,
+                Some((i, len)) => {
+                    end += i;
+                    break end + len;
+                }
+                None => {
+                    end = rest.len();
+                    break end;
+                }
+            }
+        };
+
+        let raw = rest[..end].trim_end_matches('\r');
+        let line = unfold(policy, raw);
+        let (name, params, value) = IcsProperty::parse(&line).ok_or_else(|| {
+            ScanError::new(
+                ScanErrorKind::InvalidFormat {
+                    format: "ICS property",
+                },
+                start,
+            )
+        })?;
+
+        self.consume(start, start + raw.len(), start + consumed)?;
+        Ok(IcsProperty {
+            line,
+            name,
+            params,
+            value,
+            span: self.span(start, start + raw.len()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ics_folding_and_span() {
+        let mut scanner = Scanner::new("\nDESCRIPTION:a\n\tb\n c\nEND:VCARD");
+        let property = scanner.next_ics_property().unwrap();
+        assert_eq!(property.value(), "abc");
+        assert_eq!(property.span(), Span::new(1, 20));
+        assert_eq!(property.params().count(), 0);
+        assert_eq!(scanner.get_remaining(), "END:VCARD");
+
+        let property = scanner.next_ics_property().unwrap();
+        assert!(matches!(property.line, Cow::Borrowed("END:VCARD")));
+    }
+
+    #[test]
+    fn test_ics_invalid_lines() {
+        for invalid in ["NOVALUE", ":x", "A;B:x", "A;=c:x", "A;B=\"open:x", "A;B=c"] {
+            let mut scanner = Scanner::new(invalid);
+            let error = scanner.try_next_ics_property().unwrap_err();
+            assert_eq!(
+                error.kind(),
+                &ScanErrorKind::InvalidFormat {
+                    format: "ICS property"
+                },
+                "{invalid}"
+            );
+            assert_eq!(scanner.position(), 0);
+        }
+
+        let property = IcsProperty::parse("X;A=1;B=\"q;:\":v").unwrap();
+        assert_eq!(property.1.len(), 2);
+    }
+}
@@ -0,0 +1,184 @@
+use std::collections::HashSet;
+
+use super::{Scanner, Span};
+
+/// A namespaced identifier such as `std::collections::HashMap`.
+///
+/// Created by [`Scanner::next_ident_path`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdentPath<'a> {
+    /// The whole path, separators included.
+    pub text: &'a str,
+    /// The identifiers of the path, in order.
+    pub segments: Vec<&'a str>,
+    /// The span of the whole path.
+    pub span: Span,
+}
+
+impl<'a> IdentPath<'a> {
+    /// Returns the last segment, e.g. `HashMap` in `std::collections::HashMap`.
+    pub fn name(&self) -> &'a str {
+        self.segments.last().copied().unwrap_or_default()
+    }
+}
+
+/// An identifier-shaped token classified by [`Scanner::next_ident_or_keyword`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IdentOrKeyword<'a> {
+    /// One of the given keywords.
+    Keyword(&'a str),
+    /// Any other identifier.
+    Ident(&'a str),
+}
+
+/// Returns the length of the identifier at the start of `text`, or 0.
+///
+/// An identifier starts with a letter or `_` and continues with letters,
+/// digits and `_`.
+pub(super) fn ident_len(text: &str) -> usize {
+    if !text.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+        return 0;
+    }
+
+    text.find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(text.len())
+}
+
+impl<'a> Scanner<'a> {
+    /// Scans a path of identifiers joined by `separator`, such as
+    /// `std::collections::HashMap` with `"::"` or `pkg.module.Class` with
+    /// `"."`, after skipping leading whitespace.
+    ///
+    /// The path is as long as possible: a separator is only consumed when an
+    /// identifier follows it, so the full stop in `see pkg.Class.` is left
+    /// alone.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(IdentPath)` with the segments and the span of the path.
+    /// * `None` if no identifier follows the cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new(" std::collections::HashMap::new()");
+    /// let path = scanner.next_ident_path("::").unwrap();
+    /// assert_eq!(path.segments, ["std", "collections", "HashMap", "new"]);
+    /// assert_eq!(path.span.range(), 1..31);
+    /// assert_eq!(scanner.get_remaining(), "()");
+    /// ```
+    pub fn next_ident_path(&mut self, separator: &str) -> Option<IdentPath<'a>> {
+        let remaining = self.get_remaining();
+        let trimmed = remaining.trim_start();
+        let offset = remaining.len() - trimmed.len();
+
+        let mut len = ident_len(trimmed);
+        if len == 0 {
+            return None;
+        }
+
+        let mut segments = vec![&trimmed[..len]];
+        while !separator.is_empty() && trimmed[len..].starts_with(separator) {
+            let next = len + separator.len();
+            let segment_len = ident_len(&trimmed[next..]);
+            if segment_len == 0 {
+                break;
+            }
+
+            segments.push(&trimmed[next..next + segment_len]);
+            len = next + segment_len;
+        }
+
+        let start = self.position + offset;
+        self.consume(start, start + len, start + len).ok()?;
+
+        Some(IdentPath {
+            text: &trimmed[..len],
+            segments,
+            span: self.span(start, start + len),
+        })
+    }
+
+    /// Scans an identifier after skipping leading whitespace, classifying it
+    /// as one of `keywords` or as a plain identifier.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(IdentOrKeyword)` with the classified identifier.
+    /// * `None` if no identifier follows the cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use scanner::scanner::{IdentOrKeyword, Scanner};
+    /// let keywords = HashSet::from(["fn", "let"]);
+    /// let mut scanner = Scanner::new("fn main");
+    /// assert_eq!(scanner.next_ident_or_keyword(&keywords), Some(IdentOrKeyword::Keyword("fn")));
+    /// assert_eq!(scanner.next_ident_or_keyword(&keywords), Some(IdentOrKeyword::Ident("main")));
+    /// ```
+    pub fn next_ident_or_keyword(
+        &mut self,
+        keywords: &HashSet<&str>,
+    ) -> Option<IdentOrKeyword<'a>> {
+        let remaining = self.get_remaining();
+        let trimmed = remaining.trim_start();
+
+        let len = ident_len(trimmed);
+        if len == 0 {
+            return None;
+        }
+
+        let start = self.position + remaining.len() - trimmed.len();
+        self.consume(start, start + len, start + len).ok()?;
+
+        let ident = &trimmed[..len];
+        Some(if keywords.contains(ident) {
+            IdentOrKeyword::Keyword(ident)
+        } else {
+            IdentOrKeyword::Ident(ident)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ident_path_dots() {
+        let mut scanner = Scanner::new("see pkg.module.Class. for details");
+        assert_eq!(scanner.next_word(), Some("see"));
+
+        let path = scanner.next_ident_path(".").unwrap();
+        assert_eq!(path.text, "pkg.module.Class");
+        assert_eq!(path.name(), "Class");
+        assert_eq!(scanner.get_remaining(), ". for details");
+        assert_eq!(scanner.next_ident_path("."), None);
+    }
+
+    #[test]
+    fn test_ident_or_keyword() {
+        let keywords = HashSet::from(["if", "else"]);
+        let mut scanner = Scanner::new("  iffy else 9");
+        assert_eq!(
+            scanner.next_ident_or_keyword(&keywords),
+            Some(IdentOrKeyword::Ident("iffy"))
+        );
+        assert_eq!(
+            scanner.next_ident_or_keyword(&keywords),
+            Some(IdentOrKeyword::Keyword("else"))
+        );
+        assert_eq!(scanner.next_ident_or_keyword(&keywords), None);
+        assert_eq!(scanner.get_remaining(), " 9");
+    }
+
+    #[test]
+    fn test_ident_path_single_segment() {
+        let mut scanner = Scanner::new("_x1::");
+        let path = scanner.next_ident_path("::").unwrap();
+        assert_eq!(path.segments, ["_x1"]);
+        assert_eq!(scanner.get_remaining(), "::");
+    }
+}
@@ -0,0 +1,317 @@
+use super::{ScanError, ScanErrorKind, Scanner, Spanned, Token};
+
+/// A token produced by [`Scanner::indent_tokens`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IndentToken<'a> {
+    /// The indentation grew at the start of a line.
+    Indent,
+    /// One level of indentation ended at the start of a line.
+    Dedent,
+    /// The end of a non-blank line.
+    Newline,
+    /// A token scanned by [`Scanner::next_any`].
+    Token(Token<'a>),
+}
+
+/// An iterator producing indentation-aware tokens from a [`Scanner`].
+///
+/// Created by [`Scanner::indent_tokens`].
+pub struct IndentTokens<'s, 'a> {
+    scanner: &'s mut Scanner<'a>,
+    /// Widths of the open indentation levels, the outermost `0` first, each
+    /// with the width of lines that dedented inconsistently into it.
+    levels: Vec<(usize, Option<usize>)>,
+    dedents: usize,
+    at_line_start: bool,
+    finished: bool,
+}
+
+impl<'a> Scanner<'a> {
+    /// Scans the rest of the input as an indentation-significant format,
+    /// such as Python or YAML.
+    ///
+    /// Tokens are scanned with [`Scanner::next_any`]. In addition, every
+    /// non-blank line ends with [`IndentToken::Newline`], and a line
+    /// indented deeper than the previous one starts with
+    /// [`IndentToken::Indent`] while a line indented less starts with one
+    /// [`IndentToken::Dedent`] per level it closes. Blank lines are skipped.
+    /// At the end of input, every open level is closed. A tab advances the
+    /// indentation to the next multiple of 8.
+    ///
+    /// The iterator yields an error of kind
+    /// [`ScanErrorKind::InconsistentIndentation`] for a line that dedents
+    /// to a width matching no open level, and continues at the closest
+    /// enclosing level. Later lines of that width belong to the same level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{IndentToken, Scanner, Token};
+    /// let mut scanner = Scanner::new("if x:\n    y\nz\n");
+    /// let tokens: Vec<_> = scanner
+    ///     .indent_tokens()
+    ///     .map(|token| token.unwrap().value)
+    ///     .collect();
+    /// assert_eq!(
+    ///     tokens,
+    ///     [
+    ///         IndentToken::Token(Token::Word("if")),
+    ///         IndentToken::Token(Token::Word("x")),
+    ///         IndentToken::Token(Token::Punct(':')),
+    ///         IndentToken::Newline,
+    ///         IndentToken::Indent,
+    ///         IndentToken::Token(Token::Word("y")),
+    ///         IndentToken::Newline,
+    ///         IndentToken::Dedent,
+    ///         IndentToken::Token(Token::Word("z")),
+    ///         IndentToken::Newline,
+    ///     ]
+    /// );
+    /// ```
+    pub fn indent_tokens(&mut self) -> IndentTokens<'_, 'a> {
+        IndentTokens {
+            scanner: self,
+            levels: vec![(0, None)],
+            dedents: 0,
+            at_line_start: true,
+            finished: false,
+        }
+    }
+}
+
+impl<'a> IndentTokens<'_, 'a> {
+    /// Wraps `value` in a span over `start..end` of the input.
+    fn spanned(
+        &self,
+        value: IndentToken<'a>,
+        start: usize,
+        end: usize,
+    ) -> Spanned<IndentToken<'a>> {
+        Spanned {
+            value,
+            span: self.scanner.span(start, end),
+        }
+    }
+
+    /// Measures the indentation of the next non-blank line, skipping blank
+    /// lines, and compares it to the open levels.
+    fn start_line(&mut self) -> Option<Result<Spanned<IndentToken<'a>>, ScanError>> {
+        loop {
+            let remaining = self.scanner.get_remaining();
+            let len = remaining
+                .find(|c: char| c != ' ' && c != '\t')
+                .unwrap_or(remaining.len());
+            let rest = &remaining[len..];
+
+            if rest.is_empty() {
+                self.scanner.take(len);
+                self.dedents = self.levels.len() - 1;
+                self.levels.truncate(1);
+                self.finished = true;
+                return None;
+            }
+
+            let terminator = self
+                .scanner
+                .newline_policy
+                .terminator_len(rest)
+                .or_else(|| rest.starts_with("\r\n").then_some(2));
+            if let Some(terminator) = terminator {
+                let blank = len + terminator;
+                if self.scanner.take(blank).is_empty() {
+                    self.finished = true;
+                    return None;
+                }
+                continue;
+            }
+
+            let width = remaining[..len].chars().fold(0, |width, c| match c {
+                '\t' => (width / 8 + 1) * 8,
+                _ => width + 1,
+            });
+            let start = self.scanner.position();
+            self.scanner.take(len);
+            self.at_line_start = false;
+
+            let (current, alias) = self.levels.last().copied().unwrap_or_default();
+            if width > current && alias != Some(width) {
+                self.levels.push((width, None));
+                return Some(Ok(self.spanned(IndentToken::Indent, start, start + len)));
+            }
+
+            while self
+                .levels
+                .last()
+                .is_some_and(|&(level, alias)| level > width && alias != Some(width))
+            {
+                self.levels.pop();
+                self.dedents += 1;
+            }
+
+            let (level, alias) = self.levels.last_mut()?;
+            if *level != width && *alias != Some(width) {
+                *alias = Some(width);
+                return Some(Err(self
+                    .scanner
+                    .error(ScanErrorKind::InconsistentIndentation)));
+            }
+
+            return None;
+        }
+    }
+}
+
+impl<'a> Iterator for IndentTokens<'_, 'a> {
+    type Item = Result<Spanned<IndentToken<'a>>, ScanError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.dedents > 0 {
+                self.dedents -= 1;
+                let position = self.scanner.position();
+                return Some(Ok(self.spanned(IndentToken::Dedent, position, position)));
+            }
+
+            if self.finished {
+                return None;
+            }
+
+            if self.at_line_start {
+                if let Some(token) = self.start_line() {
+                    return Some(token);
+                }
+                continue;
+            }
+
+            let token = match self.scanner.try_next_any() {
+                Ok(token) => token,
+                Err(error) => {
+                    self.finished = true;
+                    return Some(Err(error));
+                }
+            };
+            let span = match token {
+                Token::Eof => {
+                    let position = self.scanner.position();
+                    self.at_line_start = true;
+                    return Some(Ok(self.spanned(IndentToken::Newline, position, position)));
+                }
+                _ => self.scanner.last_span()?,
+            };
+
+            let value = match token {
+                Token::Newline => {
+                    self.at_line_start = true;
+                    IndentToken::Newline
+                }
+                token => IndentToken::Token(token),
+            };
+
+            return Some(Ok(Spanned { value, span }));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::Span;
+
+    fn values(input: &str) -> Vec<Result<IndentToken<'_>, ScanErrorKind>> {
+        let mut scanner = Scanner::new(input);
+        scanner
+            .indent_tokens()
+            .map(|token| token.map(|token| token.value).map_err(|e| e.kind().clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_nested_levels_and_blank_lines() {
+        use IndentToken::*;
+        assert_eq!(
+            values("a\n  b\n\n  \n\tc\nd"),
+            [
+                Ok(Token(super::Token::Word("a"))),
+                Ok(Newline),
+                Ok(Indent),
+                Ok(Token(super::Token::Word("b"))),
+                Ok(Newline),
+                Ok(Indent),
+                Ok(Token(super::Token::Word("c"))),
+                Ok(Newline),
+                Ok(Dedent),
+                Ok(Dedent),
+                Ok(Token(super::Token::Word("d"))),
+                Ok(Newline),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_inconsistent_dedent_and_spans() {
+        use IndentToken::*;
+        assert_eq!(
+            values("a\n    b\n  c\n  d\ne\n"),
+            [
+                Ok(Token(super::Token::Word("a"))),
+                Ok(Newline),
+                Ok(Indent),
+                Ok(Token(super::Token::Word("b"))),
+                Ok(Newline),
+                Err(ScanErrorKind::InconsistentIndentation),
+                Ok(Dedent),
+                Ok(Token(super::Token::Word("c"))),
+                Ok(Newline),
+                Ok(Token(super::Token::Word("d"))),
+                Ok(Newline),
+                Ok(Token(super::Token::Word("e"))),
+                Ok(Newline),
+            ]
+        );
+
+        let mut scanner = Scanner::new("x\n  y");
+        let spans: Vec<_> = scanner
+            .indent_tokens()
+            .map(|token| token.unwrap().span)
+            .collect();
+        assert_eq!(
+            spans,
+            [
+                Span::new(0, 1),
+                Span::new(1, 2),
+                Span::new(2, 4),
+                Span::new(4, 5),
+                Span::new(5, 5),
+                Span::new(5, 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_universal_newlines() {
+        use IndentToken::*;
+        let mut scanner = Scanner::new("a\r  b\r\n\u{2028}c # x\u{85}d")
+            .with_newline_policy(crate::scanner::NewlinePolicy::Universal);
+        let tokens: Vec<_> = scanner
+            .indent_tokens()
+            .map(|token| token.unwrap().value)
+            .collect();
+        assert_eq!(
+            tokens,
+            [
+                Token(super::Token::Word("a")),
+                Newline,
+                Indent,
+                Token(super::Token::Word("b")),
+                Newline,
+                Dedent,
+                Token(super::Token::Word("c")),
+                Token(super::Token::Punct('#')),
+                Token(super::Token::Word("x")),
+                Newline,
+                Token(super::Token::Word("d")),
+                Newline,
+            ]
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use super::token::lex;
+use super::{Scanner, Span, TokenKind};
+
+/// One entry of a [`TokenIndex`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexedToken {
+    /// The kind of the token.
+    pub kind: TokenKind,
+    /// Byte offset of the start of the token in the input.
+    pub start: usize,
+    /// Byte offset of the end of the token in the input.
+    pub end: usize,
+}
+
+impl IndexedToken {
+    /// Returns the span of the token.
+    pub fn span(&self) -> Span {
+        Span::new(self.start, self.end)
+    }
+}
+
+/// A table of every token of an input, for random access.
+///
+/// Created by [`Scanner::index_tokens`]. Tokens are classified with the same
+/// rules as [`TokenKind`] and stored in input order, so lookups by index are
+/// O(1) and lookups by offset are O(log n).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenIndex<'a> {
+    input: &'a str,
+    tokens: Vec<IndexedToken>,
+}
+
+impl<'a> TokenIndex<'a> {
+    /// Returns the number of tokens.
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    /// Returns `true` if there are no tokens.
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// Returns the `i`-th token.
+    pub fn token(&self, i: usize) -> Option<IndexedToken> {
+        self.tokens.get(i).copied()
+    }
+
+    /// Returns the text of the `i`-th token.
+    pub fn text(&self, i: usize) -> Option<&'a str> {
+        self.tokens
+            .get(i)
+            .map(|token| &self.input[token.start..token.end])
+    }
+
+    /// Returns the input from the start of the `i`-th token to the end of
+    /// the `j`-th token, including whatever lies between them.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` with the slice.
+    /// * `None` if either index is out of bounds or `j < i`.
+    pub fn slice_between(&self, i: usize, j: usize) -> Option<&'a str> {
+        if j < i {
+            return None;
+        }
+
+        let (first, last) = (self.tokens.get(i)?, self.tokens.get(j)?);
+        Some(&self.input[first.start..last.end])
+    }
+
+    /// Returns the index of the token containing the byte `offset`.
+    pub fn token_at(&self, offset: usize) -> Option<usize> {
+        let i = self.tokens.partition_point(|token| token.end <= offset);
+        self.tokens
+            .get(i)
+            .filter(|token| token.start <= offset)
+            .map(|_| i)
+    }
+
+    /// Returns the index of the first token starting at or after the byte
+    /// `offset`.
+    pub fn first_from(&self, offset: usize) -> Option<usize> {
+        let i = self.tokens.partition_point(|token| token.start < offset);
+        (i < self.tokens.len()).then_some(i)
+    }
+
+    /// Returns an iterator over the tokens.
+    pub fn iter(&self) -> impl Iterator<Item = IndexedToken> + '_ {
+        self.tokens.iter().copied()
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Indexes every token of the remaining input in a single pass, without
+    /// consuming anything.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{Scanner, TokenKind};
+    /// let scanner = Scanner::new("let x = 42;");
+    /// let index = scanner.index_tokens();
+    /// assert_eq!(index.len(), 5);
+    /// assert_eq!(index.token(3).unwrap().kind, TokenKind::Int);
+    /// assert_eq!(index.slice_between(1, 3), Some("x = 42"));
+    /// assert_eq!(index.token_at(9), Some(3));
+    /// ```
+    pub fn index_tokens(&self) -> TokenIndex<'a> {
+        let mut tokens = Vec::new();
+        let mut offset = self.position;
+
+        while let Some((kind, start, end)) = lex(
+            &self.input[offset..],
+            self.input[..offset].chars().next_back(),
+            self.sign_policy,
+            self.newline_policy,
+        ) {
+            tokens.push(IndexedToken {
+                kind,
+                start: offset + start,
+                end: offset + end,
+            });
+            offset += end;
+        }
+
+        TokenIndex {
+            input: self.input,
+            tokens,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_lookups() {
+        let mut scanner = Scanner::new("skip\nf(a, 1.5)\n");
+        scanner.next_line();
+        let index = scanner.index_tokens();
+
+        let kinds: Vec<_> = index.iter().map(|token| token.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                TokenKind::Word,
+                TokenKind::Punct,
+                TokenKind::Word,
+                TokenKind::Punct,
+                TokenKind::Float,
+                TokenKind::Punct,
+                TokenKind::Newline,
+            ]
+        );
+        assert_eq!(index.token(0).unwrap().span(), Span::new(5, 6));
+        assert_eq!(index.text(4), Some("1.5"));
+        assert_eq!(index.token_at(9), None);
+        assert_eq!(index.first_from(9), Some(4));
+        assert_eq!(index.slice_between(3, 1), None);
+        assert_eq!(index.text(7), None);
+        assert_eq!(scanner.get_remaining(), "f(a, 1.5)\n");
+    }
+}
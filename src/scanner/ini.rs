@@ -0,0 +1,113 @@
+use super::{KeyValues, ScanError, ScanErrorKind, Scanner};
+
+/// An iterator over the `(section, key, value)` entries of an INI file.
+///
+/// Created by [`Scanner::ini_entries`].
+pub struct IniEntries<'s, 'a> {
+    pairs: KeyValues<'s, 'a>,
+    section: &'a str,
+}
+
+impl<'a> Iterator for IniEntries<'_, 'a> {
+    type Item = Result<(&'a str, &'a str, &'a str), ScanError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(section) = self.pairs.scanner.next_ini_section() {
+            self.section = section;
+        }
+
+        let section = self.section;
+        let entry = self.pairs.next()?;
+        Some(entry.map(|(key, value)| (section, key, value)))
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Scans for the next `[section]` header of an INI file.
+    ///
+    /// Blank lines and comment lines starting with `#` or `;` are skipped.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` with the trimmed name between the brackets.
+    /// * `None` if the next line is not a section header, in which case
+    ///   nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("; settings\n[ server ]\nport=80\n");
+    /// assert_eq!(scanner.next_ini_section(), Some("server"));
+    /// assert_eq!(scanner.next_ini_section(), None);
+    /// assert_eq!(scanner.next_key_value('='), Some(("port", "80")));
+    /// ```
+    pub fn next_ini_section(&mut self) -> Option<&'a str> {
+        self.try_next_ini_section().ok()
+    }
+
+    /// Scans for the next `[section]` header of an INI file.
+    ///
+    /// See [`Scanner::next_ini_section`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only blank and comment lines remain.
+    /// * [`ScanErrorKind::InvalidFormat`] if the next line is not a section
+    ///   header.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_ini_section(&mut self) -> Result<&'a str, ScanError> {
+        let checkpoint = self.checkpoint();
+
+        let (start, line) = self.next_content_line()?;
+
+        let name = line
+            .strip_prefix('[')
+            .and_then(|line| line.strip_suffix(']'));
+        name.map(str::trim).ok_or_else(|| {
+            self.restore(checkpoint);
+            let format = "INI section header";
+            ScanError::new(ScanErrorKind::InvalidFormat { format }, start)
+        })
+    }
+
+    /// Returns an iterator over the `key=value` entries of an INI file, each
+    /// with the name of the section it is in.
+    ///
+    /// Entries before the first section header are in the section `""`.
+    /// Entries are read as by [`Scanner::next_key_value`] with `=`, and a
+    /// malformed line is yielded as an error and skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("debug=1\n[db]\nhost = localhost\n\n[cache]\nttl=60\n");
+    /// let entries: Vec<_> = scanner.ini_entries().filter_map(Result::ok).collect();
+    /// assert_eq!(
+    ///     entries,
+    ///     [("", "debug", "1"), ("db", "host", "localhost"), ("cache", "ttl", "60")]
+    /// );
+    /// ```
+    pub fn ini_entries(&mut self) -> IniEntries<'_, 'a> {
+        IniEntries {
+            pairs: self.key_values('='),
+            section: "",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ini_errors_and_empty_sections() {
+        let mut scanner = Scanner::new("[a]\n[b]\n  oops\nk=v\n[c]\n");
+        let entries: Vec<_> = scanner.ini_entries().collect();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].as_ref().unwrap_err().position(), 10);
+        assert_eq!(entries[1], Ok(("b", "k", "v")));
+        assert_eq!(scanner.get_remaining(), "");
+    }
+}
@@ -0,0 +1,169 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, RandomState};
+
+use super::Scanner;
+
+/// A small copyable handle to a string interned in a [`Scanner`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    /// Returns the index of the symbol in interning order.
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// The table of strings interned in a [`Scanner`].
+///
+/// Strings borrowed from the input are stored without copying; only strings
+/// interned with [`Scanner::intern_owned`] are owned by the table. Every
+/// string is stored once and looked up by its hash.
+#[derive(Debug, Clone, Default)]
+pub struct Interner<'a> {
+    /// The last symbol interned for each hash.
+    symbols: HashMap<u64, Symbol>,
+    strings: Vec<Cow<'a, str>>,
+    /// For each symbol, the symbol interned before it with the same hash.
+    collisions: Vec<Option<Symbol>>,
+    hasher: RandomState,
+}
+
+impl<'a> Interner<'a> {
+    /// Returns the number of distinct strings interned.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns `true` if nothing was interned.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Returns the symbol of `string`, if it was interned.
+    pub fn get(&self, string: &str) -> Option<Symbol> {
+        self.find(self.hasher.hash_one(string), string)
+    }
+
+    /// Returns the string of a symbol.
+    pub fn resolve(&self, symbol: Symbol) -> Option<&str> {
+        self.strings
+            .get(symbol.index())
+            .map(|string| string.as_ref())
+    }
+
+    /// Returns the symbol of `string`, whose hash is `hash`, if it was
+    /// interned.
+    fn find(&self, hash: u64, string: &str) -> Option<Symbol> {
+        let mut candidate = self.symbols.get(&hash).copied();
+        while let Some(symbol) = candidate {
+            if self.strings[symbol.index()] == string {
+                return Some(symbol);
+            }
+            candidate = self.collisions[symbol.index()];
+        }
+        None
+    }
+
+    fn intern(&mut self, string: Cow<'a, str>) -> Symbol {
+        let hash = self.hasher.hash_one(string.as_ref());
+        if let Some(symbol) = self.find(hash, &string) {
+            return symbol;
+        }
+
+        let symbol = Symbol(u32::try_from(self.strings.len()).expect("too many symbols"));
+        self.strings.push(string);
+        self.collisions.push(self.symbols.insert(hash, symbol));
+        symbol
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Interns `token`, returning a symbol that compares equal for equal
+    /// strings.
+    ///
+    /// Tokens returned by the scanner borrow the input, so interning them
+    /// does not copy them. Symbols let parsers build ASTs out of small
+    /// copyable values instead of carrying the input's lifetime everywhere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("x y x");
+    /// let x = scanner.next_word().unwrap();
+    /// let x = scanner.intern(x);
+    /// let y = scanner.next_word().unwrap();
+    /// let y = scanner.intern(y);
+    /// let again = scanner.next_word().unwrap();
+    /// assert_eq!(scanner.intern(again), x);
+    /// assert_ne!(x, y);
+    /// assert_eq!(scanner.resolve(y), Some("y"));
+    /// ```
+    pub fn intern(&mut self, token: &'a str) -> Symbol {
+        self.interner.intern(Cow::Borrowed(token))
+    }
+
+    /// Interns a string that does not borrow the input, such as a
+    /// transformed token, storing a copy of it in the scanner.
+    pub fn intern_owned(&mut self, string: impl Into<String>) -> Symbol {
+        self.interner.intern(Cow::Owned(string.into()))
+    }
+
+    /// Returns the string of a symbol interned in this scanner.
+    pub fn resolve(&self, symbol: Symbol) -> Option<&str> {
+        self.interner.resolve(symbol)
+    }
+
+    /// Returns the table of strings interned in this scanner.
+    pub fn interner(&self) -> &Interner<'a> {
+        &self.interner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intern_mixed() {
+        let mut scanner = Scanner::new("SELECT a FROM t");
+        let keyword = scanner.next_word().unwrap();
+        let select = scanner.intern_owned(keyword.to_lowercase());
+        let a = scanner.next_word().unwrap();
+        let a = scanner.intern(a);
+
+        assert_eq!(scanner.intern_owned("select"), select);
+        assert_eq!(scanner.intern_owned("a"), a);
+        assert_eq!(scanner.interner().len(), 2);
+        assert_eq!(scanner.interner().get("a"), Some(a));
+        assert_eq!(scanner.resolve(select), Some("select"));
+    }
+
+    #[test]
+    fn test_lookahead_keeps_interner() {
+        let mut scanner = Scanner::new("a 1");
+        let a = scanner.next_word().unwrap();
+        let a = scanner.intern(a);
+        assert!(scanner.has_next_number::<i32>());
+        assert_eq!(scanner.resolve(a), Some("a"));
+    }
+
+    #[test]
+    fn test_hash_collisions_are_chained() {
+        let mut interner = Interner::default();
+        let a = interner.intern(Cow::Borrowed("a"));
+        let b = Symbol(1);
+        interner.strings.push(Cow::Borrowed("b"));
+        interner.collisions.push(Some(a));
+        interner.symbols.insert(7, b);
+        interner.symbols.insert(8, a);
+
+        assert_eq!(interner.find(7, "a"), Some(a));
+        assert_eq!(interner.find(7, "b"), Some(b));
+        assert_eq!(interner.find(7, "c"), None);
+        assert_eq!(interner.find(8, "b"), None);
+        assert_eq!(interner.get("a"), Some(a));
+    }
+}
@@ -0,0 +1,201 @@
+use super::{ScanError, ScanErrorKind, Scanner};
+
+/// How deeply arrays and objects may nest in [`Scanner::next_json_value`].
+const MAX_DEPTH: usize = 128;
+
+/// Returns the number of leading JSON whitespace bytes of `text`.
+fn whitespace(text: &[u8]) -> usize {
+    text.iter()
+        .take_while(|b| matches!(b, b' ' | b'\t' | b'\n' | b'\r'))
+        .count()
+}
+
+/// Returns the length of the JSON string at the start of `text`, quotes
+/// included.
+fn string_len(text: &[u8]) -> Option<usize> {
+    let mut i = 1;
+    loop {
+        match *text.get(i)? {
+            b'"' => return Some(i + 1),
+            b'\\' => match *text.get(i + 1)? {
+                b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't' => i += 2,
+                b'u' => {
+                    let hex = text.get(i + 2..i + 6)?;
+                    if !hex.iter().all(u8::is_ascii_hexdigit) {
+                        return None;
+                    }
+                    i += 6;
+                }
+                _ => return None,
+            },
+            0x00..=0x1f => return None,
+            _ => i += 1,
+        }
+    }
+}
+
+/// Returns the length of the JSON number at the start of `text`.
+fn number_len(text: &[u8]) -> Option<usize> {
+    let digits = |from: usize| {
+        text[from.min(text.len())..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+
+    let mut i = usize::from(text.first() == Some(&b'-'));
+    match digits(i) {
+        0 => return None,
+        n if n > 1 && text[i] == b'0' => return None,
+        n => i += n,
+    }
+
+    if text.get(i) == Some(&b'.') {
+        let n = digits(i + 1);
+        if n == 0 {
+            return None;
+        }
+        i += 1 + n;
+    }
+
+    if matches!(text.get(i), Some(b'e' | b'E')) {
+        i += 1;
+        if matches!(text.get(i), Some(b'+' | b'-')) {
+            i += 1;
+        }
+        let n = digits(i);
+        if n == 0 {
+            return None;
+        }
+        i += n;
+    }
+
+    Some(i)
+}
+
+/// Returns the length of the JSON value at the start of `text`, which must
+/// not start with whitespace.
+fn value_len(text: &[u8], depth: usize) -> Option<usize> {
+    match *text.first()? {
+        b'"' => string_len(text),
+        b'{' | b'[' if depth < MAX_DEPTH => container_len(text, depth + 1),
+        b't' => text.starts_with(b"true").then_some(4),
+        b'f' => text.starts_with(b"false").then_some(5),
+        b'n' => text.starts_with(b"null").then_some(4),
+        b'-' | b'0'..=b'9' => number_len(text),
+        _ => None,
+    }
+}
+
+/// Returns the length of the JSON array or object at the start of `text`.
+fn container_len(text: &[u8], depth: usize) -> Option<usize> {
+    let (object, close) = match text[0] {
+        b'{' => (true, b'}'),
+        _ => (false, b']'),
+    };
+
+    let mut i = 1 + whitespace(&text[1..]);
+    if text.get(i) == Some(&close) {
+        return Some(i + 1);
+    }
+
+    loop {
+        if object {
+            if text.get(i) != Some(&b'"') {
+                return None;
+            }
+            i += string_len(&text[i..])?;
+            i += whitespace(&text[i..]);
+            if text.get(i) != Some(&b':') {
+                return None;
+            }
+            i += 1 + whitespace(&text[i + 1..]);
+        }
+
+        i += value_len(&text[i..], depth)?;
+        i += whitespace(&text[i..]);
+        match *text.get(i)? {
+            b',' => i += 1 + whitespace(&text[i + 1..]),
+            b if b == close => return Some(i + 1),
+            _ => return None,
+        }
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Scans for the next JSON value and returns it as written.
+    ///
+    /// Leading whitespace is skipped. The value may be an object, an array,
+    /// a string, a number or one of `true`, `false` and `null`, and is
+    /// checked against the JSON grammar, so that it can be handed to a JSON
+    /// parser or skipped over. Arrays and objects may nest 128 levels deep.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` with the raw value.
+    /// * `None` if no valid value is next, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new(r#"INFO {"user": "a\"b", "ids": [1, 2.5e3]} done"#);
+    /// scanner.next_word();
+    /// assert_eq!(
+    ///     scanner.next_json_value(),
+    ///     Some(r#"{"user": "a\"b", "ids": [1, 2.5e3]}"#)
+    /// );
+    /// assert_eq!(scanner.next_json_value(), None);
+    /// assert_eq!(scanner.next_word(), Some("done"));
+    /// ```
+    pub fn next_json_value(&mut self) -> Option<&'a str> {
+        self.try_next_json_value().ok()
+    }
+
+    /// Scans for the next JSON value and returns it as written.
+    ///
+    /// See [`Scanner::next_json_value`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only whitespace remains.
+    /// * [`ScanErrorKind::InvalidFormat`] if no valid value is next.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_json_value(&mut self) -> Result<&'a str, ScanError> {
+        let remaining = self.get_remaining();
+        let trimmed = remaining.trim_start();
+        let start = self.position + remaining.len() - trimmed.len();
+
+        if trimmed.is_empty() {
+            return Err(self.error(ScanErrorKind::EndOfInput));
+        }
+
+        let len = value_len(trimmed.as_bytes(), 0).ok_or_else(|| {
+            let format = "JSON value";
+            ScanError::new(ScanErrorKind::InvalidFormat { format }, start)
+        })?;
+        self.consume(start, start + len, start + len)?;
+        Ok(&trimmed[..len])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_grammar() {
+        let len = |text: &str| value_len(text.as_bytes(), 0);
+        assert_eq!(len("[]x"), Some(2));
+        assert_eq!(len("{ \"a\" : { } , \"b\":[null,true]}"), Some(30));
+        assert_eq!(len("-0.5E+10,"), Some(8));
+        assert_eq!(len("\"\\u00e9\\n\""), Some(10));
+        assert_eq!(len(&"[".repeat(200)), None);
+
+        for invalid in [
+            "[1,]", "{\"a\"}", "{1:2}", "01", "1.", "-", "\"\\x\"", "\"\n\"", "nul", "[1 2]",
+        ] {
+            assert_eq!(len(invalid), None, "{invalid}");
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use std::marker::PhantomData;
+
+use serde::Deserialize;
+
+use super::Scanner;
+
+/// An iterator deserializing the remaining lines of a [`Scanner`] as JSON
+/// Lines.
+///
+/// Created by [`Scanner::json_lines`].
+pub struct JsonLines<'s, 'a, T> {
+    scanner: &'s mut Scanner<'a>,
+    item: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> Iterator for JsonLines<'_, 'a, T>
+where
+    T: Deserialize<'a>,
+{
+    type Item = Result<T, serde_json::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = loop {
+            let line = self.scanner.next_line()?;
+            if !line.trim_start().is_empty() {
+                break line;
+            }
+        };
+
+        Some(serde_json::from_str(line))
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Returns an iterator deserializing each remaining line as a JSON
+    /// value of type `T`, as in [JSON Lines](https://jsonlines.org/) and
+    /// ndjson files.
+    ///
+    /// Blank lines are skipped. A line that fails to deserialize is yielded
+    /// as an error and iteration goes on with the next line. Since lines are
+    /// consumed one at a time, the scanner can be used to read a header
+    /// before the JSON body, and `T` may borrow from the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("# export v2\n{\"id\": 1}\n\n{\"id\": 2}\n{oops}\n");
+    /// assert_eq!(scanner.next_line(), Some("# export v2"));
+    ///
+    /// let rows: Vec<Result<HashMap<&str, u32>, _>> = scanner.json_lines().collect();
+    /// assert_eq!(rows[0].as_ref().unwrap()["id"], 1);
+    /// assert_eq!(rows[1].as_ref().unwrap()["id"], 2);
+    /// assert!(rows[2].is_err());
+    /// ```
+    pub fn json_lines<T>(&mut self) -> JsonLines<'_, 'a, T>
+    where
+        T: Deserialize<'a>,
+    {
+        JsonLines {
+            scanner: self,
+            item: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_lines_borrow_and_resume() {
+        let mut scanner = Scanner::new("[\"a\", 1]\r\n[\"b\", 2]\nEND\n");
+        let mut lines = scanner.json_lines::<(&str, u8)>();
+        assert_eq!(lines.next().unwrap().unwrap(), ("a", 1));
+        assert_eq!(lines.next().unwrap().unwrap(), ("b", 2));
+        assert!(lines.next().unwrap().is_err());
+        assert!(lines.next().is_none());
+    }
+}
@@ -0,0 +1,729 @@
+use std::collections::HashMap;
+
+use super::{ScanError, ScanErrorKind, Scanner, Span};
+
+/// The shape of a literal recognized by a [`Lexer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LiteralStyle {
+    /// A run of ASCII digits, such as `42`. Signs are left to operators.
+    Integer,
+    /// Digits with a fractional part and an optional exponent, such as
+    /// `1.5` or `2.0e-3`.
+    Float,
+    /// A double-quoted string with backslash escapes, such as `"a \"b\""`.
+    DoubleQuoted,
+    /// A single-quoted string with backslash escapes, such as `'c'`.
+    SingleQuoted,
+}
+
+impl LiteralStyle {
+    /// Returns the length of the literal at the start of `text`, if any.
+    fn matches(self, text: &str) -> Option<usize> {
+        let digits = |text: &str| {
+            text.find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(text.len())
+        };
+
+        match self {
+            LiteralStyle::Integer => Some(digits(text)).filter(|&len| len > 0),
+            LiteralStyle::Float => {
+                let integer = digits(text);
+                let fraction = text[integer..]
+                    .strip_prefix('.')
+                    .map(digits)
+                    .filter(|&len| len > 0)?;
+                let mut len = integer + 1 + fraction;
+
+                if let Some(exponent) = text[len..].strip_prefix(['e', 'E']) {
+                    let sign = usize::from(exponent.starts_with(['+', '-']));
+                    let exponent_digits = digits(&exponent[sign..]);
+                    if exponent_digits > 0 {
+                        len += 1 + sign + exponent_digits;
+                    }
+                }
+
+                (integer > 0).then_some(len)
+            }
+            LiteralStyle::DoubleQuoted => quoted(text, '"'),
+            LiteralStyle::SingleQuoted => quoted(text, '\''),
+        }
+    }
+}
+
+/// Returns the length of the `quote`-delimited string at the start of `text`.
+fn quoted(text: &str, quote: char) -> Option<usize> {
+    let mut chars = text.char_indices();
+    if chars.next()?.1 != quote {
+        return None;
+    }
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            c if c == quote => return Some(i + c.len_utf8()),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// A token produced by a [`Lexer`], with the span it was scanned from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Spanned<T> {
+    /// The token.
+    pub value: T,
+    /// The span of the input the token was scanned from.
+    pub span: Span,
+}
+
+type Mapper<'a, T> = Box<dyn Fn(&'a str) -> T + 'a>;
+
+/// A change of the active mode triggered by an operator.
+#[derive(Debug, Clone)]
+enum ModeChange {
+    Push(String),
+    Pop,
+}
+
+struct Identifier<'a, T> {
+    is_start: fn(char) -> bool,
+    is_continue: fn(char) -> bool,
+    map: Option<Mapper<'a, T>>,
+}
+
+/// Builds a table-driven [`Lexer`] producing a user-defined token type.
+///
+/// Keywords, operators, an identifier rule and literal styles are
+/// registered once, and the resulting lexer repeatedly picks the longest
+/// match at the cursor. On a tie, literals win over identifiers and
+/// keywords, which win over operators.
+///
+/// Context-sensitive languages can register named modes, each with its
+/// own rules, and operators that push or pop them. See
+/// [`LexerBuilder::mode`].
+///
+/// # Examples
+///
+/// ```
+/// use scanner::scanner::{LexerBuilder, LiteralStyle, Scanner};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Tok<'a> {
+///     Let,
+///     Ident(&'a str),
+///     Int(i64),
+///     Eq,
+///     EqEq,
+///     Semi,
+/// }
+///
+/// let lexer = LexerBuilder::new()
+///     .keyword("let", Tok::Let)
+///     .operator("=", Tok::Eq)
+///     .operator("==", Tok::EqEq)
+///     .operator(";", Tok::Semi)
+///     .identifier(Tok::Ident)
+///     .literal(LiteralStyle::Integer, |text| Tok::Int(text.parse().unwrap()))
+///     .build(Scanner::new("let x = 1; x == 1"));
+///
+/// let tokens: Vec<_> = lexer.map(|token| token.unwrap().value).collect();
+/// assert_eq!(
+///     tokens,
+///     [
+///         Tok::Let,
+///         Tok::Ident("x"),
+///         Tok::Eq,
+///         Tok::Int(1),
+///         Tok::Semi,
+///         Tok::Ident("x"),
+///         Tok::EqEq,
+///         Tok::Int(1),
+///     ]
+/// );
+/// ```
+pub struct LexerBuilder<'a, T> {
+    keywords: HashMap<String, T>,
+    operators: Vec<(String, T, Option<ModeChange>)>,
+    identifier: Identifier<'a, T>,
+    literals: Vec<(LiteralStyle, Mapper<'a, T>)>,
+    text: Option<Mapper<'a, T>>,
+    line_comments: Vec<String>,
+    newline: Option<T>,
+    modes: Vec<(String, LexerBuilder<'a, T>)>,
+}
+
+impl<'a, T> Default for LexerBuilder<'a, T> {
+    fn default() -> Self {
+        LexerBuilder {
+            keywords: HashMap::new(),
+            operators: Vec::new(),
+            identifier: Identifier {
+                is_start: |c| c.is_alphabetic() || c == '_',
+                is_continue: |c| c.is_alphanumeric() || c == '_',
+                map: None,
+            },
+            literals: Vec::new(),
+            text: None,
+            line_comments: Vec::new(),
+            newline: None,
+            modes: Vec::new(),
+        }
+    }
+}
+
+impl<'a, T: Clone> LexerBuilder<'a, T> {
+    /// Creates an empty `LexerBuilder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a keyword, an identifier-shaped word producing `token`.
+    pub fn keyword(mut self, keyword: &str, token: T) -> Self {
+        self.keywords.insert(keyword.to_string(), token);
+        self
+    }
+
+    /// Registers an operator or punctuation producing `token`.
+    pub fn operator(mut self, operator: &str, token: T) -> Self {
+        self.add_operator(operator, token, None);
+        self
+    }
+
+    /// Registers an operator producing `token` and entering the mode
+    /// named `mode`.
+    pub fn push_mode_on(mut self, operator: &str, token: T, mode: &str) -> Self {
+        self.add_operator(operator, token, Some(ModeChange::Push(mode.to_string())));
+        self
+    }
+
+    /// Registers an operator producing `token` and leaving the current
+    /// mode.
+    pub fn pop_mode_on(mut self, operator: &str, token: T) -> Self {
+        self.add_operator(operator, token, Some(ModeChange::Pop));
+        self
+    }
+
+    fn add_operator(&mut self, operator: &str, token: T, change: Option<ModeChange>) {
+        if !operator.is_empty() {
+            self.operators.push((operator.to_string(), token, change));
+        }
+    }
+
+    /// Accepts identifiers, mapping their text to a token.
+    ///
+    /// By default an identifier starts with a letter or `_` and continues
+    /// with letters, digits and `_`; see [`LexerBuilder::identifier_rule`].
+    pub fn identifier<F>(mut self, map: F) -> Self
+    where
+        F: Fn(&'a str) -> T + 'a,
+    {
+        self.identifier.map = Some(Box::new(map));
+        self
+    }
+
+    /// Changes which characters may start and continue an identifier or a
+    /// keyword.
+    pub fn identifier_rule(
+        mut self,
+        is_start: fn(char) -> bool,
+        is_continue: fn(char) -> bool,
+    ) -> Self {
+        self.identifier.is_start = is_start;
+        self.identifier.is_continue = is_continue;
+        self
+    }
+
+    /// Accepts literals of the given style, mapping their text to a token.
+    pub fn literal<F>(mut self, style: LiteralStyle, map: F) -> Self
+    where
+        F: Fn(&'a str) -> T + 'a,
+    {
+        self.literals.push((style, Box::new(map)));
+        self
+    }
+
+    /// Accepts runs of raw text, mapping them to a token.
+    ///
+    /// A text run extends up to the next operator of the rule set and
+    /// includes whitespace, which is then no longer skipped. This suits
+    /// modes such as the inside of a string or a template.
+    pub fn text<F>(mut self, map: F) -> Self
+    where
+        F: Fn(&'a str) -> T + 'a,
+    {
+        self.text = Some(Box::new(map));
+        self
+    }
+
+    /// Skips comments running from `prefix` to the end of the line.
+    pub fn line_comment(mut self, prefix: &str) -> Self {
+        if !prefix.is_empty() {
+            self.line_comments.push(prefix.to_string());
+        }
+        self
+    }
+
+    /// Produces `token` for every line terminator of the scanner's
+    /// [`NewlinePolicy`](super::NewlinePolicy) instead of skipping it as
+    /// whitespace.
+    pub fn newline(mut self, token: T) -> Self {
+        self.newline = Some(token);
+        self
+    }
+
+    /// Registers a named mode with its own rules.
+    ///
+    /// The lexer starts in the rules of this builder. Operators registered
+    /// with [`push_mode_on`](LexerBuilder::push_mode_on) and
+    /// [`pop_mode_on`](LexerBuilder::pop_mode_on) switch between modes, and
+    /// so do [`Lexer::push_mode`] and [`Lexer::pop_mode`]. Modes registered
+    /// on `rules` are registered on this builder as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{LexerBuilder, Scanner};
+    ///
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// enum Tok<'a> {
+    ///     Ident(&'a str),
+    ///     Quote,
+    ///     Text(&'a str),
+    ///     Open,
+    ///     Close,
+    /// }
+    ///
+    /// let lexer = LexerBuilder::new()
+    ///     .identifier(Tok::Ident)
+    ///     .push_mode_on("\"", Tok::Quote, "string")
+    ///     .mode(
+    ///         "string",
+    ///         LexerBuilder::new()
+    ///             .text(Tok::Text)
+    ///             .pop_mode_on("\"", Tok::Quote)
+    ///             .push_mode_on("${", Tok::Open, "code"),
+    ///     )
+    ///     .mode(
+    ///         "code",
+    ///         LexerBuilder::new()
+    ///             .identifier(Tok::Ident)
+    ///             .pop_mode_on("}", Tok::Close),
+    ///     )
+    ///     .build(Scanner::new("say \"hi ${name}!\""));
+    ///
+    /// let tokens: Vec<_> = lexer.map(|token| token.unwrap().value).collect();
+    /// assert_eq!(
+    ///     tokens,
+    ///     [
+    ///         Tok::Ident("say"),
+    ///         Tok::Quote,
+    ///         Tok::Text("hi "),
+    ///         Tok::Open,
+    ///         Tok::Ident("name"),
+    ///         Tok::Close,
+    ///         Tok::Text("!"),
+    ///         Tok::Quote,
+    ///     ]
+    /// );
+    /// ```
+    pub fn mode(mut self, name: &str, mut rules: LexerBuilder<'a, T>) -> Self {
+        let nested = std::mem::take(&mut rules.modes);
+        self.modes.push((name.to_string(), rules));
+        self.modes.extend(nested);
+        self
+    }
+
+    /// Builds a lexer reading tokens from `scanner`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an operator enters a mode that was not registered.
+    pub fn build(mut self, scanner: Scanner<'a>) -> Lexer<'a, T> {
+        let mut modes = std::mem::take(&mut self.modes);
+        modes.insert(0, (String::new(), self));
+
+        for (_, rules) in &modes {
+            for (_, _, change) in &rules.operators {
+                if let Some(ModeChange::Push(mode)) = change {
+                    assert!(
+                        modes.iter().skip(1).any(|(name, _)| name == mode),
+                        "unknown lexer mode {mode:?}"
+                    );
+                }
+            }
+        }
+
+        Lexer {
+            modes,
+            stack: vec![0],
+            scanner,
+            finished: false,
+        }
+    }
+}
+
+/// A lexer producing user-defined tokens from a [`Scanner`].
+///
+/// Created by [`LexerBuilder::build`]. Yields `Err` with
+/// [`ScanErrorKind::NoMatch`] for a character no rule accepts, skipping it so
+/// lexing can continue.
+pub struct Lexer<'a, T> {
+    /// The rules of every mode, the initial rules first.
+    modes: Vec<(String, LexerBuilder<'a, T>)>,
+    /// Indices of the active modes, the current one last.
+    stack: Vec<usize>,
+    scanner: Scanner<'a>,
+    /// Set once the budget has run out, after which no more tokens are
+    /// produced.
+    finished: bool,
+}
+
+impl<'a, T: Clone> Lexer<'a, T> {
+    /// Returns the underlying scanner.
+    pub fn scanner(&self) -> &Scanner<'a> {
+        &self.scanner
+    }
+
+    /// Consumes the lexer, returning the underlying scanner.
+    pub fn into_scanner(self) -> Scanner<'a> {
+        self.scanner
+    }
+
+    /// Returns the name of the current mode, or `None` in the initial rules.
+    pub fn mode(&self) -> Option<&str> {
+        let index = self.current();
+        (index != 0).then(|| self.modes[index].0.as_str())
+    }
+
+    /// Enters the mode named `name`.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the mode exists.
+    /// * `false` otherwise, leaving the current mode unchanged.
+    pub fn push_mode(&mut self, name: &str) -> bool {
+        match self.modes.iter().skip(1).position(|(mode, _)| mode == name) {
+            Some(index) => {
+                self.stack.push(index + 1);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Leaves the current mode, returning to the previous one.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if a mode was left.
+    /// * `false` if the lexer is in its initial rules.
+    pub fn pop_mode(&mut self) -> bool {
+        if self.stack.len() > 1 {
+            self.stack.pop();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn current(&self) -> usize {
+        self.stack.last().copied().unwrap_or(0)
+    }
+
+    fn rules(&self) -> &LexerBuilder<'a, T> {
+        &self.modes[self.current()].1
+    }
+
+    /// Returns the length of the whitespace and comments at the start of
+    /// `text`, stopping at a line terminator if newlines are tokens.
+    ///
+    /// Trivia is not a token, so it is passed over together with the token
+    /// after it rather than consumed on its own.
+    fn trivia_len(&self, text: &str) -> usize {
+        let keep_newlines = self.rules().newline.is_some();
+        let keep_whitespace = self.rules().text.is_some();
+        let policy = self.scanner.newline_policy;
+        let mut len = 0;
+
+        loop {
+            if !keep_whitespace {
+                len = text[len..]
+                    .find(|c: char| {
+                        !c.is_whitespace() || (keep_newlines && policy.is_terminator(c))
+                    })
+                    .map_or(text.len(), |i| len + i);
+            }
+
+            let rest = &text[len..];
+            if !self
+                .rules()
+                .line_comments
+                .iter()
+                .any(|prefix| rest.starts_with(prefix.as_str()))
+            {
+                return len;
+            }
+
+            len += rest.find(|c| policy.is_terminator(c)).unwrap_or(rest.len());
+        }
+    }
+
+    /// Returns the longest match at the cursor as `(length, token, mode
+    /// change)`.
+    fn longest_match(&self, text: &'a str) -> Option<(usize, T, Option<ModeChange>)> {
+        let rules = self.rules();
+        let mut best: Option<(usize, T, Option<ModeChange>)> = None;
+        let mut offer = |len: usize, token: &dyn Fn() -> T, change: &Option<ModeChange>| {
+            if len > 0 && best.as_ref().is_none_or(|(best, _, _)| len > *best) {
+                best = Some((len, token(), change.clone()));
+            }
+        };
+
+        if let Some(map) = &rules.text {
+            let len = text
+                .char_indices()
+                .find(|&(i, _)| {
+                    rules
+                        .operators
+                        .iter()
+                        .any(|(operator, _, _)| text[i..].starts_with(operator.as_str()))
+                })
+                .map_or(text.len(), |(i, _)| i);
+            offer(len, &|| map(&text[..len]), &None);
+        }
+
+        if let Some(token) = &rules.newline {
+            if let Some(len) = self.scanner.newline_policy.terminator_len(text) {
+                offer(len, &|| token.clone(), &None);
+            }
+        }
+
+        for (style, map) in &rules.literals {
+            if let Some(len) = style.matches(text) {
+                offer(len, &|| map(&text[..len]), &None);
+            }
+        }
+
+        let identifier = &rules.identifier;
+        if text.starts_with(identifier.is_start) {
+            let len = text
+                .char_indices()
+                .skip(1)
+                .find(|&(_, c)| !(identifier.is_continue)(c))
+                .map_or(text.len(), |(i, _)| i);
+            let word = &text[..len];
+
+            if let Some(token) = rules.keywords.get(word) {
+                offer(len, &|| token.clone(), &None);
+            } else if let Some(map) = &identifier.map {
+                offer(len, &|| map(word), &None);
+            }
+        }
+
+        for (operator, token, change) in &rules.operators {
+            if text.starts_with(operator.as_str()) {
+                offer(operator.len(), &|| token.clone(), change);
+            }
+        }
+
+        best
+    }
+}
+
+impl<'a, T: Clone> Iterator for Lexer<'a, T> {
+    type Item = Result<Spanned<T>, ScanError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let remaining = self.scanner.get_remaining();
+        let skipped = self.trivia_len(remaining);
+        let remaining = &remaining[skipped..];
+        let start = self.scanner.position() + skipped;
+        let first = remaining.chars().next()?;
+
+        match self.longest_match(remaining) {
+            Some((len, value, change)) => {
+                if self
+                    .scanner
+                    .consume(start, start + len, start + len)
+                    .is_err()
+                {
+                    return self.budget_exceeded();
+                }
+
+                match change {
+                    Some(ModeChange::Push(mode)) => {
+                        self.push_mode(&mode);
+                    }
+                    Some(ModeChange::Pop) => {
+                        self.pop_mode();
+                    }
+                    None => {}
+                }
+
+                Some(Ok(Spanned {
+                    value,
+                    span: self.scanner.span(start, start + len),
+                }))
+            }
+            None => {
+                let end = start + first.len_utf8();
+                if self.scanner.consume(start, end, end).is_err() {
+                    return self.budget_exceeded();
+                }
+                Some(Err(ScanError::new(ScanErrorKind::NoMatch, start)))
+            }
+        }
+    }
+}
+
+impl<T> Lexer<'_, T> {
+    /// Reports that the budget ran out, ending the token stream.
+    fn budget_exceeded(&mut self) -> Option<Result<Spanned<T>, ScanError>> {
+        self.finished = true;
+        Some(Err(self.scanner.error(ScanErrorKind::BudgetExceeded)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Tok<'a> {
+        If,
+        Ident(&'a str),
+        Str(&'a str),
+        Float(&'a str),
+        Int(&'a str),
+        Lt,
+        Le,
+        Newline,
+    }
+
+    fn builder<'a>() -> LexerBuilder<'a, Tok<'a>> {
+        LexerBuilder::new()
+            .keyword("if", Tok::If)
+            .identifier(Tok::Ident)
+            .literal(LiteralStyle::DoubleQuoted, Tok::Str)
+            .literal(LiteralStyle::Float, Tok::Float)
+            .literal(LiteralStyle::Integer, Tok::Int)
+            .operator("<", Tok::Lt)
+            .operator("<=", Tok::Le)
+            .line_comment("#")
+    }
+
+    #[test]
+    fn test_lexer_longest_match_and_spans() {
+        let lexer = builder().build(Scanner::new("if iffy <= 1.5 # note\n\"a\\\"b\" < 2"));
+        let tokens: Vec<_> = lexer.map(Result::unwrap).collect();
+        let values: Vec<_> = tokens.iter().map(|token| token.value.clone()).collect();
+
+        assert_eq!(
+            values,
+            [
+                Tok::If,
+                Tok::Ident("iffy"),
+                Tok::Le,
+                Tok::Float("1.5"),
+                Tok::Str("\"a\\\"b\""),
+                Tok::Lt,
+                Tok::Int("2"),
+            ]
+        );
+        assert_eq!(tokens[1].span, Span::new(3, 7));
+    }
+
+    #[test]
+    fn test_lexer_newlines_and_errors() {
+        let mut lexer = builder()
+            .newline(Tok::Newline)
+            .build(Scanner::new("a\n@ b"));
+
+        assert_eq!(lexer.next().unwrap().unwrap().value, Tok::Ident("a"));
+        assert_eq!(lexer.next().unwrap().unwrap().value, Tok::Newline);
+        let error = lexer.next().unwrap().unwrap_err();
+        assert_eq!(
+            (error.kind(), error.position()),
+            (&ScanErrorKind::NoMatch, 2)
+        );
+        assert_eq!(lexer.next().unwrap().unwrap().value, Tok::Ident("b"));
+        assert!(lexer.next().is_none());
+        assert_eq!(lexer.into_scanner().get_remaining(), "");
+    }
+
+    #[test]
+    fn test_lexer_modes() {
+        let mut lexer = builder()
+            .push_mode_on("/", Tok::Lt, "regex")
+            .mode(
+                "regex",
+                LexerBuilder::new().text(Tok::Str).pop_mode_on("/", Tok::Le),
+            )
+            .build(Scanner::new("a /b c/ d"));
+
+        assert_eq!(lexer.next().unwrap().unwrap().value, Tok::Ident("a"));
+        assert_eq!(lexer.next().unwrap().unwrap().value, Tok::Lt);
+        assert_eq!(lexer.mode(), Some("regex"));
+        assert_eq!(lexer.next().unwrap().unwrap().value, Tok::Str("b c"));
+        assert_eq!(lexer.next().unwrap().unwrap().value, Tok::Le);
+        assert_eq!(lexer.mode(), None);
+        assert!(!lexer.pop_mode());
+
+        assert!(lexer.push_mode("regex"));
+        assert!(!lexer.push_mode("missing"));
+        assert_eq!(lexer.next().unwrap().unwrap().value, Tok::Str(" d"));
+        assert!(lexer.next().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "unknown lexer mode")]
+    fn test_lexer_unknown_mode() {
+        builder()
+            .push_mode_on("{", Tok::Lt, "block")
+            .build(Scanner::new(""));
+    }
+
+    #[test]
+    fn test_lexer_stops_when_budget_runs_out() {
+        let lexer = builder().build(Scanner::new("a b c").with_budget(1, usize::MAX));
+        let tokens: Vec<_> = lexer.collect();
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].as_ref().unwrap().value, Tok::Ident("a"));
+        assert_eq!(
+            *tokens[1].as_ref().unwrap_err().kind(),
+            ScanErrorKind::BudgetExceeded
+        );
+
+        let lexer = builder().build(Scanner::new("# note\nx").with_budget(0, usize::MAX));
+        assert_eq!(lexer.count(), 1);
+
+        let lexer = builder().build(Scanner::new("a  b # note\n c").with_budget(2, usize::MAX));
+        let tokens: Vec<_> = lexer.collect();
+        assert_eq!(tokens[0].as_ref().unwrap().value, Tok::Ident("a"));
+        assert_eq!(tokens[1].as_ref().unwrap().value, Tok::Ident("b"));
+        assert_eq!(
+            *tokens[2].as_ref().unwrap_err().kind(),
+            ScanErrorKind::BudgetExceeded
+        );
+
+        let mut scanner = Scanner::new("a  b # note\n c").with_budget(2, usize::MAX);
+        assert_eq!(scanner.next_word(), Some("a"));
+        assert_eq!(scanner.next_word(), Some("b"));
+    }
+
+    #[test]
+    fn test_literal_styles() {
+        assert_eq!(LiteralStyle::Float.matches("1.5e-3x"), Some(6));
+        assert_eq!(LiteralStyle::Float.matches("1."), None);
+        assert_eq!(LiteralStyle::Float.matches("1e5"), None);
+        assert_eq!(LiteralStyle::SingleQuoted.matches("'\\''"), Some(4));
+        assert_eq!(LiteralStyle::DoubleQuoted.matches("\"open"), None);
+    }
+}
@@ -0,0 +1,153 @@
+use std::str::FromStr;
+
+use super::Scanner;
+
+impl<'a> Scanner<'a> {
+    /// Returns `true` if another token can be scanned, i.e. if anything but
+    /// whitespace remains.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("word  \n");
+    /// assert!(scanner.has_next());
+    /// scanner.next_word();
+    /// assert!(!scanner.has_next());
+    /// ```
+    pub fn has_next(&self) -> bool {
+        self.has_next_word()
+    }
+
+    /// Returns `true` if the next token is a valid `T`, without consuming it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("1 2 3 end");
+    /// let mut sum = 0;
+    /// while scanner.has_next_number::<i32>() {
+    ///     sum += scanner.next_number::<i32>().unwrap();
+    /// }
+    /// assert_eq!(sum, 6);
+    /// assert_eq!(scanner.next_word(), Some("end"));
+    /// ```
+    pub fn has_next_number<T>(&self) -> bool
+    where
+        T: FromStr,
+    {
+        self.fork().try_next_number::<T>().is_ok()
+    }
+
+    /// Returns `true` if [`Scanner::next_word`] would return a word.
+    pub fn has_next_word(&self) -> bool {
+        self.fork().try_next_word().is_ok()
+    }
+
+    /// Returns `true` if [`Scanner::next_line`] would return a line.
+    pub fn has_next_line(&self) -> bool {
+        self.fork().try_next_line().is_ok()
+    }
+
+    /// Returns up to the next `n` words, without consuming them.
+    ///
+    /// # Returns
+    ///
+    /// The words [`Scanner::next_word`] would return, which are fewer than
+    /// `n` if the input or the budget runs out first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("if x then y");
+    /// assert_eq!(scanner.lookahead(3), ["if", "x", "then"]);
+    /// assert_eq!(scanner.lookahead(9).len(), 4);
+    /// assert_eq!(scanner.next_word(), Some("if"));
+    /// ```
+    pub fn lookahead(&self, n: usize) -> Vec<&'a str> {
+        let mut fork = self.fork();
+        (0..n).map_while(|_| fork.next_word()).collect()
+    }
+
+    /// Returns the word `n` words ahead, without consuming anything. The
+    /// next word is at `n` = 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let scanner = Scanner::new("let x = 1");
+    /// assert_eq!(scanner.peek_nth(2), Some("="));
+    /// assert_eq!(scanner.peek_nth(4), None);
+    /// ```
+    pub fn peek_nth(&self, n: usize) -> Option<&'a str> {
+        let mut fork = self.fork();
+        (0..n).try_for_each(|_| fork.next_word().map(|_| ()))?;
+        fork.next_word()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has_next_family() {
+        let mut scanner = Scanner::new("12 abc\n");
+        assert!(scanner.has_next());
+        assert!(scanner.has_next_number::<u8>());
+        assert_eq!(scanner.next_number(), Some(12));
+        assert!(!scanner.has_next_number::<i32>());
+        assert!(scanner.has_next_word());
+        assert_eq!(scanner.next_word(), Some("abc"));
+        assert!(!scanner.has_next());
+        assert!(scanner.has_next_line());
+        assert_eq!(scanner.next_line(), Some(""));
+        assert!(!scanner.has_next_line());
+    }
+
+    #[test]
+    fn test_has_next_number_overflow() {
+        let scanner = Scanner::new("300");
+        assert!(scanner.has_next_number::<u16>());
+        assert!(!scanner.has_next_number::<u8>());
+        assert_eq!(scanner.get_remaining(), "300");
+    }
+
+    #[test]
+    fn test_has_next_respects_budget() {
+        let mut scanner = Scanner::new("a b").with_budget(1, usize::MAX);
+        scanner.next_word();
+        assert!(!scanner.has_next());
+        assert!(scanner.lookahead(2).is_empty());
+    }
+
+    #[test]
+    fn test_lookahead_and_peek_nth() {
+        let mut scanner = Scanner::new("a b c").with_stats();
+        assert_eq!(scanner.lookahead(0), Vec::<&str>::new());
+        assert_eq!(scanner.lookahead(5), ["a", "b", "c"]);
+        assert_eq!(scanner.peek_nth(0), Some("a"));
+        assert_eq!(scanner.peek_nth(2), Some("c"));
+        assert_eq!(scanner.peek_nth(3), None);
+        assert_eq!(scanner.position(), 0);
+        assert_eq!(scanner.stats().unwrap().tokens, 0);
+
+        scanner.next_word();
+        assert_eq!(scanner.lookahead(2), ["b", "c"]);
+        assert_eq!(scanner.peek_nth(1), Some("c"));
+
+        let mut scanner = Scanner::new("a b c d").with_budget(3, usize::MAX);
+        scanner.next_word();
+        assert_eq!(scanner.lookahead(5), ["b", "c"]);
+        assert_eq!(scanner.peek_nth(1), Some("c"));
+        assert_eq!(scanner.peek_nth(2), None);
+        assert_eq!(scanner.next_word(), Some("b"));
+
+        let scanner = Scanner::new("a b c").with_budget(usize::MAX, 3);
+        assert_eq!(scanner.lookahead(3), ["a", "b"]);
+        assert_eq!(scanner.peek_nth(2), None);
+    }
+}
@@ -0,0 +1,252 @@
+use super::{ScanError, ScanErrorKind, Scanner};
+
+/// A tag of XML or HTML markup, scanned by [`Scanner::next_tag`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Tag<'a> {
+    /// A start tag such as `<a href="/">`, or an empty-element tag such as
+    /// `<br/>`.
+    Start {
+        /// The tag name.
+        name: &'a str,
+        /// The attributes in order, with their values unquoted but entities
+        /// left as written. An attribute without a value has an empty one.
+        attributes: Vec<(&'a str, &'a str)>,
+        /// Whether the tag ends with `/>`.
+        self_closing: bool,
+    },
+    /// An end tag such as `</a>`.
+    End {
+        /// The tag name.
+        name: &'a str,
+    },
+    /// The text of a `<!-- comment -->`.
+    Comment(&'a str),
+    /// The text of a `<![CDATA[ section ]]>`.
+    CData(&'a str),
+    /// The text of a declaration such as `<!DOCTYPE html>`, after the `!`.
+    Declaration(&'a str),
+    /// The text of a processing instruction such as `<?xml version="1.0"?>`,
+    /// between the `?`s.
+    ProcessingInstruction(&'a str),
+}
+
+/// The `(name, value)` attributes of a start tag.
+type Attributes<'a> = Vec<(&'a str, &'a str)>;
+
+/// Returns the length of the name at the start of `text`.
+fn name_len(text: &str) -> usize {
+    text.find(|c: char| c.is_whitespace() || matches!(c, '/' | '>' | '='))
+        .unwrap_or(text.len())
+}
+
+/// Parses the attributes of a start tag and the end of it, from after the
+/// tag name to after the `>`.
+///
+/// Returns the attributes, whether the tag is self-closing and the length
+/// of `text` parsed.
+fn attributes(text: &str) -> Option<(Attributes<'_>, bool, usize)> {
+    let mut attributes = Vec::new();
+    let mut i = 0;
+
+    loop {
+        i = text.len() - text[i..].trim_start().len();
+        let rest = &text[i..];
+        if rest.starts_with('>') {
+            return Some((attributes, false, i + 1));
+        }
+        if rest.starts_with("/>") {
+            return Some((attributes, true, i + 2));
+        }
+
+        let name = &rest[..name_len(rest)];
+        if name.is_empty() {
+            return None;
+        }
+        i += name.len();
+
+        let after_name = text[i..].trim_start();
+        let Some(value) = after_name.strip_prefix('=') else {
+            attributes.push((name, ""));
+            continue;
+        };
+        let value = value.trim_start();
+        i = text.len() - value.len();
+
+        let (attribute, len) = match value.chars().next()? {
+            quote @ ('"' | '\'') => {
+                let end = value[1..].find(quote)?;
+                (&value[1..1 + end], end + 2)
+            }
+            _ => {
+                let end = value
+                    .find(|c: char| c.is_whitespace() || c == '>')
+                    .unwrap_or(value.len());
+                (&value[..end], end)
+            }
+        };
+        attributes.push((name, attribute));
+        i += len;
+    }
+}
+
+impl<'a> Tag<'a> {
+    /// Parses the tag at the start of `text`, returning it and its length.
+    fn parse(text: &'a str) -> Option<(Tag<'a>, usize)> {
+        let enclosed = |open: &str, close: &str| {
+            let inner = text.strip_prefix(open)?;
+            let end = inner.find(close)?;
+            Some((&inner[..end], open.len() + end + close.len()))
+        };
+
+        if let Some((comment, len)) = enclosed("<!--", "-->") {
+            return Some((Tag::Comment(comment), len));
+        }
+        if let Some((data, len)) = enclosed("<![CDATA[", "]]>") {
+            return Some((Tag::CData(data), len));
+        }
+        if let Some((declaration, len)) = enclosed("<!", ">") {
+            return Some((Tag::Declaration(declaration), len));
+        }
+        if let Some((instruction, len)) = enclosed("<?", "?>") {
+            return Some((Tag::ProcessingInstruction(instruction), len));
+        }
+
+        if let Some(rest) = text.strip_prefix("</") {
+            let name = &rest[..name_len(rest)];
+            let after = rest[name.len()..].trim_start();
+            if name.is_empty() || !after.starts_with('>') {
+                return None;
+            }
+            return Some((Tag::End { name }, text.len() - after.len() + 1));
+        }
+
+        let rest = text.strip_prefix('<')?;
+        let name = &rest[..name_len(rest)];
+        if name.is_empty() {
+            return None;
+        }
+        let (attributes, self_closing, len) = attributes(&rest[name.len()..])?;
+        let tag = Tag::Start {
+            name,
+            attributes,
+            self_closing,
+        };
+        Some((tag, 1 + name.len() + len))
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Scans for the next XML or HTML tag, comment, CDATA section,
+    /// declaration or processing instruction.
+    ///
+    /// Leading whitespace is skipped. This recognizes single tokens of
+    /// markup only: nesting is not checked and entities are not decoded.
+    /// Attribute values may be double-quoted, single-quoted or unquoted, and
+    /// a quoted value may contain `>`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Tag)` if a well-formed tag is next.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{Scanner, Tag};
+    /// let mut scanner = Scanner::new(r#"<a href="/x?a>b" hidden>Home</a><!-- nav -->"#);
+    /// assert_eq!(
+    ///     scanner.next_tag(),
+    ///     Some(Tag::Start {
+    ///         name: "a",
+    ///         attributes: vec![("href", "/x?a>b"), ("hidden", "")],
+    ///         self_closing: false,
+    ///     })
+    /// );
+    /// assert_eq!(scanner.next_text_until_tag(), Some("Home"));
+    /// assert_eq!(scanner.next_tag(), Some(Tag::End { name: "a" }));
+    /// assert_eq!(scanner.next_tag(), Some(Tag::Comment(" nav ")));
+    /// ```
+    pub fn next_tag(&mut self) -> Option<Tag<'a>> {
+        self.try_next_tag().ok()
+    }
+
+    /// Scans for the next markup tag.
+    ///
+    /// See [`Scanner::next_tag`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only whitespace remains.
+    /// * [`ScanErrorKind::InvalidFormat`] if no well-formed tag is next.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_tag(&mut self) -> Result<Tag<'a>, ScanError> {
+        let remaining = self.get_remaining();
+        let trimmed = remaining.trim_start();
+        let start = self.position + remaining.len() - trimmed.len();
+
+        if trimmed.is_empty() {
+            return Err(self.error(ScanErrorKind::EndOfInput));
+        }
+
+        let (tag, len) = Tag::parse(trimmed)
+            .ok_or_else(|| ScanError::new(ScanErrorKind::InvalidFormat { format: "tag" }, start))?;
+        self.consume(start, start + len, start + len)?;
+        Ok(tag)
+    }
+
+    /// Consumes the text up to the next `<` or the end of the input.
+    ///
+    /// Whitespace is kept, and entities are not decoded.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` with the text before the next tag.
+    /// * `None` if a `<` or the end of the input is next.
+    pub fn next_text_until_tag(&mut self) -> Option<&'a str> {
+        let remaining = self.get_remaining();
+        let len = remaining.find('<').unwrap_or(remaining.len());
+
+        if len == 0 {
+            return None;
+        }
+
+        let start = self.position;
+        self.consume(start, start + len, start + len).ok()?;
+        Some(&remaining[..len])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tag_kinds() {
+        let mut scanner = Scanner::new(
+            "<?xml version='1.0'?>\n<!DOCTYPE note><br/><img src=a.png alt = 'x' /><![CDATA[<b>]]></ p >",
+        );
+        assert_eq!(
+            scanner.next_tag(),
+            Some(Tag::ProcessingInstruction("xml version='1.0'"))
+        );
+        assert_eq!(scanner.next_tag(), Some(Tag::Declaration("DOCTYPE note")));
+        assert!(matches!(
+            scanner.next_tag(),
+            Some(Tag::Start {
+                name: "br",
+                self_closing: true,
+                ..
+            })
+        ));
+        let Some(Tag::Start { attributes, .. }) = scanner.next_tag() else {
+            panic!("expected a start tag");
+        };
+        assert_eq!(attributes, [("src", "a.png"), ("alt", "x")]);
+        assert_eq!(scanner.next_tag(), Some(Tag::CData("<b>")));
+        assert_eq!(scanner.next_tag(), None);
+
+        for invalid in ["< p>", "<a href='x>", "<a", "</>", "<!-- open"] {
+            assert_eq!(Tag::parse(invalid), None, "{invalid}");
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use std::str::FromStr;
+
+use super::{ScanError, ScanErrorKind, Scanner};
+
+impl Scanner<'_> {
+    /// Scans a matrix of `rows` lines with `cols` numbers each.
+    ///
+    /// Blank lines before each row are skipped, so the matrix may directly
+    /// follow a header line such as its dimensions. Every row must hold
+    /// exactly `cols` numbers, parsed as by [`Scanner::next_number`].
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The number of rows to scan.
+    /// * `cols` - The number of values in each row.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Vec<Vec<T>>)` with `rows` rows of `cols` values.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("2 3\n1 2 3\n4 5 6\n");
+    /// let (rows, cols) = (scanner.next_number().unwrap(), scanner.next_number().unwrap());
+    /// let matrix = scanner.next_matrix::<i32>(rows, cols).unwrap();
+    /// assert_eq!(matrix, [[1, 2, 3], [4, 5, 6]]);
+    /// ```
+    pub fn next_matrix<T>(&mut self, rows: usize, cols: usize) -> Option<Vec<Vec<T>>>
+    where
+        T: FromStr,
+    {
+        self.try_next_matrix(rows, cols).ok()
+    }
+
+    /// Scans a matrix of `rows` lines with `cols` numbers each.
+    ///
+    /// See [`Scanner::next_matrix`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if the input ends before the last row.
+    /// * [`ScanErrorKind::InvalidFormat`] if a row holds fewer or more than
+    ///   `cols` values. The error points at the end of a short row, or at the
+    ///   first extra value.
+    /// * [`ScanErrorKind::InvalidNumber`] or [`ScanErrorKind::Overflow`] if a
+    ///   value is not a valid `T`.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{ScanErrorKind, Scanner};
+    /// let mut scanner = Scanner::new("1 2 3\n4 5\n");
+    /// let error = scanner.try_next_matrix::<i32>(2, 3).unwrap_err();
+    /// assert_eq!(error.kind(), &ScanErrorKind::InvalidFormat { format: "matrix row" });
+    /// assert_eq!(error.position(), 9);
+    /// assert_eq!(scanner.get_remaining(), "1 2 3\n4 5\n");
+    /// ```
+    pub fn try_next_matrix<T>(&mut self, rows: usize, cols: usize) -> Result<Vec<Vec<T>>, ScanError>
+    where
+        T: FromStr,
+    {
+        let checkpoint = self.checkpoint();
+
+        let matrix: Result<Vec<_>, _> = (0..rows)
+            .map(|_| {
+                let mut line = self.try_next_line()?;
+                while line.is_empty() {
+                    line = self.try_next_line()?;
+                }
+                self.matrix_row(Some(cols))
+            })
+            .collect();
+
+        if matrix.is_err() {
+            self.restore(checkpoint);
+        }
+        matrix
+    }
+
+    /// Scans the next block of consecutive non-blank lines as a matrix,
+    /// taking its dimensions from the lines themselves.
+    ///
+    /// The block is delimited as by [`Scanner::next_paragraph`]: leading
+    /// blank lines are skipped and the blank line ending the block is
+    /// consumed. Each line is a row, and every row must hold as many values
+    /// as the first. Values are parsed as by [`Scanner::next_number`].
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Vec<Vec<T>>)` with one row per line.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("1 2\n3 4\n\n5 6\n7\n");
+    /// assert_eq!(scanner.next_matrix_block(), Some(vec![vec![1, 2], vec![3, 4]]));
+    /// assert_eq!(scanner.next_matrix_block::<i32>(), None);
+    /// assert_eq!(scanner.get_remaining(), "5 6\n7\n");
+    /// ```
+    pub fn next_matrix_block<T>(&mut self) -> Option<Vec<Vec<T>>>
+    where
+        T: FromStr,
+    {
+        self.try_next_matrix_block().ok()
+    }
+
+    /// Scans the next block of consecutive non-blank lines as a matrix.
+    ///
+    /// See [`Scanner::next_matrix_block`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only blank lines remain.
+    /// * [`ScanErrorKind::InvalidFormat`] if a row holds fewer or more values
+    ///   than the first.
+    /// * [`ScanErrorKind::InvalidNumber`] or [`ScanErrorKind::Overflow`] if a
+    ///   value is not a valid `T`.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_matrix_block<T>(&mut self) -> Result<Vec<Vec<T>>, ScanError>
+    where
+        T: FromStr,
+    {
+        let checkpoint = self.checkpoint();
+        let matrix = self.matrix_block();
+
+        if matrix.is_err() {
+            self.restore(checkpoint);
+        }
+        matrix
+    }
+
+    fn matrix_block<T>(&mut self) -> Result<Vec<Vec<T>>, ScanError>
+    where
+        T: FromStr,
+    {
+        let mut line = self.try_next_line()?;
+        while line.is_empty() {
+            line = self.try_next_line()?;
+        }
+
+        let first = self.matrix_row(None)?;
+        let cols = first.len();
+        let mut matrix = vec![first];
+
+        while !self.get_remaining().is_empty() && !self.try_next_line()?.is_empty() {
+            matrix.push(self.matrix_row(Some(cols))?);
+        }
+        Ok(matrix)
+    }
+
+    /// Parses the values of the line just scanned, requiring exactly `cols`
+    /// of them if given.
+    fn matrix_row<T>(&self, cols: Option<usize>) -> Result<Vec<T>, ScanError>
+    where
+        T: FromStr,
+    {
+        let (start, end) = self.last.unwrap_or((self.position, self.position));
+        let mut row = self.fork();
+        row.input = &self.input[..end];
+        row.position = start;
+
+        let mut values = Vec::new();
+        while cols.is_none_or(|cols| values.len() < cols) {
+            match row.try_next_number() {
+                Ok(value) => values.push(value),
+                Err(error) if error.kind() == &ScanErrorKind::EndOfInput => break,
+                Err(error) => return Err(error),
+            }
+        }
+
+        let invalid = |position| {
+            ScanError::new(
+                ScanErrorKind::InvalidFormat {
+                    format: "matrix row",
+                },
+                position,
+            )
+        };
+        let rest = row.get_remaining();
+        let extra = rest.trim_start();
+        match cols {
+            Some(cols) if values.len() < cols => Err(invalid(end)),
+            _ if !extra.is_empty() => Err(invalid(end - extra.len())),
+            _ => Ok(values),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matrix_errors_are_positioned() {
+        let mut scanner = Scanner::new("1 2\n3 4 5\n");
+        let error = scanner.try_next_matrix::<u8>(2, 2).unwrap_err();
+        assert_eq!(error.position(), 8);
+        assert_eq!(scanner.get_remaining(), "1 2\n3 4 5\n");
+
+        let mut scanner = Scanner::new("\n1 2\n3 x\n");
+        let error = scanner.try_next_matrix_block::<u8>().unwrap_err();
+        assert_eq!(error.kind(), &ScanErrorKind::InvalidNumber);
+        assert_eq!(error.position(), 7);
+
+        let mut scanner = Scanner::new("\n\n");
+        let error = scanner.try_next_matrix_block::<u8>().unwrap_err();
+        assert_eq!(error.kind(), &ScanErrorKind::EndOfInput);
+    }
+}
@@ -0,0 +1,113 @@
+use std::fs::File;
+use std::io;
+use std::path::Path;
+use std::str;
+
+use memmap2::Mmap;
+
+use super::{ByteScanner, ScanError, ScanErrorKind, Scanner};
+
+/// A file mapped into memory, to be scanned without reading it into a
+/// `String` first.
+///
+/// The operating system pages the file in as it is scanned, so multi-gigabyte
+/// files can be scanned with the borrowed `&str` API of [`Scanner`].
+///
+/// The file should not be modified while it is mapped: the mapped text
+/// changing under a scanner can break the guarantees of `&str`.
+///
+/// # Examples
+///
+/// ```
+/// use scanner::scanner::{MappedFile, Scanner};
+/// let path = std::env::temp_dir().join("scanner-mapped-file-example.txt");
+/// std::fs::write(&path, "1 2 3\n").unwrap();
+///
+/// let file = MappedFile::open(&path).unwrap();
+/// let mut scanner = Scanner::from_mmap(&file).unwrap();
+/// assert_eq!(scanner.next_number(), Some(1));
+/// assert_eq!(scanner.get_remaining(), " 2 3\n");
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct MappedFile {
+    map: Mmap,
+}
+
+impl MappedFile {
+    /// Opens the file at `path` and maps it into memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or mapped.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = File::open(path)?;
+        // SAFETY: the map is only read, and as documented on `MappedFile`,
+        // the file must not be modified while it is mapped.
+        let map = unsafe { Mmap::map(&file)? };
+        Ok(MappedFile { map })
+    }
+
+    /// Returns the contents of the file.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.map
+    }
+
+    /// Creates a new [`ByteScanner`] over the file, for files that may not
+    /// be valid UTF-8.
+    pub fn byte_scanner(&self) -> ByteScanner<'_> {
+        ByteScanner::new(&self.map)
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Creates a new `Scanner` over a memory-mapped file, without copying it.
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::InvalidUtf8`] at the first malformed sequence if
+    ///   the file is not valid UTF-8. Use [`MappedFile::byte_scanner`] to
+    ///   scan such files.
+    pub fn from_mmap(file: &'a MappedFile) -> Result<Self, ScanError> {
+        let bytes = file.as_bytes();
+
+        match str::from_utf8(bytes) {
+            Ok(text) => Ok(Scanner::new(text)),
+            Err(error) => {
+                let offset = error.valid_up_to();
+                let len = error.error_len().unwrap_or(bytes.len() - offset);
+                let bytes = bytes[offset..offset + len].to_vec();
+                Err(ScanError::new(ScanErrorKind::InvalidUtf8 { bytes }, offset))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_invalid_and_empty_files() {
+        let path = std::env::temp_dir().join("scanner-mapped-file-test.txt");
+
+        fs::write(&path, b"ok\n\xff").unwrap();
+        let file = MappedFile::open(&path).unwrap();
+        let error = Scanner::from_mmap(&file).unwrap_err();
+        assert_eq!(
+            error.kind(),
+            &ScanErrorKind::InvalidUtf8 { bytes: vec![0xff] }
+        );
+        assert_eq!(error.position(), 3);
+        assert_eq!(file.byte_scanner().next_line(), Some(Ok("ok")));
+        drop(file);
+
+        fs::write(&path, "").unwrap();
+        let file = MappedFile::open(&path).unwrap();
+        assert_eq!(Scanner::from_mmap(&file).unwrap().next_word(), None);
+        drop(file);
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,204 @@
+use super::{ScanError, ScanErrorKind, Scanner};
+
+/// The currency symbols recognized by [`Scanner::next_money`].
+const CURRENCY_SYMBOLS: [char; 16] = [
+    '$', '€', '£', '¥', '¢', '₹', '₽', '₩', '₺', '₪', '₫', '₴', '₦', '₱', '฿', '₿',
+];
+
+/// An amount of money scanned by [`Scanner::next_money`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Money<'a> {
+    /// The currency symbol or ISO 4217 code, as written.
+    pub currency: &'a str,
+    /// The amount, as read by [`Scanner::next_float`].
+    pub amount: f64,
+}
+
+/// Returns the length of the currency symbol or three-letter uppercase code
+/// at the start of `text`, if any.
+fn currency(text: &str) -> Option<usize> {
+    if let Some(symbol) = text.chars().next().filter(|c| CURRENCY_SYMBOLS.contains(c)) {
+        return Some(symbol.len_utf8());
+    }
+
+    let code = text.get(..3)?;
+    let boundary = !text[3..].starts_with(char::is_alphanumeric);
+    (code.bytes().all(|b| b.is_ascii_uppercase()) && boundary).then_some(3)
+}
+
+/// Returns the length of the spaces and tabs at the start of `text`.
+fn blanks(text: &str) -> usize {
+    text.len() - text.trim_start_matches([' ', '\t']).len()
+}
+
+impl<'a> Scanner<'a> {
+    /// Scans for the next percentage, such as `42%` or `-3.5 %`.
+    ///
+    /// Leading whitespace is skipped. The number is read as by
+    /// [`Scanner::next_float`] and followed by a `%`, directly or after
+    /// spaces.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(f64)` with the number before the `%`, so `42%` is `42.0`.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("CPU 42% RAM 12.5 % disk 7");
+    /// scanner.next_word();
+    /// assert_eq!(scanner.next_percent(), Some(42.0));
+    /// scanner.next_word();
+    /// assert_eq!(scanner.next_percent(), Some(12.5));
+    /// scanner.next_word();
+    /// assert_eq!(scanner.next_percent(), None);
+    /// ```
+    pub fn next_percent(&mut self) -> Option<f64> {
+        self.try_next_percent().ok()
+    }
+
+    /// Scans for the next percentage.
+    ///
+    /// See [`Scanner::next_percent`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only whitespace remains.
+    /// * [`ScanErrorKind::InvalidFormat`] if no percentage is next.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_percent(&mut self) -> Result<f64, ScanError> {
+        let (start, trimmed) = self.trimmed_amount("percentage")?;
+        let invalid = ScanError::new(
+            ScanErrorKind::InvalidFormat {
+                format: "percentage",
+            },
+            start,
+        );
+
+        let (len, number) = self.number_format.read(trimmed).ok_or(invalid.clone())?;
+        let len = len + blanks(&trimmed[len..]);
+        if !trimmed[len..].starts_with('%') {
+            return Err(invalid);
+        }
+
+        let value = number.parse().map_err(|_| invalid)?;
+        self.consume(start, start + len + 1, start + len + 1)?;
+        Ok(value)
+    }
+
+    /// Scans for the next amount of money, such as `$1,234.56`, `€3.50`,
+    /// `3,50 €` or `USD 12`.
+    ///
+    /// Leading whitespace is skipped. The currency is a common currency
+    /// symbol or a three-letter uppercase ISO 4217 code, before or after the
+    /// amount and optionally separated from it by spaces. The amount is read
+    /// as by [`Scanner::next_float`], so digit grouping and the decimal
+    /// separator follow the [`NumberFormat`](super::NumberFormat).
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Money)` with the currency and amount.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{NumberFormat, Scanner};
+    /// let mut scanner = Scanner::new("$1,234.56 then 3.50€, USD 12").with_number_format(NumberFormat::US);
+    /// let money = scanner.next_money().unwrap();
+    /// assert_eq!((money.currency, money.amount), ("$", 1234.56));
+    /// scanner.next_word();
+    /// assert_eq!(scanner.next_money().unwrap().currency, "€");
+    /// scanner.expect(",").unwrap();
+    /// assert_eq!(scanner.next_money().unwrap().amount, 12.0);
+    /// ```
+    pub fn next_money(&mut self) -> Option<Money<'a>> {
+        self.try_next_money().ok()
+    }
+
+    /// Scans for the next amount of money.
+    ///
+    /// See [`Scanner::next_money`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only whitespace remains.
+    /// * [`ScanErrorKind::InvalidFormat`] if no amount with a currency is
+    ///   next.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_money(&mut self) -> Result<Money<'a>, ScanError> {
+        let (start, trimmed) = self.trimmed_amount("money")?;
+        let invalid = ScanError::new(ScanErrorKind::InvalidFormat { format: "money" }, start);
+        let format = self.number_format;
+
+        let (currency, len, number) = match currency(trimmed) {
+            Some(prefix) => {
+                let number_start = prefix + blanks(&trimmed[prefix..]);
+                let (len, number) = format
+                    .read(&trimmed[number_start..])
+                    .ok_or(invalid.clone())?;
+                (&trimmed[..prefix], number_start + len, number)
+            }
+            None => {
+                let (len, number) = format.read(trimmed).ok_or(invalid.clone())?;
+                let suffix_start = len + blanks(&trimmed[len..]);
+                let suffix = currency(&trimmed[suffix_start..]).ok_or(invalid.clone())?;
+                let currency = &trimmed[suffix_start..suffix_start + suffix];
+                (currency, suffix_start + suffix, number)
+            }
+        };
+
+        let amount = number.parse().map_err(|_| invalid)?;
+        self.consume(start, start + len, start + len)?;
+        Ok(Money { currency, amount })
+    }
+
+    /// Skips whitespace before an amount, returning its offset and the rest
+    /// of the input from there.
+    ///
+    /// Fails if the amount starts with a sign the
+    /// [`SignPolicy`](super::SignPolicy) reads as an operator.
+    fn trimmed_amount(&self, format: &'static str) -> Result<(usize, &'a str), ScanError> {
+        let remaining = self.get_remaining();
+        let trimmed = remaining.trim_start();
+        let start = self.position + remaining.len() - trimmed.len();
+
+        if trimmed.is_empty() {
+            return Err(self.error(ScanErrorKind::EndOfInput));
+        }
+
+        let previous = self.input[..start].chars().next_back();
+        if trimmed.starts_with(['-', '+']) && !self.sign_policy.is_sign_after(previous) {
+            return Err(ScanError::new(
+                ScanErrorKind::InvalidFormat { format },
+                start,
+            ));
+        }
+
+        Ok((start, trimmed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::NumberFormat;
+
+    #[test]
+    fn test_money_forms() {
+        let mut scanner = Scanner::new("1.234,50 EUR -3 ¥ £ x 12 EURO")
+            .with_number_format(NumberFormat::EUROPEAN);
+        let money = scanner.next_money().unwrap();
+        assert_eq!((money.currency, money.amount), ("EUR", 1234.5));
+        assert_eq!(scanner.next_money().unwrap().amount, -3.0);
+
+        let error = scanner.try_next_money().unwrap_err();
+        assert_eq!(error.to_string(), "invalid money at offset 19");
+        scanner.next_word();
+        scanner.next_word();
+        assert_eq!(scanner.next_money(), None);
+        assert_eq!(scanner.get_remaining(), " 12 EURO");
+    }
+}
@@ -0,0 +1,138 @@
+use super::Scanner;
+
+/// Controls which sequences end a line for [`Scanner::next_line`] and the
+/// methods built on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NewlinePolicy {
+    /// Only `\n` ends a line. The `\r` of a Windows `\r\n` is removed along
+    /// with the other trailing whitespace of the line, but a lone `\r` does
+    /// not end a line.
+    #[default]
+    LineFeed,
+    /// `\n`, `\r\n`, a lone `\r`, `U+0085 NEXT LINE`, `U+2028 LINE
+    /// SEPARATOR` and `U+2029 PARAGRAPH SEPARATOR` all end a line.
+    Universal,
+}
+
+impl NewlinePolicy {
+    /// Returns `true` if `c` is, or starts, a line terminator.
+    pub(super) fn is_terminator(self, c: char) -> bool {
+        match self {
+            NewlinePolicy::LineFeed => c == '\n',
+            NewlinePolicy::Universal => {
+                matches!(c, '\n' | '\r' | '\u{85}' | '\u{2028}' | '\u{2029}')
+            }
+        }
+    }
+
+    /// Returns the length of the line terminator at the start of `text`, if
+    /// there is one.
+    pub(super) fn terminator_len(self, text: &str) -> Option<usize> {
+        match text.chars().next().filter(|&c| self.is_terminator(c))? {
+            '\r' if text[1..].starts_with('\n') => Some(2),
+            c => Some(c.len_utf8()),
+        }
+    }
+
+    /// Finds the first line terminator in `text`, returning its offset and
+    /// length.
+    pub(super) fn find(self, text: &str) -> Option<(usize, usize)> {
+        let i = text.find(|c| self.is_terminator(c))?;
+        Some((i, self.terminator_len(&text[i..])?))
+    }
+
+    /// Splits `text` at its line terminators, dropping them, like
+    /// [`str::split`] on `'\n'`.
+    pub(super) fn split(self, text: &str) -> impl Iterator<Item = &str> {
+        let mut rest = Some(text);
+        std::iter::from_fn(move || {
+            let text = rest?;
+            match self.find(text) {
+                Some((i, len)) => {
+                    rest = Some(&text[i + len..]);
+                    Some(&text[..i])
+                }
+                None => {
+                    rest = None;
+                    Some(text)
+                }
+            }
+        })
+    }
+
+    /// Splits `text` into lines that keep their terminators, like
+    /// [`str::split_inclusive`] on `'\n'`.
+    pub(super) fn split_inclusive(self, text: &str) -> impl Iterator<Item = &str> {
+        let mut rest = text;
+        std::iter::from_fn(move || {
+            if rest.is_empty() {
+                return None;
+            }
+            let len = self.find(rest).map_or(rest.len(), |(i, len)| i + len);
+            let (line, tail) = rest.split_at(len);
+            rest = tail;
+            Some(line)
+        })
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Sets which sequences end a line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{NewlinePolicy, Scanner};
+    /// let input = "mac\rdos\r\nunix\n";
+    ///
+    /// let mut scanner = Scanner::new(input);
+    /// assert_eq!(scanner.next_line(), Some("mac\rdos"));
+    ///
+    /// let mut scanner = Scanner::new(input).with_newline_policy(NewlinePolicy::Universal);
+    /// assert_eq!(scanner.next_line(), Some("mac"));
+    /// assert_eq!(scanner.next_line(), Some("dos"));
+    /// assert_eq!(scanner.next_line(), Some("unix"));
+    /// assert_eq!(scanner.next_line(), None);
+    /// ```
+    pub fn with_newline_policy(mut self, policy: NewlinePolicy) -> Self {
+        self.newline_policy = policy;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_universal_terminators() {
+        let policy = NewlinePolicy::Universal;
+        assert_eq!(policy.find("a\r\nb"), Some((1, 2)));
+        assert_eq!(policy.find("a\rb"), Some((1, 1)));
+        assert_eq!(policy.find("a\u{2028}b"), Some((1, 3)));
+        assert_eq!(policy.find("ab"), None);
+        assert_eq!(NewlinePolicy::LineFeed.find("a\rb"), None);
+
+        let mut scanner =
+            Scanner::new("one\u{2029}two\r\r\nthree").with_newline_policy(NewlinePolicy::Universal);
+        let mut lines = Vec::new();
+        while let Some(line) = scanner.next_line() {
+            lines.push(line);
+        }
+        assert_eq!(lines, ["one", "two", "", "three"]);
+    }
+
+    #[test]
+    fn test_universal_paragraphs() {
+        let mut scanner = Scanner::new("one\r\rtwo\u{2028}three\r\n\u{2029}four")
+            .with_newline_policy(NewlinePolicy::Universal);
+        assert_eq!(scanner.next_paragraph(), Some("one"));
+        assert_eq!(scanner.get_remaining(), "two\u{2028}three\r\n\u{2029}four");
+        assert_eq!(scanner.next_paragraph(), Some("two\u{2028}three"));
+        assert_eq!(scanner.next_paragraph(), Some("four"));
+        assert_eq!(scanner.next_paragraph(), None);
+
+        let mut scanner = Scanner::new("one\r\rtwo");
+        assert_eq!(scanner.next_paragraph(), Some("one\r\rtwo"));
+    }
+}
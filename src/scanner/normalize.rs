@@ -0,0 +1,136 @@
+use std::borrow::Cow;
+
+use unicode_normalization::char::canonical_combining_class;
+use unicode_normalization::{
+    is_nfc_quick, is_nfd_quick, is_nfkc_quick, is_nfkd_quick, IsNormalized, UnicodeNormalization,
+};
+
+use super::{Filter, OffsetMap};
+
+/// Unicode normalization forms applied by [`NormalizeUnicode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalizationForm {
+    /// Canonical composition.
+    Nfc,
+    /// Canonical decomposition.
+    Nfd,
+    /// Compatibility composition.
+    Nfkc,
+    /// Compatibility decomposition.
+    Nfkd,
+}
+
+impl NormalizationForm {
+    /// Returns `true` if `chars` are known to be in this form already.
+    fn is_normalized(self, chars: impl Iterator<Item = char>) -> bool {
+        let quick = match self {
+            NormalizationForm::Nfc => is_nfc_quick(chars),
+            NormalizationForm::Nfd => is_nfd_quick(chars),
+            NormalizationForm::Nfkc => is_nfkc_quick(chars),
+            NormalizationForm::Nfkd => is_nfkd_quick(chars),
+        };
+        quick == IsNormalized::Yes
+    }
+
+    /// Returns `true` if `c` neither changes nor interacts with the text
+    /// before it, so that the text on each side normalizes on its own.
+    fn is_boundary(self, c: char) -> bool {
+        canonical_combining_class(c) == 0 && self.is_normalized(std::iter::once(c))
+    }
+
+    /// Appends `text` in this form to `output`.
+    fn push_normalized(self, output: &mut String, text: &str) {
+        match self {
+            NormalizationForm::Nfc => output.extend(text.nfc()),
+            NormalizationForm::Nfd => output.extend(text.nfd()),
+            NormalizationForm::Nfkc => output.extend(text.nfkc()),
+            NormalizationForm::Nfkd => output.extend(text.nfkd()),
+        }
+    }
+}
+
+/// Normalizes the text to a Unicode normalization form.
+///
+/// The text is normalized cluster by cluster, each cluster running up to the
+/// next character that is unaffected by normalization, so that offsets map
+/// exactly outside of the clusters that changed.
+#[derive(Debug, Clone, Copy)]
+pub struct NormalizeUnicode(pub NormalizationForm);
+
+impl Filter for NormalizeUnicode {
+    fn apply<'t>(&self, text: &'t str) -> Cow<'t, str> {
+        self.apply_mapped(text, &mut OffsetMap::new())
+    }
+
+    fn apply_mapped<'t>(&self, text: &'t str, map: &mut OffsetMap) -> Cow<'t, str> {
+        let form = self.0;
+        if form.is_normalized(text.chars()) {
+            return Cow::Borrowed(text);
+        }
+
+        let mut output = String::with_capacity(text.len());
+        let mut start = 0;
+        let ends = text
+            .char_indices()
+            .filter(|&(i, c)| i > 0 && form.is_boundary(c))
+            .map(|(i, _)| i)
+            .chain([text.len()]);
+
+        for end in ends {
+            let cluster = &text[start..end];
+            if form.is_normalized(cluster.chars()) {
+                output.push_str(cluster);
+            } else {
+                map.record(output.len(), start);
+                form.push_normalized(&mut output, cluster);
+                map.record(output.len(), end);
+            }
+            start = end;
+        }
+
+        Cow::Owned(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_unicode() {
+        let filter = NormalizeUnicode(NormalizationForm::Nfc);
+        assert_eq!(filter.apply("e\u{301}"), "\u{e9}");
+        assert!(matches!(filter.apply("\u{e9}"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_normalize_unicode_offsets() {
+        let mut map = OffsetMap::new();
+        let text =
+            NormalizeUnicode(NormalizationForm::Nfd).apply_mapped("\u{e9}t\u{e9} x", &mut map);
+        assert_eq!(text, "e\u{301}te\u{301} x");
+        assert_eq!(map.original_offset(3), 2);
+        assert_eq!(map.original_offset(7), 5);
+        assert_eq!(map.original_offset(8), 6);
+
+        let mut map = OffsetMap::new();
+        let text =
+            NormalizeUnicode(NormalizationForm::Nfkc).apply_mapped("\u{fb01}le e\u{301}", &mut map);
+        assert_eq!(text, "file \u{e9}");
+        assert_eq!(map.original_offset(2), 3);
+        assert_eq!(map.original_offset(5), 6);
+        assert_eq!(map.original_offset(7), 9);
+
+        let hangul = "\u{1100}\u{1161}\u{11a8} a\u{308}";
+        for form in [
+            NormalizationForm::Nfc,
+            NormalizationForm::Nfd,
+            NormalizationForm::Nfkc,
+            NormalizationForm::Nfkd,
+        ] {
+            let mut expected = String::new();
+            form.push_normalized(&mut expected, hangul);
+            assert_eq!(NormalizeUnicode(form).apply(hangul), expected);
+        }
+    }
+}
@@ -0,0 +1,479 @@
+use std::borrow::Cow;
+
+use super::{ScanError, ScanErrorKind, Scanner};
+
+/// Controls whether a `-` or `+` directly in front of digits is read as the sign of
+/// a number or as a separate operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SignPolicy {
+    /// A `-` directly in front of digits is always a sign, so `5-3` scans as
+    /// `5` followed by `-3`. This suits lists of numbers such as `-1-2-3`.
+    #[default]
+    Always,
+    /// A `-` directly after a number, a word or a closing bracket is an
+    /// operator, so `5-3` scans as `5`, `-` and `3`. This suits expressions.
+    /// A `-` at the start of the input or after whitespace or another
+    /// operator is still a sign.
+    Contextual,
+}
+
+impl SignPolicy {
+    /// Returns `true` if a `-` or `+` preceded by `previous` is the sign of a
+    /// number.
+    pub(super) fn is_sign_after(self, previous: Option<char>) -> bool {
+        match self {
+            SignPolicy::Always => true,
+            SignPolicy::Contextual => {
+                !previous.is_some_and(|c| c.is_alphanumeric() || c == '_' || c == ')' || c == ']')
+            }
+        }
+    }
+}
+
+/// The decimal and digit grouping separators of numbers scanned by
+/// [`Scanner::next_float`], and whether digits may be separated by
+/// underscores.
+///
+/// # Examples
+///
+/// ```
+/// use scanner::scanner::{NumberFormat, Scanner};
+/// let mut scanner = Scanner::new("1.234.567,89").with_number_format(NumberFormat::EUROPEAN);
+/// assert_eq!(scanner.next_float(), Some(1_234_567.89));
+///
+/// let mut scanner = Scanner::new("1,234,567.89").with_number_format(NumberFormat::US);
+/// assert_eq!(scanner.next_float(), Some(1_234_567.89));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    /// The character separating the integer part from the fraction.
+    pub decimal: char,
+    /// The character grouping the digits of the integer part by thousands,
+    /// if any.
+    pub grouping: Option<char>,
+    /// Whether an `_` between two digits is allowed and ignored, as in
+    /// `1_000_000` or `0xFF_FF`.
+    pub underscores: bool,
+}
+
+impl NumberFormat {
+    /// `1234567.89`, without grouping.
+    pub const PLAIN: NumberFormat = NumberFormat {
+        decimal: '.',
+        grouping: None,
+        underscores: false,
+    };
+
+    /// `1,234,567.89`, as in the United States.
+    pub const US: NumberFormat = NumberFormat {
+        decimal: '.',
+        grouping: Some(','),
+        underscores: false,
+    };
+
+    /// `1.234.567,89`, as in much of continental Europe.
+    pub const EUROPEAN: NumberFormat = NumberFormat {
+        decimal: ',',
+        grouping: Some('.'),
+        underscores: false,
+    };
+
+    /// Returns the format with `_` digit separators allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{NumberFormat, Scanner};
+    /// let format = NumberFormat::PLAIN.with_underscores();
+    /// let mut scanner = Scanner::new("1_000_000 0xFF_FF 2_5.0_1").with_number_format(format);
+    /// assert_eq!(scanner.next_number(), Some(1_000_000));
+    /// assert_eq!(scanner.next_int_literal(), Some(0xFFFF));
+    /// assert_eq!(scanner.next_float(), Some(25.01));
+    /// ```
+    pub const fn with_underscores(mut self) -> Self {
+        self.underscores = true;
+        self
+    }
+
+    /// Reads the digits of `radix` at `from` in `text`, skipping the
+    /// underscores allowed by the format.
+    ///
+    /// Returns the end of the digits in `text` and the digits without
+    /// underscores, borrowed from `text` if there were none.
+    pub(super) fn digits(self, text: &str, from: usize, radix: u32) -> (usize, Cow<'_, str>) {
+        let from = from.min(text.len());
+        let mut end = from;
+
+        for (i, c) in text[from..].char_indices() {
+            let i = from + i;
+            if c.is_digit(radix) {
+                end = i + 1;
+            } else if !(self.underscores
+                && c == '_'
+                && end == i
+                && end > from
+                && text[i + 1..].starts_with(|c: char| c.is_digit(radix)))
+            {
+                break;
+            }
+        }
+
+        let digits = &text[from..end];
+        match digits.contains('_') {
+            true => (end, Cow::Owned(digits.replace('_', ""))),
+            false => (end, Cow::Borrowed(digits)),
+        }
+    }
+
+    /// Reads the number at the start of `text`.
+    ///
+    /// A grouping separator is only part of the number when it is followed
+    /// by exactly three digits, so that it cannot be mistaken for a list
+    /// separator.
+    ///
+    /// Returns the length of the number in `text` and the number rewritten
+    /// without separators and with a `.` decimal separator, or `None` if
+    /// `text` does not start with digits.
+    pub(super) fn read(self, text: &str) -> Option<(usize, String)> {
+        let mut normalized = String::new();
+        let mut len = usize::from(text.starts_with(['-', '+']));
+        normalized.push_str(&text[..len]);
+
+        let (end, integer) = self.digits(text, len, 10);
+        if integer.is_empty() {
+            return None;
+        }
+        normalized.push_str(&integer);
+        len = end;
+
+        if let Some(grouping) = self.grouping {
+            while text[len..].starts_with(grouping) {
+                let group = len + grouping.len_utf8();
+                let (end, digits) = self.digits(text, group, 10);
+                if digits.len() != 3 || end != group + 3 {
+                    break;
+                }
+                normalized.push_str(&digits);
+                len = end;
+            }
+        }
+
+        if text[len..].starts_with(self.decimal) {
+            let (end, fraction) = self.digits(text, len + self.decimal.len_utf8(), 10);
+            if !fraction.is_empty() {
+                normalized.push('.');
+                normalized.push_str(&fraction);
+                len = end;
+            }
+        }
+
+        if let Some(exponent) = text[len..].strip_prefix(['e', 'E']) {
+            let sign = usize::from(exponent.starts_with(['+', '-']));
+            let (end, digits) = self.digits(text, len + 1 + sign, 10);
+            if !digits.is_empty() {
+                normalized.push_str(&text[len..len + 1 + sign]);
+                normalized.push_str(&digits);
+                len = end;
+            }
+        }
+
+        Some((len, normalized))
+    }
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        NumberFormat::PLAIN
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Sets the decimal and grouping separators used by
+    /// [`Scanner::next_float`].
+    pub fn with_number_format(mut self, format: NumberFormat) -> Self {
+        self.number_format = format;
+        self
+    }
+
+    /// Scans for the next floating-point number.
+    ///
+    /// Leading whitespace is skipped. The number has an optional `-` sign,
+    /// interpreted according to the [`SignPolicy`], digits grouped and
+    /// separated from the fraction as configured by the [`NumberFormat`],
+    /// and an optional exponent.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(f64)` if a number is found.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("  -2.5e3 kg");
+    /// assert_eq!(scanner.next_float(), Some(-2500.0));
+    /// assert_eq!(scanner.next_float(), None);
+    /// ```
+    pub fn next_float(&mut self) -> Option<f64> {
+        self.try_next_float().ok()
+    }
+
+    /// Scans for the next floating-point number.
+    ///
+    /// See [`Scanner::next_float`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only whitespace remains.
+    /// * [`ScanErrorKind::InvalidNumber`] if no number starts at the next
+    ///   non-whitespace character.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_float(&mut self) -> Result<f64, ScanError> {
+        self.parse_next_float(|number| number.parse().ok())
+    }
+
+    /// Scans the next number as [`Scanner::next_float`] does and converts it
+    /// with `parse`, which receives the number without separators and with a
+    /// `.` decimal separator.
+    ///
+    /// If `parse` returns `None`, nothing is consumed and the
+    /// [`ScanErrorKind::Overflow`] error points at the start of the number.
+    pub(super) fn parse_next_float<T>(
+        &mut self,
+        parse: impl FnOnce(&str) -> Option<T>,
+    ) -> Result<T, ScanError> {
+        let remaining = self.get_remaining();
+        let trimmed = remaining.trim_start();
+        let start = self.position + remaining.len() - trimmed.len();
+
+        if trimmed.is_empty() {
+            return Err(self.error(ScanErrorKind::EndOfInput));
+        }
+
+        let previous = self.input[..start].chars().next_back();
+        let invalid = ScanError::new(ScanErrorKind::InvalidNumber, start);
+        if trimmed.starts_with(['-', '+']) && !self.sign_policy.is_sign_after(previous) {
+            return Err(invalid);
+        }
+
+        let (len, normalized) = self.number_format.read(trimmed).ok_or(invalid.clone())?;
+        let value = parse(&normalized).ok_or(ScanError::new(ScanErrorKind::Overflow, start))?;
+        self.consume(start, start + len, start + len)?;
+        Ok(value)
+    }
+
+    /// Scans for the next integer literal, in decimal or with a `0x`, `0o`
+    /// or `0b` prefix.
+    ///
+    /// Leading whitespace is skipped and an optional `-` or `+` sign is accepted,
+    /// according to the [`SignPolicy`]. Digits may be separated by `_` if
+    /// the [`NumberFormat`] allows it.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(i128)` if an integer literal is found.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("0x1F -0b101 0o17 42");
+    /// assert_eq!(scanner.next_int_literal(), Some(31));
+    /// assert_eq!(scanner.next_int_literal(), Some(-5));
+    /// assert_eq!(scanner.next_int_literal(), Some(15));
+    /// assert_eq!(scanner.next_int_literal(), Some(42));
+    /// ```
+    pub fn next_int_literal(&mut self) -> Option<i128> {
+        self.try_next_int_literal().ok()
+    }
+
+    /// Scans for the next integer literal.
+    ///
+    /// See [`Scanner::next_int_literal`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only whitespace remains.
+    /// * [`ScanErrorKind::InvalidNumber`] if no integer literal starts at the
+    ///   next non-whitespace character.
+    /// * [`ScanErrorKind::Overflow`] if the literal does not fit in an `i128`.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_int_literal(&mut self) -> Result<i128, ScanError> {
+        let remaining = self.get_remaining();
+        let trimmed = remaining.trim_start();
+        let start = self.position + remaining.len() - trimmed.len();
+
+        if trimmed.is_empty() {
+            return Err(self.error(ScanErrorKind::EndOfInput));
+        }
+
+        let previous = self.input[..start].chars().next_back();
+        let invalid = ScanError::new(ScanErrorKind::InvalidNumber, start);
+        let sign = usize::from(trimmed.starts_with(['-', '+']));
+        if sign == 1 && !self.sign_policy.is_sign_after(previous) {
+            return Err(invalid);
+        }
+
+        let (radix, prefix) = match trimmed[sign..].get(..2) {
+            Some("0x" | "0X") => (16, 2),
+            Some("0o" | "0O") => (8, 2),
+            Some("0b" | "0B") => (2, 2),
+            _ => (10, 0),
+        };
+
+        let (len, digits) = self.number_format.digits(trimmed, sign + prefix, radix);
+        if digits.is_empty() {
+            return Err(invalid);
+        }
+
+        let value = i128::from_str_radix(&format!("{}{digits}", &trimmed[..sign]), radix)
+            .map_err(|_| ScanError::new(ScanErrorKind::Overflow, start))?;
+        self.consume(start, start + len, start + len)?;
+        Ok(value)
+    }
+
+    /// Sets how a `-` or `+` directly in front of digits is disambiguated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{Scanner, SignPolicy};
+    /// let mut scanner = Scanner::new("5-3");
+    /// assert_eq!(scanner.next_number(), Some(5));
+    /// assert_eq!(scanner.next_number(), Some(-3));
+    ///
+    /// let mut scanner = Scanner::new("5-3").with_sign_policy(SignPolicy::Contextual);
+    /// assert_eq!(scanner.next_number(), Some(5));
+    /// assert_eq!(scanner.next_number::<i32>(), None);
+    /// assert_eq!(scanner.expect_one_of(&["+", "-"]), Ok("-"));
+    /// assert_eq!(scanner.next_number(), Some(3));
+    /// ```
+    pub fn with_sign_policy(mut self, policy: SignPolicy) -> Self {
+        self.sign_policy = policy;
+        self
+    }
+
+    /// Returns the character right before the cursor.
+    pub(super) fn previous_char(&self) -> Option<char> {
+        self.input[..self.position].chars().next_back()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contextual_sign() {
+        let mut scanner = Scanner::new("-1 - -2 (x)-3").with_sign_policy(SignPolicy::Contextual);
+        assert_eq!(scanner.next_number(), Some(-1));
+        assert_eq!(scanner.next_word(), Some("-"));
+        assert_eq!(scanner.next_number(), Some(-2));
+        assert_eq!(scanner.next_word(), Some("(x)-3"));
+
+        let mut scanner = Scanner::new("(x)-3").with_sign_policy(SignPolicy::Contextual);
+        assert!(scanner.expect("(x)").is_ok());
+        assert_eq!(scanner.next_number::<i32>(), None);
+        assert_eq!(scanner.get_remaining(), "-3");
+    }
+
+    #[test]
+    fn test_plus_sign_and_overflow() {
+        let mut scanner = Scanner::new("+7 +1.5 +0x10 300 -x");
+        assert_eq!(scanner.next_number::<u8>(), Some(7));
+        assert_eq!(scanner.next_float(), Some(1.5));
+        assert_eq!(scanner.next_int_literal(), Some(16));
+
+        let error = scanner.try_next_number::<u8>().unwrap_err();
+        assert_eq!(error.kind(), &ScanErrorKind::Overflow);
+        assert_eq!(scanner.next_number::<u16>(), Some(300));
+
+        let error = scanner.try_next_number::<u8>().unwrap_err();
+        assert_eq!(error.kind(), &ScanErrorKind::InvalidNumber);
+        assert_eq!(scanner.get_remaining(), " -x");
+
+        for input in ["-3", "-0", "-300"] {
+            let error = Scanner::new(input).try_next_number::<u8>().unwrap_err();
+            assert_eq!(error.kind(), &ScanErrorKind::InvalidNumber, "{input}");
+        }
+        let error = Scanner::new("-300").try_next_number::<i8>().unwrap_err();
+        assert_eq!(error.kind(), &ScanErrorKind::Overflow);
+
+        let error = Scanner::new("0x100000000000000000000000000000000")
+            .try_next_int_literal()
+            .unwrap_err();
+        assert_eq!(error.kind(), &ScanErrorKind::Overflow);
+    }
+
+    #[test]
+    fn test_contextual_sign_in_index() {
+        let scanner = Scanner::new("5-3");
+        assert_eq!(scanner.index_tokens().len(), 2);
+
+        let scanner = Scanner::new("5-3").with_sign_policy(SignPolicy::Contextual);
+        assert_eq!(scanner.index_tokens().len(), 3);
+    }
+
+    #[test]
+    fn test_number_format_grouping() {
+        assert_eq!(
+            NumberFormat::US.read("1,234x"),
+            Some((5, "1234".to_string()))
+        );
+        assert_eq!(NumberFormat::US.read("1,5"), Some((1, "1".to_string())));
+        assert_eq!(
+            NumberFormat::US.read("12,3456"),
+            Some((2, "12".to_string()))
+        );
+        assert_eq!(
+            NumberFormat::EUROPEAN.read("-0,5e-2"),
+            Some((7, "-0.5e-2".to_string()))
+        );
+        assert_eq!(
+            NumberFormat::PLAIN.read("1,000"),
+            Some((1, "1".to_string()))
+        );
+        assert_eq!(NumberFormat::PLAIN.read("1."), Some((1, "1".to_string())));
+        assert_eq!(NumberFormat::PLAIN.read(".5"), None);
+
+        let format = NumberFormat::US.with_underscores();
+        assert_eq!(
+            format.read("1_0,000.0_5_"),
+            Some((11, "10000.05".to_string()))
+        );
+        assert_eq!(format.read("1__0"), Some((1, "1".to_string())));
+        assert_eq!(format.read("1,0_00"), Some((1, "1".to_string())));
+
+        let mut scanner = Scanner::new("1,5;2,25").with_number_format(NumberFormat::EUROPEAN);
+        assert_eq!(scanner.next_float(), Some(1.5));
+        assert_eq!(scanner.next_float(), None);
+        assert_eq!(scanner.next_char(), Some(';'));
+        assert_eq!(scanner.next_float(), Some(2.25));
+    }
+
+    #[test]
+    fn test_next_number_underscores() {
+        let format = NumberFormat::PLAIN.with_underscores();
+        let mut scanner = Scanner::new("1_000 -2_5 +3_0_0").with_number_format(format);
+        assert_eq!(scanner.next_number(), Some(1000));
+        assert_eq!(scanner.next_number(), Some(-25));
+        assert_eq!(scanner.next_number(), Some(300));
+
+        for (input, value, rest) in [
+            ("1__0", Some(1), "__0"),
+            ("5_ x", Some(5), "_ x"),
+            ("-_3", None, "-_3"),
+            ("_7", None, "_7"),
+        ] {
+            let mut scanner = Scanner::new(input).with_number_format(format);
+            assert_eq!(scanner.next_number::<i32>(), value, "{input}");
+            assert_eq!(scanner.get_remaining(), rest, "{input}");
+        }
+
+        let mut scanner = Scanner::new("1_000");
+        assert_eq!(scanner.next_number(), Some(1));
+        assert_eq!(scanner.get_remaining(), "_000");
+    }
+}
@@ -0,0 +1,152 @@
+use super::{ScanError, ScanErrorKind, Scanner};
+
+/// An iterator over the `key=value` pairs of a [`Scanner`].
+///
+/// Created by [`Scanner::key_values`].
+pub struct KeyValues<'s, 'a> {
+    pub(super) scanner: &'s mut Scanner<'a>,
+    separator: char,
+}
+
+impl<'a> Iterator for KeyValues<'_, 'a> {
+    type Item = Result<(&'a str, &'a str), ScanError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.scanner.try_next_key_value(self.separator) {
+            Err(error) if error.kind() == &ScanErrorKind::EndOfInput => None,
+            Err(error) => {
+                self.scanner.position = error.position();
+                self.scanner.next_line();
+                Some(Err(error))
+            }
+            pair => Some(pair),
+        }
+    }
+}
+
+/// Removes one pair of matching `"` or `'` quotes around `value`.
+fn unquote(value: &str) -> &str {
+    ['"', '\'']
+        .into_iter()
+        .find_map(|quote| value.strip_prefix(quote)?.strip_suffix(quote))
+        .unwrap_or(value)
+}
+
+impl<'a> Scanner<'a> {
+    /// Scans for the next `key<separator>value` line, such as `KEY=value`
+    /// in an env file or `name: value` in a simple config.
+    ///
+    /// Blank lines and comment lines starting with `#` or `;` are skipped.
+    /// The line is split at the first `separator`; the key and the value are
+    /// trimmed, a leading `export ` is dropped from the key as in shell env
+    /// files, and one pair of matching `"` or `'` quotes around the value is
+    /// removed. Escapes inside quotes are left as they are.
+    ///
+    /// # Arguments
+    ///
+    /// * `separator` - The character between the key and the value.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((key, value))` for the next pair.
+    /// * `None` if no pair is left or the next line has no separator, in
+    ///   which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("# database\nexport DB_HOST=localhost\n\nDB_NAME = \"app db\"\n");
+    /// assert_eq!(scanner.next_key_value('='), Some(("DB_HOST", "localhost")));
+    /// assert_eq!(scanner.next_key_value('='), Some(("DB_NAME", "app db")));
+    /// assert_eq!(scanner.next_key_value('='), None);
+    /// ```
+    pub fn next_key_value(&mut self, separator: char) -> Option<(&'a str, &'a str)> {
+        self.try_next_key_value(separator).ok()
+    }
+
+    /// Scans for the next `key<separator>value` line.
+    ///
+    /// See [`Scanner::next_key_value`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only blank and comment lines remain.
+    /// * [`ScanErrorKind::InvalidFormat`] if the next line has no separator
+    ///   or an empty key.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_key_value(&mut self, separator: char) -> Result<(&'a str, &'a str), ScanError> {
+        let checkpoint = self.checkpoint();
+
+        let (start, line) = self.next_content_line()?;
+
+        let pair = line.split_once(separator).and_then(|(key, value)| {
+            let key = key.trim();
+            let key = key.strip_prefix("export ").map_or(key, str::trim_start);
+            (!key.is_empty()).then(|| (key, unquote(value.trim())))
+        });
+
+        pair.ok_or_else(|| {
+            self.restore(checkpoint);
+            let format = "key-value pair";
+            ScanError::new(ScanErrorKind::InvalidFormat { format }, start)
+        })
+    }
+
+    /// Consumes lines up to the next one that is neither blank nor a comment
+    /// starting with `#` or `;`.
+    ///
+    /// Returns the offset of the line's first non-whitespace character and
+    /// the line from there.
+    pub(super) fn next_content_line(&mut self) -> Result<(usize, &'a str), ScanError> {
+        loop {
+            let line = self.try_next_line()?;
+            let content = line.trim_start();
+            if !(content.is_empty() || content.starts_with(['#', ';'])) {
+                let (start, _) = self.last.unwrap_or_default();
+                return Ok((start + line.len() - content.len(), content));
+            }
+        }
+    }
+
+    /// Returns an iterator over the remaining `key<separator>value` lines.
+    ///
+    /// Each item is the result of [`Scanner::try_next_key_value`]. After an
+    /// error, the offending line is skipped so that iteration can go on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("name: demo\nbroken\nport: 80\n");
+    /// let pairs: Vec<_> = scanner.key_values(':').filter_map(Result::ok).collect();
+    /// assert_eq!(pairs, [("name", "demo"), ("port", "80")]);
+    /// ```
+    pub fn key_values(&mut self, separator: char) -> KeyValues<'_, 'a> {
+        KeyValues {
+            scanner: self,
+            separator,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_value_edge_cases() {
+        assert_eq!(unquote("'a'"), "a");
+        assert_eq!(unquote("\"a'"), "\"a'");
+        assert_eq!(unquote("\""), "\"");
+
+        let mut scanner = Scanner::new("  ; comment\n  =x\nurl=http://h?a=b\n");
+        let error = scanner.try_next_key_value('=').unwrap_err();
+        assert_eq!(error.to_string(), "invalid key-value pair at offset 14");
+        assert_eq!(scanner.position(), 0);
+
+        let pairs: Vec<_> = scanner.key_values('=').collect();
+        assert_eq!(pairs.len(), 2);
+        assert_eq!(pairs[1], Ok(("url", "http://h?a=b")));
+    }
+}
@@ -0,0 +1,72 @@
+use rayon::prelude::*;
+
+use super::Scanner;
+
+impl<'a> Scanner<'a> {
+    /// Consumes the remaining input and calls `f` on each line in parallel,
+    /// returning the results in the order of the lines.
+    ///
+    /// Lines are split as by [`Scanner::next_line`], including the
+    /// [`NewlinePolicy`](super::NewlinePolicy), and trailing whitespace is
+    /// trimmed. `f` typically builds its own [`Scanner`] over the line.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The function applied to each line.
+    ///
+    /// # Returns
+    ///
+    /// The result of `f` for each line, or an empty `Vec` if the scanner's
+    /// budget is used up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("1 2\n3 4 5\n\n6\n");
+    /// let sums = scanner.par_lines(|line| {
+    ///     let mut line = Scanner::new(line);
+    ///     std::iter::from_fn(|| line.next_number::<u32>()).sum::<u32>()
+    /// });
+    /// assert_eq!(sums, [3, 12, 0, 6]);
+    /// assert_eq!(scanner.get_remaining(), "");
+    /// ```
+    pub fn par_lines<R, F>(&mut self, f: F) -> Vec<R>
+    where
+        R: Send,
+        F: Fn(&'a str) -> R + Sync + Send,
+    {
+        let mut rest = self.get_remaining();
+        if self
+            .consume(self.position, self.input.len(), self.input.len())
+            .is_err()
+        {
+            return Vec::new();
+        }
+
+        let mut lines = Vec::new();
+        while !rest.is_empty() {
+            let (line, next) = match self.newline_policy.find(rest) {
+                Some((newline, len)) => (&rest[..newline], &rest[newline + len..]),
+                None => (rest, ""),
+            };
+            lines.push(line.trim_end());
+            rest = next;
+        }
+
+        lines.into_par_iter().map(f).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_is_kept() {
+        let input: String = (0..10_000).map(|i| format!("{i}\r\n")).collect();
+        let mut scanner = Scanner::new(&input);
+        let numbers = scanner.par_lines(|line| line.parse::<usize>().unwrap());
+        assert!(numbers.iter().copied().eq(0..10_000));
+    }
+}
@@ -0,0 +1,103 @@
+use super::Scanner;
+
+impl<'a> Scanner<'a> {
+    /// Consumes the remaining input and divides it into `n` chunks of about
+    /// the same size, each ending at a line boundary, returning a new
+    /// `Scanner` over each chunk.
+    ///
+    /// Line boundaries follow the [`NewlinePolicy`](super::NewlinePolicy),
+    /// so no line is split between two chunks. A chunk is empty if the lines
+    /// before it already reach past its share of the input. The returned
+    /// scanners have the settings, remaining budget and source map of this
+    /// one, report positions as offsets in the whole input, and can be moved
+    /// to other threads.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of chunks.
+    ///
+    /// # Returns
+    ///
+    /// `n` scanners whose inputs concatenate to the remaining input, or an
+    /// empty `Vec` if `n` is `0` or the scanner's budget is used up, in which
+    /// case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("1\n2\n3\n4\n5\n6\n");
+    /// let sums: Vec<u32> = std::thread::scope(|scope| {
+    ///     let workers: Vec<_> = scanner
+    ///         .partition(3)
+    ///         .into_iter()
+    ///         .map(|mut chunk| {
+    ///             scope.spawn(move || std::iter::from_fn(|| chunk.next_number::<u32>()).sum())
+    ///         })
+    ///         .collect();
+    ///     workers.into_iter().map(|worker| worker.join().unwrap()).collect()
+    /// });
+    /// assert_eq!(sums, [3, 7, 11]);
+    /// ```
+    pub fn partition(&mut self, n: usize) -> Vec<Scanner<'a>> {
+        let base = self.position;
+        let remaining = self.get_remaining();
+        if n == 0
+            || self
+                .consume(self.position, self.input.len(), self.input.len())
+                .is_err()
+        {
+            return Vec::new();
+        }
+
+        let len = remaining.len();
+        let mut chunks = Vec::with_capacity(n);
+        let mut start = 0;
+        for k in 1..n {
+            let target = len * k / n;
+            let mut end = start;
+            if target > start {
+                let mut from = target - 1;
+                while !remaining.is_char_boundary(from) {
+                    from += 1;
+                }
+                end = match self.newline_policy.find(&remaining[from..]) {
+                    Some((newline, newline_len)) => from + newline + newline_len,
+                    None => len,
+                };
+            }
+            chunks.push((start, end));
+            start = end;
+        }
+        chunks.push((start, len));
+
+        chunks
+            .into_iter()
+            .map(|(start, end)| self.child(base + start, base + end))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunks_cover_input_at_line_boundaries() {
+        let input = "a long first line\nb\r\nc\nd\n";
+        let mut scanner = Scanner::new(input);
+        let chunks = scanner.partition(4);
+        let texts: Vec<_> = chunks.iter().map(|chunk| chunk.get_remaining()).collect();
+        assert_eq!(texts, ["a long first line\n", "", "", "b\r\nc\nd\n"]);
+        assert_eq!(scanner.get_remaining(), "");
+
+        let mut scanner = Scanner::new("é\né\n");
+        let texts: Vec<_> = scanner
+            .partition(5)
+            .iter()
+            .map(|c| c.get_remaining())
+            .collect();
+        assert_eq!(texts.concat(), "é\né\n");
+        assert!(Scanner::new("x").partition(0).is_empty());
+    }
+}
@@ -0,0 +1,211 @@
+use std::str::FromStr;
+
+use super::{ScanError, ScanErrorKind, Scanner};
+
+/// The separator and labels of points scanned by [`Scanner::next_point`].
+///
+/// # Examples
+///
+/// ```
+/// use scanner::scanner::{PointFormat, Scanner};
+/// let format = PointFormat::DEFAULT.with_separator(';').with_labels("lat", "lon");
+/// let mut scanner = Scanner::new("lat=52.5; lon=13.4").with_point_format(format);
+/// assert_eq!(scanner.next_point(), Some((52.5, 13.4)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PointFormat {
+    /// The character between the two coordinates.
+    pub separator: char,
+    /// The labels that may precede the coordinates, each followed by `=`.
+    pub labels: (&'static str, &'static str),
+}
+
+impl PointFormat {
+    /// `3,4`, `(3, 4)` or `x=3, y=4`.
+    pub const DEFAULT: PointFormat = PointFormat {
+        separator: ',',
+        labels: ("x", "y"),
+    };
+
+    /// Returns the format with `separator` between the coordinates.
+    pub const fn with_separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Returns the format with the labels `first` and `second`.
+    pub const fn with_labels(mut self, first: &'static str, second: &'static str) -> Self {
+        self.labels = (first, second);
+        self
+    }
+}
+
+impl Default for PointFormat {
+    fn default() -> Self {
+        PointFormat::DEFAULT
+    }
+}
+
+impl Scanner<'_> {
+    /// Sets the separator and labels used by [`Scanner::next_point`].
+    pub fn with_point_format(mut self, format: PointFormat) -> Self {
+        self.point_format = format;
+        self
+    }
+
+    /// Scans for the next point, such as `3,4`, `(3, 4)` or `x=3, y=4`.
+    ///
+    /// Leading whitespace is skipped. The point may be wrapped in
+    /// parentheses, and either both coordinates or neither are labeled.
+    /// The separator and the labels are set with
+    /// [`Scanner::with_point_format`]. Coordinates are read as by
+    /// [`Scanner::next_float`], except that a grouping separator equal to
+    /// the point separator is not part of a number. With a decimal comma,
+    /// use another separator such as `;`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((x, y))` if a point is found.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("from (3, 4) to x=-1, y=2.5 via 7,8!");
+    /// assert_eq!(scanner.next_word(), Some("from"));
+    /// assert_eq!(scanner.next_point(), Some((3, 4)));
+    /// assert_eq!(scanner.next_word(), Some("to"));
+    /// assert_eq!(scanner.next_point(), Some((-1.0, 2.5)));
+    /// assert_eq!(scanner.next_word(), Some("via"));
+    /// assert_eq!(scanner.next_point(), Some((7, 8)));
+    /// assert_eq!(scanner.get_remaining(), "!");
+    /// ```
+    pub fn next_point<T>(&mut self) -> Option<(T, T)>
+    where
+        T: FromStr,
+    {
+        self.try_next_point().ok()
+    }
+
+    /// Scans for the next point.
+    ///
+    /// See [`Scanner::next_point`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only whitespace remains.
+    /// * [`ScanErrorKind::InvalidFormat`] if no point starts at the next
+    ///   non-whitespace character.
+    /// * [`ScanErrorKind::InvalidNumber`] if a coordinate is not a valid `T`.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_point<T>(&mut self) -> Result<(T, T), ScanError>
+    where
+        T: FromStr,
+    {
+        let remaining = self.get_remaining();
+        let trimmed = remaining.trim_start();
+        let start = self.position + remaining.len() - trimmed.len();
+
+        if trimmed.is_empty() {
+            return Err(self.error(ScanErrorKind::EndOfInput));
+        }
+
+        let invalid = ScanError::new(ScanErrorKind::InvalidFormat { format: "point" }, start);
+        let PointFormat { separator, labels } = self.point_format;
+        let parenthesized = trimmed.starts_with('(');
+        let mut len = usize::from(parenthesized);
+        if parenthesized {
+            len += skip_whitespace(&trimmed[len..]);
+        }
+
+        let first = label(&trimmed[len..], labels.0).filter(|_| !labels.0.is_empty());
+        let labeled = first.is_some();
+        len += first.unwrap_or(0);
+        let (x, end) = self.coordinate(trimmed, start, len)?;
+        len = end + skip_whitespace(&trimmed[end..]);
+
+        if !trimmed[len..].starts_with(separator) {
+            return Err(invalid);
+        }
+        len += separator.len_utf8();
+        len += skip_whitespace(&trimmed[len..]);
+
+        if labeled {
+            len += label(&trimmed[len..], labels.1).ok_or(invalid.clone())?;
+        }
+        let (y, end) = self.coordinate(trimmed, start, len)?;
+        len = end;
+
+        if parenthesized {
+            len += skip_whitespace(&trimmed[len..]);
+            if !trimmed[len..].starts_with(')') {
+                return Err(invalid);
+            }
+            len += 1;
+        }
+
+        self.consume(start, start + len, start + len)?;
+        Ok((x, y))
+    }
+
+    /// Reads the coordinate at `from` in `text`, which starts at `start` in
+    /// the input, returning it and its end in `text`.
+    fn coordinate<T>(&self, text: &str, start: usize, from: usize) -> Result<(T, usize), ScanError>
+    where
+        T: FromStr,
+    {
+        let invalid = ScanError::new(ScanErrorKind::InvalidNumber, start + from);
+        let previous = self.input[..start + from].chars().next_back();
+        if text[from..].starts_with(['-', '+']) && !self.sign_policy.is_sign_after(previous) {
+            return Err(invalid);
+        }
+
+        let mut format = self.number_format;
+        if format.grouping == Some(self.point_format.separator) {
+            format.grouping = None;
+        }
+
+        let (len, number) = format.read(&text[from..]).ok_or(invalid.clone())?;
+        let value = number.parse().map_err(|_| invalid)?;
+        Ok((value, from + len))
+    }
+}
+
+/// Returns the length of `name`, optional whitespace and `=` at the start of
+/// `text`, if present.
+fn label(text: &str, name: &str) -> Option<usize> {
+    let rest = text.strip_prefix(name)?;
+    let spaces = skip_whitespace(rest);
+    let rest = rest[spaces..].strip_prefix('=')?;
+    Some(text.len() - rest.len() + skip_whitespace(rest))
+}
+
+fn skip_whitespace(text: &str) -> usize {
+    text.len() - text.trim_start().len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_malformed_points() {
+        let mut scanner = Scanner::new("(1, 2 x=1, 2 1;2 3, y");
+        let error = scanner.try_next_point::<i32>().unwrap_err();
+        assert_eq!(
+            error.kind(),
+            &ScanErrorKind::InvalidFormat { format: "point" }
+        );
+        assert_eq!(error.position(), 0);
+
+        let builder = Scanner::builder().point_format(PointFormat::DEFAULT.with_separator(';'));
+        let mut scanner = builder.build("1;2 x = 3 ; y = 4 5;z");
+        assert_eq!(scanner.next_point(), Some((1, 2)));
+        assert_eq!(scanner.next_point(), Some((3, 4)));
+        let error = scanner.try_next_point::<i32>().unwrap_err();
+        assert_eq!(error.kind(), &ScanErrorKind::InvalidNumber);
+        assert_eq!(error.position(), 20);
+        assert_eq!(scanner.get_remaining(), " 5;z");
+    }
+}
@@ -0,0 +1,174 @@
+use std::ops::{Deref, DerefMut};
+use std::sync::Mutex;
+
+use super::decode::decode_lenient_into;
+use super::{Fallback, Scanner, ScannerBuilder};
+
+/// A pool of pre-configured scanners and reusable input buffers.
+///
+/// Services scanning many small payloads can share one pool between
+/// threads: every scanner it hands out starts fresh with the pool's
+/// configuration, and owned payloads (e.g. decoded bytes) are copied into
+/// buffers that are recycled instead of allocated per request.
+///
+/// # Examples
+///
+/// ```
+/// use scanner::scanner::{Scanner, ScannerPool};
+/// let pool = ScannerPool::new(Scanner::builder().budget(100, 4096));
+///
+/// let mut buffer = pool.buffer();
+/// buffer.push_str("GET /index.html");
+/// let mut scanner = buffer.scanner();
+/// assert_eq!(scanner.next_word(), Some("GET"));
+/// drop(buffer);
+///
+/// assert_eq!(pool.idle_buffers(), 1);
+/// ```
+#[derive(Debug)]
+pub struct ScannerPool {
+    builder: ScannerBuilder,
+    buffers: Mutex<Vec<String>>,
+    max_idle: usize,
+}
+
+impl ScannerPool {
+    /// Creates a pool handing out scanners configured by `builder`.
+    pub fn new(builder: ScannerBuilder) -> Self {
+        ScannerPool {
+            builder,
+            buffers: Mutex::new(Vec::new()),
+            max_idle: 64,
+        }
+    }
+
+    /// Sets how many idle buffers the pool keeps at most. Buffers returned
+    /// beyond that are freed.
+    pub fn with_max_idle(mut self, max_idle: usize) -> Self {
+        self.max_idle = max_idle;
+        self
+    }
+
+    /// Creates a new scanner over borrowed `input` with the pool's
+    /// configuration.
+    pub fn scanner<'a>(&self, input: &'a str) -> Scanner<'a> {
+        self.builder.build(input)
+    }
+
+    /// Takes an empty buffer from the pool, or allocates one if none is idle.
+    /// The buffer goes back to the pool when dropped.
+    pub fn buffer(&self) -> PooledBuffer<'_> {
+        let buffer = self.lock().pop().unwrap_or_default();
+        PooledBuffer { pool: self, buffer }
+    }
+
+    /// Decodes `bytes` leniently straight into a pooled buffer.
+    ///
+    /// See [`DecodedInput::lenient`](super::DecodedInput::lenient).
+    pub fn decode(&self, bytes: &[u8], fallback: Fallback) -> PooledBuffer<'_> {
+        let mut buffer = self.buffer();
+        decode_lenient_into(bytes, fallback, &mut buffer.buffer, |_, _, _| {});
+        buffer
+    }
+
+    /// Returns the number of buffers waiting to be reused.
+    pub fn idle_buffers(&self) -> usize {
+        self.lock().len()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<String>> {
+        self.buffers
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// An input buffer borrowed from a [`ScannerPool`].
+///
+/// Dereferences to `String`. It is cleared and returned to the pool when
+/// dropped.
+#[derive(Debug)]
+pub struct PooledBuffer<'p> {
+    pool: &'p ScannerPool,
+    buffer: String,
+}
+
+impl PooledBuffer<'_> {
+    /// Creates a new scanner over the buffer with the pool's configuration.
+    pub fn scanner(&self) -> Scanner<'_> {
+        self.pool.scanner(&self.buffer)
+    }
+}
+
+impl Deref for PooledBuffer<'_> {
+    type Target = String;
+
+    fn deref(&self) -> &String {
+        &self.buffer
+    }
+}
+
+impl DerefMut for PooledBuffer<'_> {
+    fn deref_mut(&mut self) -> &mut String {
+        &mut self.buffer
+    }
+}
+
+impl Drop for PooledBuffer<'_> {
+    fn drop(&mut self) {
+        let mut buffer = std::mem::take(&mut self.buffer);
+        buffer.clear();
+
+        let mut buffers = self.pool.lock();
+        if buffers.len() < self.pool.max_idle {
+            buffers.push(buffer);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_buffers_are_reused() {
+        let pool = ScannerPool::new(ScannerBuilder::new());
+
+        let mut buffer = pool.buffer();
+        buffer.push_str(&"x".repeat(1000));
+        drop(buffer);
+
+        let buffer = pool.buffer();
+        assert!(buffer.is_empty());
+        assert!(buffer.capacity() >= 1000);
+        assert_eq!(pool.idle_buffers(), 0);
+
+        let allocation = buffer.as_ptr();
+        drop(buffer);
+        let buffer = pool.decode(b"caf\xe9", Fallback::Windows1252);
+        assert_eq!(*buffer, "caf\u{e9}");
+        assert_eq!(buffer.as_ptr(), allocation);
+    }
+
+    #[test]
+    fn test_concurrent_use() {
+        let pool = ScannerPool::new(Scanner::builder().budget(1, usize::MAX)).with_max_idle(2);
+
+        thread::scope(|scope| {
+            for i in 0..4 {
+                let pool = &pool;
+                scope.spawn(move || {
+                    let mut bytes = i.to_string().into_bytes();
+                    bytes.extend(b" \xff");
+                    let buffer = pool.decode(&bytes, Fallback::Replace);
+                    let mut scanner = buffer.scanner();
+                    assert_eq!(scanner.next_number(), Some(i));
+                    assert_eq!(scanner.next_word(), None);
+                });
+            }
+        });
+
+        assert!(pool.idle_buffers() <= 2);
+    }
+}
@@ -0,0 +1,129 @@
+use super::Scanner;
+
+/// A callback invoked as the cursor of a [`Scanner`] advances.
+#[derive(Clone, Copy)]
+pub(super) struct ProgressHook<'a> {
+    callback: &'a (dyn Fn(usize, usize) + Sync),
+    every: usize,
+    /// The offset at which to invoke the callback next.
+    next: usize,
+}
+
+impl<'a> Scanner<'a> {
+    /// Returns the fraction of the input consumed so far, from `0.0` to
+    /// `1.0`. An empty input counts as fully consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("1234 6789");
+    /// scanner.next_word();
+    /// assert_eq!(scanner.progress(), 4.0 / 9.0);
+    /// ```
+    pub fn progress(&self) -> f64 {
+        match self.input.len() {
+            0 => 1.0,
+            len => self.position as f64 / len as f64,
+        }
+    }
+
+    /// Calls `callback` whenever the cursor has advanced by another `every`
+    /// bytes, to drive a progress bar.
+    ///
+    /// The callback receives the offset of the cursor and the length of the
+    /// input. It is called at most once per scanning method, however far the
+    /// method moves the cursor, and only from methods that consume input.
+    /// A token that fails to parse is not reported. A scan that reads several
+    /// tokens before failing, such as [`Scanner::next_matrix`], may report
+    /// progress before rewinding, in which case the rewound offsets are
+    /// reported again when they are scanned again. Scanners created from
+    /// this one, for example by [`Scanner::partition`], do not call it.
+    ///
+    /// # Arguments
+    ///
+    /// * `every` - The number of bytes between two calls, at least 1.
+    /// * `callback` - The function called with the cursor offset and the
+    ///   input length. It must be `Sync` so that the scanner stays `Send`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Mutex;
+    ///
+    /// use scanner::scanner::Scanner;
+    /// let reports = Mutex::new(Vec::new());
+    /// let report = |position, len| reports.lock().unwrap().push((position, len));
+    ///
+    /// let mut scanner = Scanner::new("aa bb cc dd").with_progress(5, &report);
+    /// while scanner.next_word().is_some() {}
+    /// assert_eq!(*reports.lock().unwrap(), [(5, 11), (11, 11)]);
+    /// ```
+    pub fn with_progress(
+        mut self,
+        every: usize,
+        callback: &'a (dyn Fn(usize, usize) + Sync),
+    ) -> Self {
+        let every = every.max(1);
+        self.progress = Some(ProgressHook {
+            callback,
+            every,
+            next: (self.position / every + 1) * every,
+        });
+        self
+    }
+
+    /// Calls the progress callback if the cursor has reached the next
+    /// reporting offset.
+    pub(super) fn report_progress(&mut self) {
+        let Some(hook) = &mut self.progress else {
+            return;
+        };
+
+        if self.position >= hook.next {
+            (hook.callback)(self.position, self.input.len());
+            hook.next = (self.position / hook.every + 1) * hook.every;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[test]
+    fn test_failed_scans_and_lines() {
+        assert_eq!(Scanner::new("").progress(), 1.0);
+
+        let calls = AtomicUsize::new(0);
+        let count = |_, _| {
+            calls.fetch_add(1, Ordering::Relaxed);
+        };
+        let mut scanner = Scanner::new("a\nb\nc\n").with_progress(0, &count);
+        assert_eq!(scanner.next_number::<i32>(), None);
+        assert_eq!(calls.load(Ordering::Relaxed), 0);
+        while scanner.next_line().is_some() {}
+        assert_eq!(calls.load(Ordering::Relaxed), 3);
+        assert_eq!(scanner.progress(), 1.0);
+    }
+
+    #[test]
+    fn test_failed_parse_is_not_reported() {
+        let reports = Mutex::new(Vec::new());
+        let report = |position, len| reports.lock().unwrap().push((position, len));
+
+        let mut scanner = Scanner::new("99999999999 1").with_progress(5, &report);
+        assert_eq!(scanner.next_number::<i32>(), None);
+        assert!(reports.lock().unwrap().is_empty());
+        assert_eq!(scanner.next_number::<i64>(), Some(99_999_999_999));
+        assert_eq!(*reports.lock().unwrap(), [(11, 13)]);
+
+        let mut scanner = Scanner::new("aa bb cc").with_progress(5, &report);
+        assert_eq!(scanner.attempt(|s| s.skip_tokens(3).checked_sub(4)), None);
+        scanner.skip_tokens(2);
+        assert_eq!(reports.lock().unwrap()[1..], [(5, 8), (5, 8)]);
+    }
+}
@@ -0,0 +1,112 @@
+use std::borrow::Cow;
+
+use super::{ScanError, Scanner};
+
+/// A decoded `(key, value)` pair of a URL query string.
+pub type QueryPair<'a> = (Cow<'a, str>, Cow<'a, str>);
+
+/// Decodes `+` as a space and `%XX` escapes as bytes, as in
+/// `application/x-www-form-urlencoded` data.
+///
+/// Malformed escapes are kept as they are, and decoded bytes that are not
+/// valid UTF-8 are replaced with U+FFFD.
+fn percent_decode(text: &str) -> Cow<'_, str> {
+    if !text.contains(['+', '%']) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut rest = text.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        let escaped = match (byte, tail) {
+            (b'%', [high, low, ..]) => char::from(*high)
+                .to_digit(16)
+                .zip(char::from(*low).to_digit(16)),
+            _ => None,
+        };
+
+        match (byte, escaped) {
+            (_, Some((high, low))) => {
+                bytes.push((high * 16 + low) as u8);
+                rest = &tail[2..];
+                continue;
+            }
+            (b'+', None) => bytes.push(b' '),
+            (byte, None) => bytes.push(byte),
+        }
+        rest = tail;
+    }
+
+    Cow::Owned(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+impl<'a> Scanner<'a> {
+    /// Scans the next URL query string, such as `a=1&b=two%20words`, into
+    /// its decoded pairs.
+    ///
+    /// The query string is the next whitespace-delimited word. A leading `?`
+    /// is skipped and a `#` fragment is ignored. Pairs are separated by `&`
+    /// and empty ones are dropped; a pair without `=` has an empty value.
+    /// Keys and values are decoded as form data: `+` is a space and `%XX` is
+    /// a byte, with malformed escapes kept as they are.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Vec<(Cow<str>, Cow<str>)>)` with the pairs in order, borrowed
+    ///   from the input where nothing is decoded.
+    /// * `None` if only whitespace remains.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("?q=caf%C3%A9+au+lait&page=2&&debug#top");
+    /// let pairs = scanner.next_query_pairs().unwrap();
+    /// assert_eq!(pairs[0], ("q".into(), "café au lait".into()));
+    /// assert_eq!(pairs[1], ("page".into(), "2".into()));
+    /// assert_eq!(pairs[2], ("debug".into(), "".into()));
+    /// assert_eq!(pairs.len(), 3);
+    /// ```
+    pub fn next_query_pairs(&mut self) -> Option<Vec<QueryPair<'a>>> {
+        self.try_next_query_pairs().ok()
+    }
+
+    /// Scans the next URL query string into its decoded pairs.
+    ///
+    /// See [`Scanner::next_query_pairs`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`](super::ScanErrorKind::EndOfInput) if
+    ///   only whitespace remains.
+    /// * [`ScanErrorKind::BudgetExceeded`](super::ScanErrorKind::BudgetExceeded)
+    ///   if the scanner's budget is used up.
+    pub fn try_next_query_pairs(&mut self) -> Result<Vec<QueryPair<'a>>, ScanError> {
+        let word = self.try_next_word()?;
+        let query = word.strip_prefix('?').unwrap_or(word);
+        let query = query.split_once('#').map_or(query, |(query, _)| query);
+
+        Ok(query
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                (percent_decode(key), percent_decode(value))
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percent_decode() {
+        assert!(matches!(percent_decode("plain"), Cow::Borrowed("plain")));
+        assert_eq!(percent_decode("a%2Bb+c"), "a+b c");
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%zz%4"), "%zz%4");
+        assert_eq!(percent_decode("%FF"), "\u{FFFD}");
+    }
+}
@@ -0,0 +1,109 @@
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use super::{ScanError, ScanErrorKind, Scanner};
+
+impl Scanner<'_> {
+    /// Scans for the next inclusive range of numbers, written `3-7` or
+    /// `10..=20`, as a single token.
+    ///
+    /// Leading whitespace is skipped. Both bounds are integers as read by
+    /// [`Scanner::next_number`]; the start may have a sign according to the
+    /// [`SignPolicy`](super::SignPolicy), and so may the end of a `..=`
+    /// range. The half-open `a..b` form is not accepted, as it has no
+    /// inclusive equivalent for every `T`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(RangeInclusive<T>)` if a range is next.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("pages 3-7, -10..=-2 5");
+    /// scanner.next_word();
+    /// assert_eq!(scanner.next_range(), Some(3..=7));
+    /// scanner.expect(",").unwrap();
+    /// assert_eq!(scanner.next_range::<i32>(), Some(-10..=-2));
+    /// assert_eq!(scanner.next_range::<i32>(), None);
+    /// ```
+    pub fn next_range<T>(&mut self) -> Option<RangeInclusive<T>>
+    where
+        T: FromStr,
+    {
+        self.try_next_range().ok()
+    }
+
+    /// Scans for the next inclusive range of numbers.
+    ///
+    /// See [`Scanner::next_range`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only whitespace remains.
+    /// * [`ScanErrorKind::InvalidFormat`] if no range is next.
+    /// * [`ScanErrorKind::Overflow`] if a bound does not fit in `T`.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_range<T>(&mut self) -> Result<RangeInclusive<T>, ScanError>
+    where
+        T: FromStr,
+    {
+        let remaining = self.get_remaining();
+        let trimmed = remaining.trim_start();
+        let start = self.position + remaining.len() - trimmed.len();
+
+        if trimmed.is_empty() {
+            return Err(self.error(ScanErrorKind::EndOfInput));
+        }
+
+        let previous = self.input[..start].chars().next_back();
+        let invalid = ScanError::new(ScanErrorKind::InvalidFormat { format: "range" }, start);
+        let signed = |text: &str| usize::from(text.starts_with(['-', '+']));
+        if signed(trimmed) == 1 && !self.sign_policy.is_sign_after(previous) {
+            return Err(invalid);
+        }
+
+        let format = self.number_format;
+        let (low_end, low) = format.digits(trimmed, signed(trimmed), 10);
+        let (high_sign, high_start) = match &trimmed[low_end..] {
+            rest if rest.starts_with("..=") => (&rest[3..3 + signed(&rest[3..])], low_end + 3),
+            rest if rest.starts_with('-') => ("", low_end + 1),
+            _ => return Err(invalid),
+        };
+        let (len, high) = format.digits(trimmed, high_start + high_sign.len(), 10);
+        if low.is_empty() || high.is_empty() {
+            return Err(invalid);
+        }
+
+        let overflow = || ScanError::new(ScanErrorKind::Overflow, start);
+        let low = format!("{}{low}", &trimmed[..signed(trimmed)]);
+        let high = format!("{high_sign}{high}");
+        let low = low.parse().map_err(|_| overflow())?;
+        let high = high.parse().map_err(|_| overflow())?;
+
+        self.consume(start, start + len, start + len)?;
+        Ok(low..=high)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_forms() {
+        let mut scanner = Scanner::new("5-3 +1..=+2 1..2 300-1");
+        assert_eq!(scanner.next_range(), Some(RangeInclusive::new(5, 3)));
+        assert_eq!(scanner.next_range(), Some(1..=2));
+
+        let error = scanner.try_next_range::<u8>().unwrap_err();
+        assert_eq!(error.to_string(), "invalid range at offset 12");
+        scanner.next_word();
+
+        let error = scanner.try_next_range::<u8>().unwrap_err();
+        assert_eq!(error.kind(), &ScanErrorKind::Overflow);
+        assert_eq!(scanner.get_remaining(), " 300-1");
+    }
+}
@@ -0,0 +1,53 @@
+use std::str::FromStr;
+
+use num_integer::Integer;
+use num_rational::Ratio;
+
+use super::{ScanError, ScanErrorKind, Scanner};
+
+impl Scanner<'_> {
+    /// Scans for the next fraction as a reduced [`Ratio`].
+    ///
+    /// Fractions are read as by [`Scanner::next_fraction`].
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Ratio<T>)` if a fraction with a non-zero denominator is found.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_rational::Ratio;
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("-6/8 1/0");
+    /// assert_eq!(scanner.next_ratio(), Some(Ratio::new(-3, 4)));
+    /// assert_eq!(scanner.next_ratio::<i32>(), None);
+    /// ```
+    pub fn next_ratio<T>(&mut self) -> Option<Ratio<T>>
+    where
+        T: Clone + Integer + FromStr,
+    {
+        self.try_next_ratio().ok()
+    }
+
+    /// Scans for the next fraction as a reduced [`Ratio`].
+    ///
+    /// See [`Scanner::try_next_fraction`] for the errors. A zero denominator
+    /// is reported as [`ScanErrorKind::InvalidNumber`].
+    pub fn try_next_ratio<T>(&mut self) -> Result<Ratio<T>, ScanError>
+    where
+        T: Clone + Integer + FromStr,
+    {
+        let checkpoint = self.checkpoint();
+        let (numerator, denominator) = self.try_next_fraction::<T>()?;
+
+        if denominator.is_zero() {
+            let start = self.last.map_or(self.position, |(start, _)| start);
+            self.restore(checkpoint);
+            return Err(ScanError::new(ScanErrorKind::InvalidNumber, start));
+        }
+
+        Ok(Ratio::new(numerator, denominator))
+    }
+}
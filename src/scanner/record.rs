@@ -0,0 +1,171 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use super::{ScanError, ScanErrorKind, Scanner};
+
+/// What [`Scanner::next_record_map`] does with a key seen twice in a record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicateKeys {
+    /// Keep the last value.
+    #[default]
+    Last,
+    /// Keep the first value.
+    First,
+    /// Fail with [`ScanErrorKind::DuplicateKey`].
+    Error,
+}
+
+impl<'a> Scanner<'a> {
+    /// Scans a block of `key value` lines into a map.
+    ///
+    /// The block is the next paragraph, as in [`Scanner::next_paragraph`]:
+    /// a run of non-blank lines ending at a blank line or the end of the
+    /// input. On each line, the key is the first whitespace-separated word,
+    /// without a trailing `:`, and the value is the rest of the line with
+    /// surrounding whitespace trimmed. This covers the output of tools like
+    /// `ssh -G` as well as RFC 822-style `Key: value` headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `duplicates` - What to do with a key seen twice.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(HashMap)` with the pairs of the block.
+    /// * `None` if only blank lines remain or a duplicate key is rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{DuplicateKeys, Scanner};
+    /// let mut scanner = Scanner::new("user git\nport 22\nHost: example.org\n\nuser root\n");
+    /// let record = scanner.next_record_map(DuplicateKeys::Last).unwrap();
+    /// assert_eq!(record["user"], "git");
+    /// assert_eq!(record["port"], "22");
+    /// assert_eq!(record["Host"], "example.org");
+    /// assert_eq!(record.len(), 3);
+    /// ```
+    pub fn next_record_map(
+        &mut self,
+        duplicates: DuplicateKeys,
+    ) -> Option<HashMap<&'a str, &'a str>> {
+        self.try_next_record_map(duplicates).ok()
+    }
+
+    /// Scans a block of `key value` lines into a map.
+    ///
+    /// See [`Scanner::next_record_map`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only blank lines remain.
+    /// * [`ScanErrorKind::DuplicateKey`] at the second occurrence of a key
+    ///   if `duplicates` is [`DuplicateKeys::Error`]. Nothing is consumed
+    ///   then.
+    pub fn try_next_record_map(
+        &mut self,
+        duplicates: DuplicateKeys,
+    ) -> Result<HashMap<&'a str, &'a str>, ScanError> {
+        let checkpoint = self.checkpoint();
+        let Some(block) = self.next_paragraph() else {
+            return Err(self.error(ScanErrorKind::EndOfInput));
+        };
+
+        let mut offset = self.last.map_or(self.position, |(start, _)| start);
+        let mut record = HashMap::new();
+
+        for line in self.newline_policy.split_inclusive(block) {
+            let trimmed = line.trim_start();
+            let key_start = offset + line.len() - trimmed.len();
+            offset += line.len();
+
+            let (key, value) = trimmed
+                .split_once(char::is_whitespace)
+                .unwrap_or((trimmed, ""));
+            let key = key.strip_suffix(':').unwrap_or(key);
+            let value = value.trim();
+
+            if key.is_empty() {
+                continue;
+            }
+
+            match record.entry(key) {
+                Entry::Vacant(entry) => {
+                    entry.insert(value);
+                }
+                Entry::Occupied(mut entry) => match duplicates {
+                    DuplicateKeys::Last => {
+                        entry.insert(value);
+                    }
+                    DuplicateKeys::First => {}
+                    DuplicateKeys::Error => {
+                        self.restore(checkpoint);
+                        return Err(ScanError::new(
+                            ScanErrorKind::DuplicateKey {
+                                key: key.to_string(),
+                            },
+                            key_start,
+                        ));
+                    }
+                },
+            }
+        }
+
+        Ok(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_map_blocks() {
+        let mut scanner = Scanner::new("\n\nlink/ether 00:11\n  mtu 1500\nflag\n\nstate UP");
+        let record = scanner.next_record_map(DuplicateKeys::Last).unwrap();
+        assert_eq!(record.len(), 3);
+        assert_eq!(record["link/ether"], "00:11");
+        assert_eq!(record["mtu"], "1500");
+        assert_eq!(record["flag"], "");
+
+        let record = scanner.next_record_map(DuplicateKeys::Last).unwrap();
+        assert_eq!(record["state"], "UP");
+        assert_eq!(
+            scanner
+                .try_next_record_map(DuplicateKeys::Last)
+                .unwrap_err()
+                .kind(),
+            &ScanErrorKind::EndOfInput
+        );
+    }
+
+    #[test]
+    fn test_record_map_duplicates() {
+        let input = "a 1\nb 2\n a 3";
+
+        let mut scanner = Scanner::new(input);
+        assert_eq!(
+            scanner.next_record_map(DuplicateKeys::First).unwrap()["a"],
+            "1"
+        );
+
+        let mut scanner = Scanner::new(input);
+        assert_eq!(
+            scanner.next_record_map(DuplicateKeys::Last).unwrap()["a"],
+            "3"
+        );
+
+        let mut scanner = Scanner::new(input);
+        let error = scanner
+            .try_next_record_map(DuplicateKeys::Error)
+            .unwrap_err();
+        assert_eq!(
+            error.kind(),
+            &ScanErrorKind::DuplicateKey {
+                key: "a".to_string()
+            }
+        );
+        assert_eq!(error.position(), 9);
+        assert_eq!(scanner.position(), 0);
+    }
+}
@@ -0,0 +1,82 @@
+use super::Scanner;
+
+impl<'a> Scanner<'a> {
+    /// Skips forward past the next synchronization token, to resume scanning
+    /// after an error.
+    ///
+    /// After a failed `try_next_*` call, this lets a parser discard the rest
+    /// of the bad statement or record and keep going, so that several errors
+    /// can be reported for one input instead of stopping at the first one.
+    /// The earliest occurrence of any of `sync` wins; on a tie, the longest
+    /// token does.
+    ///
+    /// # Arguments
+    ///
+    /// * `sync` - The synchronization tokens, e.g. `&[";", "\n"]`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` with the synchronization token that was consumed.
+    /// * `None` if none occurs in the remaining input, in which case the
+    ///   whole remaining input is skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("1; oops 2; 3;");
+    /// let mut numbers = Vec::new();
+    /// let mut errors = Vec::new();
+    /// while scanner.has_next() {
+    ///     match scanner.try_next_number::<i32>() {
+    ///         Ok(n) => numbers.push(n),
+    ///         Err(error) => errors.push(error.position()),
+    ///     }
+    ///     scanner.recover_to(&[";"]);
+    /// }
+    /// assert_eq!(numbers, [1, 3]);
+    /// assert_eq!(errors, [3]);
+    /// ```
+    pub fn recover_to(&mut self, sync: &[&str]) -> Option<&'a str> {
+        let remaining = self.get_remaining();
+
+        let found = sync
+            .iter()
+            .filter(|token| !token.is_empty())
+            .filter_map(|token| remaining.find(token).map(|i| (i, token.len())))
+            .min_by_key(|&(i, len)| (i, usize::MAX - len));
+
+        match found {
+            Some((i, len)) => {
+                self.take(i + len);
+                Some(&remaining[i..i + len])
+            }
+            None => {
+                self.take(remaining.len());
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recover_to_earliest() {
+        let mut scanner = Scanner::new("bad tokens\nnext; line");
+        assert_eq!(scanner.recover_to(&[";", "\n"]), Some("\n"));
+        assert_eq!(scanner.get_remaining(), "next; line");
+        assert_eq!(scanner.recover_to(&[";", "\n"]), Some(";"));
+        assert_eq!(scanner.recover_to(&[";", "\n"]), None);
+        assert_eq!(scanner.get_remaining(), "");
+    }
+
+    #[test]
+    fn test_recover_to_longest_on_tie() {
+        let mut scanner = Scanner::new("x\r\ny");
+        assert_eq!(scanner.recover_to(&["\r", "\r\n"]), Some("\r\n"));
+        assert_eq!(scanner.get_remaining(), "y");
+    }
+}
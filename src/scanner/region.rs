@@ -0,0 +1,180 @@
+use std::ops::Range;
+
+use super::Scanner;
+
+/// A transformed copy of a region of the input that remembers where every
+/// piece of the transformed text came from.
+///
+/// A `MappedRegion` is produced by [`Scanner::map_region`] and friends. Since a
+/// transformation may change the byte length of a character (`'ß'` uppercases
+/// to `"SS"`), offsets into the transformed text are not offsets into the
+/// original input. The region keeps a mapping so they can be translated back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MappedRegion {
+    text: String,
+    original: Range<usize>,
+    /// `(mapped offset, original offset)` for each character of the original region.
+    map: Vec<(usize, usize)>,
+}
+
+impl MappedRegion {
+    /// Returns the transformed text.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the byte range of the original input this region was built from.
+    pub fn original_range(&self) -> Range<usize> {
+        self.original.clone()
+    }
+
+    /// Translates a byte offset in the transformed text to a byte offset in
+    /// the original input.
+    ///
+    /// Offsets pointing inside the expansion of a single original character
+    /// map to the start of that character. Offsets at or past the end of the
+    /// transformed text map to the end of the original region.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let scanner = Scanner::new("straße ok");
+    /// let region = scanner.uppercase_region(0..7).unwrap();
+    /// assert_eq!(region.as_str(), "STRASSE");
+    /// assert_eq!(region.original_offset(5), 4);
+    /// assert_eq!(region.original_offset(6), 6);
+    /// ```
+    pub fn original_offset(&self, offset: usize) -> usize {
+        if offset >= self.text.len() {
+            return self.original.end;
+        }
+
+        let index = self.map.partition_point(|&(mapped, _)| mapped <= offset);
+        self.map[index - 1].1
+    }
+
+    /// Translates a byte range in the transformed text to the byte range of
+    /// the original input it was produced from.
+    ///
+    /// The resulting range always covers whole original characters.
+    pub fn original_span(&self, range: Range<usize>) -> Range<usize> {
+        let start = self.original_offset(range.start);
+        let end = match self.map.iter().find(|&&(mapped, _)| mapped >= range.end) {
+            Some(&(_, original)) => original,
+            None => self.original.end,
+        };
+
+        start..end.max(start)
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Produces a transformed copy of a region of the input, recording the
+    /// mapping from the transformed text back to the original offsets.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The byte range of the input to transform.
+    /// * `f` - A closure mapping every character of the region to its replacement.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(MappedRegion)` holding the transformed text.
+    /// * `None` if `range` is out of bounds or does not fall on character boundaries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let scanner = Scanner::new("a-b-c");
+    /// let region = scanner.map_region(0..5, |c| if c == '-' { vec![] } else { vec![c] }).unwrap();
+    /// assert_eq!(region.as_str(), "abc");
+    /// assert_eq!(region.original_offset(2), 4);
+    /// ```
+    pub fn map_region<F, I>(&self, range: Range<usize>, mut f: F) -> Option<MappedRegion>
+    where
+        F: FnMut(char) -> I,
+        I: IntoIterator<Item = char>,
+    {
+        let region = self.input.get(range.clone())?;
+
+        let mut text = String::with_capacity(region.len());
+        let mut map = Vec::with_capacity(region.len());
+
+        for (i, c) in region.char_indices() {
+            map.push((text.len(), range.start + i));
+            text.extend(f(c));
+        }
+
+        Some(MappedRegion {
+            text,
+            original: range,
+            map,
+        })
+    }
+
+    /// Produces an uppercased copy of a region of the input.
+    ///
+    /// See [`Scanner::map_region`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let scanner = Scanner::new("select * from t");
+    /// assert_eq!(scanner.uppercase_region(0..6).unwrap().as_str(), "SELECT");
+    /// ```
+    pub fn uppercase_region(&self, range: Range<usize>) -> Option<MappedRegion> {
+        self.map_region(range, char::to_uppercase)
+    }
+
+    /// Produces a lowercased copy of a region of the input.
+    ///
+    /// See [`Scanner::map_region`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let scanner = Scanner::new("SELECT * FROM t");
+    /// assert_eq!(scanner.lowercase_region(9..13).unwrap().as_str(), "from");
+    /// ```
+    pub fn lowercase_region(&self, range: Range<usize>) -> Option<MappedRegion> {
+        self.map_region(range, char::to_lowercase)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_region_expanding() {
+        let scanner = Scanner::new("Maß und Ziel");
+        let region = scanner.uppercase_region(0..4).unwrap();
+        assert_eq!(region.as_str(), "MASS");
+        assert_eq!(region.original_range(), 0..4);
+        assert_eq!(region.original_offset(0), 0);
+        assert_eq!(region.original_offset(2), 2);
+        assert_eq!(region.original_offset(3), 2);
+        assert_eq!(region.original_span(2..4), 2..4);
+        assert_eq!(region.original_span(0..1), 0..1);
+    }
+
+    #[test]
+    fn test_map_region_offset_region() {
+        let scanner = Scanner::new("let X = 1");
+        let region = scanner.lowercase_region(4..5).unwrap();
+        assert_eq!(region.as_str(), "x");
+        assert_eq!(region.original_offset(0), 4);
+        assert_eq!(region.original_offset(1), 5);
+    }
+
+    #[test]
+    fn test_map_region_invalid_range() {
+        let scanner = Scanner::new("ßa");
+        assert_eq!(scanner.uppercase_region(1..2), None);
+        assert_eq!(scanner.uppercase_region(0..10), None);
+    }
+}
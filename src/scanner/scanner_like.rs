@@ -0,0 +1,120 @@
+use std::str::FromStr;
+
+use super::{ChunkScanner, Scanner};
+
+/// The scanning methods shared by [`Scanner`], [`ChunkScanner`] and test
+/// doubles such as [`ScriptedScanner`](super::ScriptedScanner).
+///
+/// Code that only needs words, numbers and lines can accept
+/// `impl ScannerLike`, so that it works with in-memory and chunked input
+/// alike and can be unit-tested with scripted tokens.
+///
+/// # Examples
+///
+/// ```
+/// use scanner::scanner::{ChunkScanner, Scanner, ScannerLike};
+///
+/// fn sum(scanner: &mut impl ScannerLike) -> i64 {
+///     std::iter::from_fn(|| scanner.next_number::<i64>()).sum()
+/// }
+///
+/// assert_eq!(sum(&mut Scanner::new("1 2 3")), 6);
+/// assert_eq!(sum(&mut ChunkScanner::new(["1 2", "0 3"])), 24);
+/// ```
+pub trait ScannerLike {
+    /// Scans for the next word. See [`Scanner::next_word`].
+    fn next_word(&mut self) -> Option<&str>;
+
+    /// Scans for the next number. See [`Scanner::next_number`].
+    fn next_number<T>(&mut self) -> Option<T>
+    where
+        T: FromStr;
+
+    /// Scans for the next line. See [`Scanner::next_line`].
+    fn next_line(&mut self) -> Option<&str>;
+
+    /// Returns the byte offset of the cursor in the input.
+    fn position(&self) -> usize;
+}
+
+impl ScannerLike for Scanner<'_> {
+    fn next_word(&mut self) -> Option<&str> {
+        Scanner::next_word(self)
+    }
+
+    fn next_number<T>(&mut self) -> Option<T>
+    where
+        T: FromStr,
+    {
+        Scanner::next_number(self)
+    }
+
+    fn next_line(&mut self) -> Option<&str> {
+        Scanner::next_line(self)
+    }
+
+    fn position(&self) -> usize {
+        Scanner::position(self)
+    }
+}
+
+impl<I> ScannerLike for ChunkScanner<I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    fn next_word(&mut self) -> Option<&str> {
+        ChunkScanner::next_word(self)
+    }
+
+    fn next_number<T>(&mut self) -> Option<T>
+    where
+        T: FromStr,
+    {
+        ChunkScanner::next_number(self)
+    }
+
+    fn next_line(&mut self) -> Option<&str> {
+        ChunkScanner::next_line(self)
+    }
+
+    fn position(&self) -> usize {
+        ChunkScanner::position(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Scans a header line, then sums the numbers of the line after it.
+    fn scan(scanner: &mut impl ScannerLike) -> (String, i32, usize) {
+        let name = scanner.next_word().unwrap_or_default().to_string();
+        scanner.next_line();
+        let total: i32 = std::iter::from_fn(|| scanner.next_number::<i32>()).sum();
+        (name, total, scanner.position())
+    }
+
+    #[test]
+    fn test_implementations_agree() {
+        let input = "totals: ignored\n1 2 30 x\n";
+        let expected = (String::from("totals:"), 33, 22);
+
+        assert_eq!(scan(&mut Scanner::new(input)), expected);
+        assert_eq!(
+            scan(&mut ChunkScanner::new([
+                "tot",
+                "als: ig",
+                "nored\n1 ",
+                "2 3",
+                "0 x\n"
+            ])),
+            expected
+        );
+
+        let mut scanner = Scanner::new("");
+        assert_eq!(ScannerLike::next_word(&mut scanner), None);
+        assert_eq!(ScannerLike::next_line(&mut scanner), None);
+        assert_eq!(ScannerLike::position(&scanner), 0);
+    }
+}
@@ -0,0 +1,265 @@
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+use super::{Scanner, ScannerLike};
+
+/// The [`ScannerLike`] method of a recorded call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallKind {
+    /// [`ScannerLike::next_word`].
+    Word,
+    /// [`ScannerLike::next_number`].
+    Number,
+    /// [`ScannerLike::next_line`].
+    Line,
+}
+
+/// A call made through a [`RecordingScanner`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedCall {
+    /// The method called.
+    pub kind: CallKind,
+    /// The text returned, or `None` if the call failed. For numbers, this
+    /// is the text that was parsed, without the separators allowed by the
+    /// scanner's [`NumberFormat`](super::NumberFormat).
+    pub value: Option<String>,
+    /// The position of the cursor after the call.
+    pub position: usize,
+}
+
+/// A [`Scanner`] that records every call made through [`ScannerLike`], so
+/// that a real scanning session can be replayed with
+/// [`ScriptedScanner::replay`].
+///
+/// # Examples
+///
+/// ```
+/// use scanner::scanner::{RecordingScanner, Scanner, ScannerLike};
+/// let mut recording = RecordingScanner::new(Scanner::new("move 3\n"));
+/// assert_eq!(recording.next_word(), Some("move"));
+/// assert_eq!(recording.next_number::<u8>(), Some(3));
+///
+/// let mut replay = recording.replay();
+/// assert_eq!(replay.next_word(), Some("move"));
+/// assert_eq!(replay.next_number::<u8>(), Some(3));
+/// assert_eq!(replay.position(), 6);
+/// assert!(replay.is_finished());
+/// ```
+#[derive(Debug, Clone)]
+pub struct RecordingScanner<'a> {
+    scanner: Scanner<'a>,
+    calls: Vec<RecordedCall>,
+}
+
+impl<'a> RecordingScanner<'a> {
+    /// Creates a new `RecordingScanner` recording calls on `scanner`.
+    pub fn new(scanner: Scanner<'a>) -> Self {
+        RecordingScanner {
+            scanner,
+            calls: Vec::new(),
+        }
+    }
+
+    /// Returns the calls recorded so far.
+    pub fn calls(&self) -> &[RecordedCall] {
+        &self.calls
+    }
+
+    /// Returns a [`ScriptedScanner`] replaying the recorded calls.
+    pub fn replay(&self) -> ScriptedScanner {
+        ScriptedScanner::replay(self.calls.iter().cloned())
+    }
+
+    fn record(&mut self, kind: CallKind, value: Option<&str>) {
+        self.calls.push(RecordedCall {
+            kind,
+            value: value.map(str::to_string),
+            position: self.scanner.position(),
+        });
+    }
+}
+
+impl ScannerLike for RecordingScanner<'_> {
+    fn next_word(&mut self) -> Option<&str> {
+        let word = self.scanner.next_word();
+        self.record(CallKind::Word, word);
+        word
+    }
+
+    fn next_number<T>(&mut self) -> Option<T>
+    where
+        T: FromStr,
+    {
+        let number = self.scanner.next_number();
+        let text = number.as_ref().and(self.scanner.last_span()).map(|span| {
+            let text = &self.scanner.input[span.start..span.end];
+            let sign = usize::from(text.starts_with(['-', '+']));
+            let (_, digits) = self.scanner.number_format.digits(text, sign, 10);
+            format!("{}{digits}", &text[..sign])
+        });
+        self.record(CallKind::Number, text.as_deref());
+        number
+    }
+
+    fn next_line(&mut self) -> Option<&str> {
+        let line = self.scanner.next_line();
+        self.record(CallKind::Line, line);
+        line
+    }
+
+    fn position(&self) -> usize {
+        self.scanner.position()
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Script {
+    Tokens(VecDeque<String>),
+    Replay(VecDeque<RecordedCall>),
+}
+
+/// A [`ScannerLike`] test double that answers calls from a script instead
+/// of scanning text.
+///
+/// A scanner created with [`ScriptedScanner::from_tokens`] hands out a
+/// fixed sequence of tokens to whichever method is called. One created with
+/// [`ScriptedScanner::replay`] returns the results of a recorded session in
+/// order.
+///
+/// # Examples
+///
+/// ```
+/// use scanner::scanner::{ScannerLike, ScriptedScanner};
+/// let mut scanner = ScriptedScanner::from_tokens(["add", "2", "two words"]);
+/// assert_eq!(scanner.next_number::<i32>(), None);
+/// assert_eq!(scanner.next_word(), Some("add"));
+/// assert_eq!(scanner.next_number::<i32>(), Some(2));
+/// assert_eq!(scanner.next_line(), Some("two words"));
+/// assert_eq!(scanner.next_word(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ScriptedScanner {
+    script: Script,
+    /// The value last returned, which borrowed results point into.
+    current: Option<String>,
+    position: usize,
+}
+
+impl ScriptedScanner {
+    /// Creates a `ScriptedScanner` handing out `tokens` in order.
+    ///
+    /// Words and lines take the next token as is. A number takes the next
+    /// token only if it parses, like [`Scanner::next_number`] leaves a
+    /// token that is not a number in place. The position is the number of
+    /// tokens taken.
+    pub fn from_tokens<S>(tokens: impl IntoIterator<Item = S>) -> Self
+    where
+        S: Into<String>,
+    {
+        ScriptedScanner {
+            script: Script::Tokens(tokens.into_iter().map(Into::into).collect()),
+            current: None,
+            position: 0,
+        }
+    }
+
+    /// Creates a `ScriptedScanner` replaying recorded `calls` in order.
+    ///
+    /// Calls must be made in the recorded order. Once the script is used
+    /// up, every call returns `None`.
+    ///
+    /// # Panics
+    ///
+    /// A call panics if it is not the call that was recorded next.
+    pub fn replay(calls: impl IntoIterator<Item = RecordedCall>) -> Self {
+        ScriptedScanner {
+            script: Script::Replay(calls.into_iter().collect()),
+            current: None,
+            position: 0,
+        }
+    }
+
+    /// Returns `true` if every token or recorded call has been used.
+    pub fn is_finished(&self) -> bool {
+        match &self.script {
+            Script::Tokens(tokens) => tokens.is_empty(),
+            Script::Replay(calls) => calls.is_empty(),
+        }
+    }
+
+    /// Answers a call of `kind`, taking the next token only if `accept`
+    /// holds for it.
+    fn answer(&mut self, kind: CallKind, accept: impl Fn(&str) -> bool) -> Option<&str> {
+        self.current = match &mut self.script {
+            Script::Tokens(tokens) => {
+                let token = tokens.pop_front_if(|token| accept(token))?;
+                self.position += 1;
+                Some(token)
+            }
+            Script::Replay(calls) => {
+                let call = calls.pop_front()?;
+                assert_eq!(
+                    call.kind, kind,
+                    "expected a {:?} call, but a {kind:?} call was made",
+                    call.kind
+                );
+                self.position = call.position;
+                call.value
+            }
+        };
+        self.current.as_deref()
+    }
+}
+
+impl ScannerLike for ScriptedScanner {
+    fn next_word(&mut self) -> Option<&str> {
+        self.answer(CallKind::Word, |_| true)
+    }
+
+    fn next_number<T>(&mut self) -> Option<T>
+    where
+        T: FromStr,
+    {
+        self.answer(CallKind::Number, |token| token.parse::<T>().is_ok())?
+            .parse()
+            .ok()
+    }
+
+    fn next_line(&mut self) -> Option<&str> {
+        self.answer(CallKind::Line, |_| true)
+    }
+
+    fn position(&self) -> usize {
+        self.position
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "expected a Word call, but a Line call was made")]
+    fn test_replay_out_of_order_panics() {
+        let mut recording = RecordingScanner::new(Scanner::new("a b"));
+        recording.next_word();
+        assert_eq!(recording.calls()[0].position, 1);
+
+        recording.replay().next_line();
+    }
+
+    #[test]
+    fn test_replay_numbers_with_separators() {
+        let scanner = Scanner::new("-1_000 2_5")
+            .with_number_format(crate::scanner::NumberFormat::PLAIN.with_underscores());
+        let mut recording = RecordingScanner::new(scanner);
+        assert_eq!(recording.next_number::<i32>(), Some(-1000));
+        assert_eq!(recording.next_number::<u8>(), Some(25));
+        assert_eq!(recording.calls()[0].value.as_deref(), Some("-1000"));
+
+        let mut replay = recording.replay();
+        assert_eq!(replay.next_number::<i32>(), Some(-1000));
+        assert_eq!(replay.next_number::<u8>(), Some(25));
+        assert_eq!(replay.position(), 10);
+    }
+}
@@ -0,0 +1,73 @@
+use super::Scanner;
+
+impl Scanner<'_> {
+    /// Returns `true` if the remaining input starts with `pattern`, without
+    /// skipping whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("# comment\nx = 1");
+    /// if scanner.remaining_starts_with("#") {
+    ///     scanner.next_line();
+    /// }
+    /// assert_eq!(scanner.next_word(), Some("x"));
+    /// ```
+    pub fn remaining_starts_with(&self, pattern: &str) -> bool {
+        self.get_remaining().starts_with(pattern)
+    }
+
+    /// Finds the next occurrence of `pattern` in the remaining input.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` with the byte offset of the match in the whole input,
+    ///   which can be passed to [`Scanner::set_position`].
+    /// * `None` if `pattern` does not occur.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("a=1; b=2");
+    /// scanner.next_word();
+    /// assert_eq!(scanner.find("="), Some(6));
+    /// assert_eq!(scanner.find("a"), None);
+    /// ```
+    pub fn find(&self, pattern: &str) -> Option<usize> {
+        self.get_remaining()
+            .find(pattern)
+            .map(|offset| self.position + offset)
+    }
+
+    /// Returns `true` if `pattern` occurs in the remaining input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let scanner = Scanner::new("key: value");
+    /// assert!(scanner.contains(": "));
+    /// assert!(!scanner.contains("="));
+    /// ```
+    pub fn contains(&self, pattern: &str) -> bool {
+        self.get_remaining().contains(pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_is_absolute() {
+        let mut scanner = Scanner::new("ab ab");
+        assert_eq!(scanner.find("ab"), Some(0));
+        scanner.next_word();
+        let offset = scanner.find("ab").unwrap();
+        scanner.set_position(offset).unwrap();
+        assert!(scanner.remaining_starts_with("ab"));
+        assert_eq!(offset, 3);
+    }
+}
@@ -0,0 +1,134 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::{ScanError, ScanErrorKind, Scanner};
+
+impl<'a> Scanner<'a> {
+    /// Scans for the next word according to the Unicode word boundaries of
+    /// [UAX #29](https://www.unicode.org/reports/tr29/).
+    ///
+    /// Unlike [`Scanner::next_word`], punctuation is not glued to words and
+    /// every ideograph of CJK text is a word of its own. Segments without
+    /// any alphanumeric character, such as whitespace and punctuation, are
+    /// skipped.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` with the next word.
+    /// * `None` if no word is left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("\"Hello,\" she said. 你好");
+    /// assert_eq!(scanner.next_unicode_word(), Some("Hello"));
+    /// assert_eq!(scanner.next_unicode_word(), Some("she"));
+    /// assert_eq!(scanner.next_unicode_word(), Some("said"));
+    /// assert_eq!(scanner.next_unicode_word(), Some("你"));
+    /// assert_eq!(scanner.next_unicode_word(), Some("好"));
+    /// assert_eq!(scanner.next_unicode_word(), None);
+    /// ```
+    pub fn next_unicode_word(&mut self) -> Option<&'a str> {
+        self.try_next_unicode_word().ok()
+    }
+
+    /// Scans for the next word according to the Unicode word boundaries.
+    ///
+    /// See [`Scanner::next_unicode_word`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if no word is left.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the budget is used up.
+    pub fn try_next_unicode_word(&mut self) -> Result<&'a str, ScanError> {
+        let remaining = self.get_remaining();
+
+        let (start, word) = remaining
+            .split_word_bound_indices()
+            .find(|(_, segment)| segment.chars().any(char::is_alphanumeric))
+            .ok_or_else(|| self.error(ScanErrorKind::EndOfInput))?;
+
+        let start = self.position + start;
+        let end = start + word.len();
+        self.consume(start, end, end)?;
+        Ok(word)
+    }
+
+    /// Consumes the next extended grapheme cluster, as defined by
+    /// [UAX #29](https://www.unicode.org/reports/tr29/).
+    ///
+    /// Whitespace is not skipped. An emoji sequence or a letter with
+    /// combining marks is consumed as a single unit.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` with the grapheme cluster.
+    /// * `None` at the end of the input or if the budget is used up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("e\u{301}👩‍🔬!");
+    /// assert_eq!(scanner.next_grapheme(), Some("e\u{301}"));
+    /// assert_eq!(scanner.next_grapheme(), Some("👩‍🔬"));
+    /// assert_eq!(scanner.next_grapheme(), Some("!"));
+    /// assert_eq!(scanner.next_grapheme(), None);
+    /// ```
+    pub fn next_grapheme(&mut self) -> Option<&'a str> {
+        let grapheme = self.peek_grapheme()?;
+        Some(self.take(grapheme.len())).filter(|taken| !taken.is_empty())
+    }
+
+    /// Returns the next extended grapheme cluster without consuming it.
+    ///
+    /// See [`Scanner::next_grapheme`].
+    pub fn peek_grapheme(&self) -> Option<&'a str> {
+        self.peek_nth_grapheme(0)
+    }
+
+    /// Returns the `n`-th extended grapheme cluster ahead of the cursor,
+    /// counting from 0, without consuming anything.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let scanner = Scanner::new("🇵🇹🇧🇷 ok");
+    /// assert_eq!(scanner.peek_nth_grapheme(1), Some("🇧🇷"));
+    /// assert_eq!(scanner.peek_nth_grapheme(5), None);
+    /// ```
+    pub fn peek_nth_grapheme(&self, n: usize) -> Option<&'a str> {
+        self.get_remaining().graphemes(true).nth(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unicode_words() {
+        let mut scanner = Scanner::new("can't stop—won't stop 3.14 (x_y)");
+        let mut words = Vec::new();
+        while let Some(word) = scanner.next_unicode_word() {
+            words.push(word);
+        }
+        assert_eq!(words, ["can't", "stop", "won't", "stop", "3.14", "x_y"]);
+        assert_eq!(
+            scanner.try_next_unicode_word().unwrap_err().kind(),
+            &ScanErrorKind::EndOfInput
+        );
+    }
+
+    #[test]
+    fn test_graphemes_and_budget() {
+        let mut scanner = Scanner::new("a\r\nb\u{308}c").with_budget(3, usize::MAX);
+        assert_eq!(scanner.peek_grapheme(), Some("a"));
+        assert_eq!(scanner.next_grapheme(), Some("a"));
+        assert_eq!(scanner.next_grapheme(), Some("\r\n"));
+        assert_eq!(scanner.next_grapheme(), Some("b\u{308}"));
+        assert_eq!(scanner.next_grapheme(), None);
+        assert_eq!(scanner.get_remaining(), "c");
+    }
+}
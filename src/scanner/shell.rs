@@ -0,0 +1,134 @@
+use std::borrow::Cow;
+
+use super::{ScanError, ScanErrorKind, Scanner};
+
+/// Reads the shell word at the start of `text`, which must not start with
+/// whitespace.
+///
+/// Returns the length of the word in `text` and its value with quotes and
+/// escapes removed, or `None` if a quote or a trailing backslash is left
+/// open.
+fn shell_word(text: &str) -> Option<(usize, Cow<'_, str>)> {
+    let end = text.find(char::is_whitespace).unwrap_or(text.len());
+    if !text[..end].contains(['\'', '"', '\\']) {
+        return Some((end, Cow::Borrowed(&text[..end])));
+    }
+
+    let mut word = String::new();
+    let mut chars = text.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            c if c.is_whitespace() => return Some((i, Cow::Owned(word))),
+            '\\' => match chars.next()? {
+                (_, '\n') => {}
+                (_, escaped) => word.push(escaped),
+            },
+            '\'' => loop {
+                match chars.next()? {
+                    (_, '\'') => break,
+                    (_, c) => word.push(c),
+                }
+            },
+            '"' => loop {
+                match chars.next()? {
+                    (_, '"') => break,
+                    (_, '\\') => match chars.next()? {
+                        (_, '\n') => {}
+                        (_, c @ ('$' | '`' | '"' | '\\')) => word.push(c),
+                        (_, c) => {
+                            word.push('\\');
+                            word.push(c);
+                        }
+                    },
+                    (_, c) => word.push(c),
+                }
+            },
+            c => word.push(c),
+        }
+    }
+
+    Some((text.len(), Cow::Owned(word)))
+}
+
+impl<'a> Scanner<'a> {
+    /// Scans for the next word of a shell command line.
+    ///
+    /// Leading whitespace is skipped. As in a POSIX shell, the word ends at
+    /// unquoted whitespace; single quotes keep everything literally, double
+    /// quotes keep everything but the backslash escapes of `$`, `` ` ``,
+    /// `"`, `\` and newline, and an unquoted backslash escapes the next
+    /// character. Quotes and escapes are removed from the result, and
+    /// backslash-newline continues the word. Expansions such as `$HOME` are
+    /// left as they are.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Cow<str>)` with the word, borrowed from the input if it has
+    ///   no quotes or escapes.
+    /// * `None` if no word is left or a quote is left open, in which case
+    ///   nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new(r#"say "hello world" it\'s 'a "b"'"#);
+    /// assert_eq!(scanner.next_shell_word().unwrap(), "say");
+    /// assert_eq!(scanner.next_shell_word().unwrap(), "hello world");
+    /// assert_eq!(scanner.next_shell_word().unwrap(), "it's");
+    /// assert_eq!(scanner.next_shell_word().unwrap(), "a \"b\"");
+    /// assert_eq!(scanner.next_shell_word(), None);
+    /// ```
+    pub fn next_shell_word(&mut self) -> Option<Cow<'a, str>> {
+        self.try_next_shell_word().ok()
+    }
+
+    /// Scans for the next word of a shell command line.
+    ///
+    /// See [`Scanner::next_shell_word`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only whitespace remains.
+    /// * [`ScanErrorKind::InvalidFormat`] if a quote or a trailing backslash
+    ///   is left open.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_shell_word(&mut self) -> Result<Cow<'a, str>, ScanError> {
+        let remaining = self.get_remaining();
+        let trimmed = remaining.trim_start();
+        let start = self.position + remaining.len() - trimmed.len();
+
+        if trimmed.is_empty() {
+            return Err(self.error(ScanErrorKind::EndOfInput));
+        }
+
+        let (len, word) = shell_word(trimmed).ok_or_else(|| {
+            let format = "shell word";
+            ScanError::new(ScanErrorKind::InvalidFormat { format }, start)
+        })?;
+        self.consume(start, start + len, start + len)?;
+        Ok(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shell_word_quoting() {
+        assert_eq!(shell_word("a\\ b c"), Some((4, "a b".into())));
+        assert_eq!(shell_word("\"\\$x\\n\"y"), Some((8, "$x\\ny".into())));
+        assert_eq!(shell_word("''"), Some((2, "".into())));
+        assert_eq!(shell_word("a\\\nb"), Some((4, "ab".into())));
+        assert_eq!(shell_word("'open"), None);
+        assert_eq!(shell_word("trailing\\"), None);
+
+        let mut scanner = Scanner::new("echo \"unterminated");
+        scanner.next_shell_word();
+        let error = scanner.try_next_shell_word().unwrap_err();
+        assert_eq!(error.to_string(), "invalid shell word at offset 5");
+        assert_eq!(scanner.get_remaining(), " \"unterminated");
+    }
+}
@@ -0,0 +1,140 @@
+use super::{ScanError, ScanErrorKind, Scanner};
+
+/// The byte size units, matched case-insensitively, with their multiples.
+const UNITS: [(&str, u64); 14] = [
+    ("b", 1),
+    ("kb", 1_000),
+    ("mb", 1_000_000),
+    ("gb", 1_000_000_000),
+    ("tb", 1_000_000_000_000),
+    ("pb", 1_000_000_000_000_000),
+    ("eb", 1_000_000_000_000_000_000),
+    ("kib", 1 << 10),
+    ("mib", 1 << 20),
+    ("gib", 1 << 30),
+    ("tib", 1 << 40),
+    ("pib", 1 << 50),
+    ("eib", 1 << 60),
+    ("bytes", 1),
+];
+
+/// Returns the multiple of `unit`, if it is a byte size unit.
+fn multiple(unit: &str) -> Option<u64> {
+    UNITS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(unit))
+        .map(|&(_, multiple)| multiple)
+}
+
+/// Multiplies the decimal `number`, such as `4.5`, by `multiple`, rounding
+/// down to whole bytes.
+fn bytes(number: &str, multiple: u64) -> Option<u64> {
+    let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+    let scale = 10u128.checked_pow(u32::try_from(fraction.len()).ok()?)?;
+    let mantissa: u128 = format!("{integer}{fraction}").parse().ok()?;
+
+    u64::try_from(mantissa.checked_mul(u128::from(multiple))? / scale).ok()
+}
+
+impl Scanner<'_> {
+    /// Scans for the next byte size, such as `10MiB`, `4.5 GB` or `512`.
+    ///
+    /// Leading whitespace is skipped. The number may have a fraction, read
+    /// as by [`Scanner::next_float`], and is followed by a decimal unit
+    /// (`B`, `kB`, `MB`, ... `EB`) or a binary unit (`KiB`, `MiB`, ...
+    /// `EiB`), directly or after spaces. Units are matched case-insensitively,
+    /// and a number without a unit counts bytes.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(u64)` with the size in bytes, rounded down.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("10MiB 4.5 GB 512 files");
+    /// assert_eq!(scanner.next_byte_size(), Some(10 * 1024 * 1024));
+    /// assert_eq!(scanner.next_byte_size(), Some(4_500_000_000));
+    /// assert_eq!(scanner.next_byte_size(), Some(512));
+    /// assert_eq!(scanner.get_remaining(), " files");
+    /// ```
+    pub fn next_byte_size(&mut self) -> Option<u64> {
+        self.try_next_byte_size().ok()
+    }
+
+    /// Scans for the next byte size.
+    ///
+    /// See [`Scanner::next_byte_size`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only whitespace remains.
+    /// * [`ScanErrorKind::InvalidFormat`] if no byte size is next, or a
+    ///   number is directly followed by an unknown unit.
+    /// * [`ScanErrorKind::Overflow`] if the size does not fit in a `u64`.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_byte_size(&mut self) -> Result<u64, ScanError> {
+        let remaining = self.get_remaining();
+        let trimmed = remaining.trim_start();
+        let start = self.position + remaining.len() - trimmed.len();
+
+        if trimmed.is_empty() {
+            return Err(self.error(ScanErrorKind::EndOfInput));
+        }
+
+        let invalid = ScanError::new(
+            ScanErrorKind::InvalidFormat {
+                format: "byte size",
+            },
+            start,
+        );
+        let (mut len, number) = match self.number_format.read(trimmed) {
+            Some((len, number)) if !number.contains(['-', '+', 'e', 'E']) => (len, number),
+            _ => return Err(invalid),
+        };
+
+        let after = &trimmed[len..];
+        let spaced = after.trim_start_matches([' ', '\t']);
+        let unit_len = spaced
+            .find(|c: char| !c.is_alphanumeric())
+            .unwrap_or(spaced.len());
+        let unit = &spaced[..unit_len];
+
+        let multiple = match multiple(unit) {
+            Some(multiple) if !unit.is_empty() => {
+                len += after.len() - spaced.len() + unit_len;
+                multiple
+            }
+            _ if after.starts_with(char::is_alphanumeric) => return Err(invalid),
+            _ => 1,
+        };
+
+        let size =
+            bytes(&number, multiple).ok_or(ScanError::new(ScanErrorKind::Overflow, start))?;
+        self.consume(start, start + len, start + len)?;
+        Ok(size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_size_units() {
+        assert_eq!(bytes("1.5", 1 << 10), Some(1536));
+        assert_eq!(bytes("0.0001", 1_000), Some(0));
+        assert_eq!(bytes("16", 1 << 60), None);
+
+        let mut scanner = Scanner::new("2kib 1 kB\t3 B 7xB");
+        assert_eq!(scanner.next_byte_size(), Some(2048));
+        assert_eq!(scanner.next_byte_size(), Some(1000));
+        assert_eq!(scanner.next_byte_size(), Some(3));
+        assert_eq!(scanner.last_span().unwrap().range(), 10..13);
+
+        let error = scanner.try_next_byte_size().unwrap_err();
+        assert_eq!(error.to_string(), "invalid byte size at offset 14");
+    }
+}
@@ -0,0 +1,70 @@
+use super::Scanner;
+
+impl Scanner<'_> {
+    /// Discards the next `n` words.
+    ///
+    /// # Returns
+    ///
+    /// The number of words skipped, which is less than `n` if the input or
+    /// the budget runs out first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("Game 12: 3 blue");
+    /// assert_eq!(scanner.skip_tokens(2), 2);
+    /// assert_eq!(scanner.next_number(), Some(3));
+    /// assert_eq!(scanner.skip_tokens(5), 1);
+    /// ```
+    pub fn skip_tokens(&mut self, n: usize) -> usize {
+        (0..n).map_while(|_| self.next_word()).count()
+    }
+
+    /// Discards the next `n` lines, including the rest of the current one.
+    ///
+    /// # Returns
+    ///
+    /// The number of lines skipped, which is less than `n` if the input or
+    /// the budget runs out first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("name,age\nstring,int\nAda,36\n");
+    /// assert_eq!(scanner.skip_lines(2), 2);
+    /// assert_eq!(scanner.next_line(), Some("Ada,36"));
+    /// ```
+    pub fn skip_lines(&mut self, n: usize) -> usize {
+        (0..n).map_while(|_| self.next_line()).count()
+    }
+
+    /// Discards whitespace at the cursor, including newlines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new(" \n\t x");
+    /// scanner.skip_whitespace();
+    /// assert_eq!(scanner.get_remaining(), "x");
+    /// ```
+    pub fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skip_lines_past_end() {
+        let mut scanner = Scanner::new("a\nb");
+        assert_eq!(scanner.skip_lines(3), 2);
+        assert_eq!(scanner.get_remaining(), "");
+        scanner.skip_whitespace();
+        assert_eq!(scanner.skip_tokens(1), 0);
+    }
+}
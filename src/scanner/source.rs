@@ -0,0 +1,385 @@
+use std::fs;
+use std::io;
+use std::ops::Range;
+use std::path::Path;
+
+use super::{Scanner, Span};
+
+/// Identifies one input registered in a [`SourceMap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SourceId(usize);
+
+impl SourceId {
+    /// Returns the index of the source in registration order.
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// A piece of a source copied into the combined text.
+#[derive(Debug, Clone)]
+struct Segment {
+    /// Offset of the segment in the combined text.
+    start: usize,
+    source: SourceId,
+    /// Offset of the segment in its source.
+    original: usize,
+}
+
+/// Several named inputs combined into a single text to be scanned as one.
+///
+/// The map keeps the text of every source, and every piece pushed into the
+/// combined text remembers which source it came from and where it was in
+/// that source. This works for plain concatenation as well as for include
+/// expansion, where the text of an included file is spliced between two
+/// pieces of the including file, and the `#include` line itself is left
+/// out. Spans produced by the
+/// [`scanner`](SourceMap::scanner) carry the id of their source and can be
+/// resolved back to a name and an original position with
+/// [`resolve_span`](SourceMap::resolve_span).
+///
+/// # Examples
+///
+/// ```
+/// use scanner::scanner::SourceMap;
+/// let mut sources = SourceMap::new();
+/// let main = sources.add_source("main.conf", "a = 1\ninclude extra.conf\nc = 3\n");
+/// let extra = sources.add_source("extra.conf", "b = 2\n");
+/// sources.push(main, 0..6);
+/// sources.push(extra, 0..6);
+/// sources.push(main, 25..31);
+///
+/// let mut scanner = sources.scanner();
+/// scanner.next_line();
+/// scanner.next_line();
+/// scanner.next_line();
+/// let resolved = sources.resolve_span(scanner.last_span().unwrap()).unwrap();
+/// assert_eq!((resolved.name, resolved.start, resolved.line), ("main.conf", 25, 3));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SourceMap {
+    text: String,
+    names: Vec<String>,
+    sources: Vec<String>,
+    segments: Vec<Segment>,
+}
+
+/// A span translated back to the source it came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResolvedSpan<'m> {
+    /// The source the span starts in.
+    pub source: SourceId,
+    /// The name of the source.
+    pub name: &'m str,
+    /// Byte offset of the start of the span in the source.
+    pub start: usize,
+    /// Byte offset of the end of the span in the source.
+    pub end: usize,
+    /// Line of the start of the span in the source, starting at 1.
+    pub line: usize,
+    /// Column of the start of the span in the source in characters, starting at 1.
+    pub column: usize,
+}
+
+impl SourceMap {
+    /// Creates an empty `SourceMap`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a new source under `name` with its whole `text` and returns
+    /// its id.
+    ///
+    /// Nothing is added to the combined text until pieces of the source are
+    /// [`push`](SourceMap::push)ed. The text is kept to resolve lines and
+    /// columns, including those of text that is never pushed.
+    pub fn add_source(&mut self, name: impl Into<String>, text: &str) -> SourceId {
+        self.names.push(name.into());
+        self.sources.push(text.to_string());
+        SourceId(self.names.len() - 1)
+    }
+
+    /// Appends the bytes `range` of `source` to the combined text.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source` was not registered in this map, or if `range` is
+    /// out of bounds or not on character boundaries of its text.
+    pub fn push(&mut self, source: SourceId, range: Range<usize>) {
+        assert!(source.0 < self.names.len(), "unknown source {source:?}");
+        let text = &self.sources[source.0][range.clone()];
+
+        if text.is_empty() {
+            return;
+        }
+
+        self.segments.push(Segment {
+            start: self.text.len(),
+            source,
+            original: range.start,
+        });
+        self.text.push_str(text);
+    }
+
+    /// Registers a new source and appends its whole text.
+    pub fn append(&mut self, name: impl Into<String>, text: &str) -> SourceId {
+        let source = self.add_source(name, text);
+        self.push(source, 0..text.len());
+        source
+    }
+
+    /// Combines several inputs to be scanned as one, like
+    /// `cat first second | scan`.
+    ///
+    /// Each input becomes a source named `input 1`, `input 2` and so on.
+    /// Inputs are not separated, so an input should end with a newline if
+    /// its last token must not run into the next input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::SourceMap;
+    /// let sources = SourceMap::chain(["1 2\n", "3\n"]);
+    /// let mut scanner = sources.scanner();
+    /// assert_eq!(scanner.next_number(), Some(1));
+    /// assert_eq!(scanner.next_number(), Some(2));
+    /// assert_eq!(scanner.next_number(), Some(3));
+    ///
+    /// let source = scanner.last_span().unwrap().source.unwrap();
+    /// assert_eq!((source.index(), sources.name(source)), (1, Some("input 2")));
+    /// ```
+    pub fn chain<'i>(inputs: impl IntoIterator<Item = &'i str>) -> Self {
+        let mut sources = SourceMap::new();
+        for (index, input) in inputs.into_iter().enumerate() {
+            sources.append(format!("input {}", index + 1), input);
+        }
+        sources
+    }
+
+    /// Reads several files to be scanned as one.
+    ///
+    /// See [`SourceMap::chain`]. Each source is named by the path of its
+    /// file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file cannot be read or is not valid UTF-8.
+    pub fn chain_files<P>(paths: impl IntoIterator<Item = P>) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let mut sources = SourceMap::new();
+        for path in paths {
+            let path = path.as_ref();
+            sources.append(path.display().to_string(), &fs::read_to_string(path)?);
+        }
+        Ok(sources)
+    }
+
+    /// Returns the name of a source.
+    pub fn name(&self, source: SourceId) -> Option<&str> {
+        self.names.get(source.0).map(String::as_str)
+    }
+
+    /// Returns the combined text.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Creates a new `Scanner` over the combined text whose spans carry
+    /// source ids.
+    pub fn scanner(&self) -> Scanner<'_> {
+        let mut scanner = Scanner::new(&self.text);
+        scanner.sources = Some(self);
+        scanner
+    }
+
+    /// Returns the source of the byte at `offset` in the combined text.
+    pub fn source_at(&self, offset: usize) -> Option<SourceId> {
+        self.segment_at(offset).map(|segment| segment.source)
+    }
+
+    /// Translates an offset in the combined text to `(source, original offset)`.
+    pub fn resolve_offset(&self, offset: usize) -> Option<(SourceId, usize)> {
+        self.segment_at(offset)
+            .map(|segment| (segment.source, segment.original + offset - segment.start))
+    }
+
+    /// Translates a span over the combined text to its source name, original
+    /// offsets, line and column.
+    ///
+    /// If the span crosses into another source, its end is clamped to the
+    /// end of the segment it starts in.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(ResolvedSpan)` if the span starts inside the combined text.
+    /// * `None` otherwise.
+    pub fn resolve_span(&self, span: Span) -> Option<ResolvedSpan<'_>> {
+        let index = self.segment_index(span.start)?;
+        let segment = &self.segments[index];
+        let segment_end = self
+            .segments
+            .get(index + 1)
+            .map_or(self.text.len(), |next| next.start);
+
+        let start = segment.original + span.start - segment.start;
+        let end = segment.original + span.end.min(segment_end).max(span.start) - segment.start;
+        let (line, column) = self.line_column(segment.source, start);
+
+        Some(ResolvedSpan {
+            source: segment.source,
+            name: &self.names[segment.source.0],
+            start,
+            end,
+            line,
+            column,
+        })
+    }
+
+    fn segment_index(&self, offset: usize) -> Option<usize> {
+        if self.segments.is_empty() || offset > self.text.len() {
+            return None;
+        }
+
+        Some(
+            self.segments
+                .partition_point(|segment| segment.start <= offset)
+                - 1,
+        )
+    }
+
+    fn segment_at(&self, offset: usize) -> Option<&Segment> {
+        self.segment_index(offset)
+            .map(|index| &self.segments[index])
+    }
+
+    /// Computes the line and column of `offset` in the text of `source`.
+    fn line_column(&self, source: SourceId, offset: usize) -> (usize, usize) {
+        let before = &self.sources[source.0][..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        (
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+        )
+    }
+}
+
+impl Scanner<'_> {
+    /// Combines several inputs to be scanned as one, like
+    /// `cat first second | scan`.
+    ///
+    /// A scanner borrows its input, so this returns the [`SourceMap`] that
+    /// owns the combined text. Its [`SourceMap::scanner`] tags the span of
+    /// every token with the input it came from. See [`SourceMap::chain`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let sources = Scanner::chain(["a b\n", "c\n"]);
+    /// let mut scanner = sources.scanner();
+    /// scanner.next_word();
+    /// scanner.next_word();
+    /// assert_eq!(scanner.next_word(), Some("c"));
+    ///
+    /// let resolved = sources.resolve_span(scanner.last_span().unwrap()).unwrap();
+    /// assert_eq!((resolved.name, resolved.line), ("input 2", 1));
+    /// ```
+    pub fn chain<'i>(inputs: impl IntoIterator<Item = &'i str>) -> SourceMap {
+        SourceMap::chain(inputs)
+    }
+
+    /// Reads several files to be scanned as one.
+    ///
+    /// See [`Scanner::chain`] and [`SourceMap::chain_files`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file cannot be read or is not valid UTF-8.
+    pub fn chain_files<P>(paths: impl IntoIterator<Item = P>) -> io::Result<SourceMap>
+    where
+        P: AsRef<Path>,
+    {
+        SourceMap::chain_files(paths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_append_and_resolve() {
+        let mut sources = SourceMap::new();
+        let first = sources.append("first.txt", "1 2\n");
+        let second = sources.append("second.txt", "3\n4 five\n");
+
+        let mut scanner = sources.scanner();
+        assert_eq!(scanner.next_number(), Some(1));
+        assert_eq!(scanner.last_span().unwrap().source, Some(first));
+        assert_eq!(scanner.next_number(), Some(2));
+        assert_eq!(scanner.next_number(), Some(3));
+        assert_eq!(scanner.last_span().unwrap().source, Some(second));
+        assert_eq!(scanner.next_number(), Some(4));
+        assert_eq!(scanner.next_word(), Some("five"));
+
+        let resolved = sources.resolve_span(scanner.last_span().unwrap()).unwrap();
+        assert_eq!(resolved.name, "second.txt");
+        assert_eq!((resolved.start, resolved.end), (4, 8));
+        assert_eq!((resolved.line, resolved.column), (2, 3));
+    }
+
+    #[test]
+    fn test_include_expansion() {
+        let mut sources = SourceMap::new();
+        let main = sources.add_source("main", "a\n#include \"hdr.h\"\nb\n");
+        let header = sources.add_source("hdr.h", "h1\nh2\n");
+        sources.push(main, 0..2);
+        sources.push(header, 0..6);
+        sources.push(main, 19..21);
+
+        assert_eq!(sources.as_str(), "a\nh1\nh2\nb\n");
+        assert_eq!(sources.resolve_offset(5), Some((header, 3)));
+        assert_eq!(sources.resolve_offset(8), Some((main, 19)));
+
+        let resolved = sources.resolve_span(Span::new(8, 9)).unwrap();
+        assert_eq!(
+            (resolved.name, resolved.line, resolved.column),
+            ("main", 3, 1)
+        );
+    }
+
+    #[test]
+    fn test_chain_files() {
+        let dir = std::env::temp_dir();
+        let path = |name| dir.join(format!("scanner-{}-chain-{name}.txt", std::process::id()));
+        let (first, second) = (path("first"), path("second"));
+        fs::write(&first, "a\n").unwrap();
+        fs::write(&second, "b c\n").unwrap();
+
+        let sources = Scanner::chain_files([&first, &second]).unwrap();
+        let mut scanner = sources.scanner();
+        scanner.next_word();
+        scanner.next_word();
+        assert_eq!(scanner.next_word(), Some("c"));
+        let resolved = sources.resolve_span(scanner.last_span().unwrap()).unwrap();
+        assert_eq!(resolved.name, second.display().to_string());
+        assert_eq!(resolved.start, 2);
+
+        let missing = path("missing");
+        assert!(SourceMap::chain_files([&first, &missing]).is_err());
+        fs::remove_file(first).unwrap();
+        fs::remove_file(second).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_out_of_bounds() {
+        let mut sources = SourceMap::new();
+        assert_eq!(sources.resolve_span(Span::new(0, 0)), None);
+        sources.append("a", "abc");
+        assert_eq!(sources.resolve_span(Span::new(4, 4)), None);
+        assert_eq!(sources.name(SourceId(0)), Some("a"));
+    }
+}
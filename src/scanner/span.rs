@@ -0,0 +1,74 @@
+use std::ops::Range;
+
+use super::{Scanner, SourceId};
+
+/// A byte range of the input covered by a token.
+///
+/// Spans produced by a scanner created from a [`SourceMap`](super::SourceMap)
+/// also carry the id of the source the token starts in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// Byte offset of the first byte of the token.
+    pub start: usize,
+    /// Byte offset one past the last byte of the token.
+    pub end: usize,
+    /// The source the token starts in, if known.
+    pub source: Option<SourceId>,
+}
+
+impl Span {
+    /// Creates a new `Span` covering `start..end`, without a source.
+    pub fn new(start: usize, end: usize) -> Self {
+        Span {
+            start,
+            end,
+            source: None,
+        }
+    }
+
+    /// Returns the length of the span in bytes.
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns `true` if the span covers no bytes.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns the span as a byte range, suitable for slicing the input.
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Returns the span of the last token, line or paragraph consumed.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Span)` of the last consumed token.
+    /// * `None` if nothing has been consumed yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{Scanner, Span};
+    /// let mut scanner = Scanner::new("let  x");
+    /// scanner.next_word();
+    /// scanner.next_word();
+    /// assert_eq!(scanner.last_span(), Some(Span::new(5, 6)));
+    /// ```
+    pub fn last_span(&self) -> Option<Span> {
+        self.last.map(|(start, end)| self.span(start, end))
+    }
+
+    /// Builds a span over `start..end`, attaching the source it starts in.
+    pub(super) fn span(&self, start: usize, end: usize) -> Span {
+        Span {
+            start,
+            end,
+            source: self.sources.and_then(|sources| sources.source_at(start)),
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use super::Scanner;
+
+/// Counts of what a [`Scanner`] consumed, collected once enabled with
+/// [`Scanner::with_stats`].
+///
+/// Every consumed token is counted once, as a number if it parses as one,
+/// as a word if it has no whitespace, or as another token, such as a line,
+/// a paragraph or a record, otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Stats {
+    /// Tokens, lines, paragraphs and records consumed.
+    pub tokens: usize,
+    /// Consumed tokens that are numbers.
+    pub numbers: usize,
+    /// Consumed tokens that are words other than numbers.
+    pub words: usize,
+    /// Consumed tokens that contain whitespace or are empty.
+    pub other: usize,
+    /// Bytes the cursor moved over, including skipped whitespace.
+    pub bytes: usize,
+    /// Line feeds the cursor moved over.
+    pub lines: usize,
+    /// Scans that read a token but failed to parse it, and rewound.
+    pub failures: usize,
+}
+
+impl Stats {
+    /// Records the consumption of `input[start..end]`, moving the cursor
+    /// from `from` to `to`.
+    pub(super) fn record(&mut self, input: &str, start: usize, end: usize, from: usize, to: usize) {
+        let token = input.get(start..end).unwrap_or_default();
+        let skipped = input.get(from..to).unwrap_or_default();
+
+        self.tokens += 1;
+        match TokenClass::of(token) {
+            TokenClass::Number => self.numbers += 1,
+            TokenClass::Word => self.words += 1,
+            TokenClass::Other => self.other += 1,
+        }
+        self.bytes += skipped.len();
+        self.lines += skipped.bytes().filter(|&b| b == b'\n').count();
+    }
+
+    /// Returns the statistics as they were before a failed scan, with the
+    /// failure counted.
+    pub(super) fn failed(self) -> Self {
+        Stats {
+            failures: self.failures + 1,
+            ..self
+        }
+    }
+}
+
+/// The class of a consumed token, as counted by [`Stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum TokenClass {
+    Number,
+    Word,
+    Other,
+}
+
+impl TokenClass {
+    pub(super) fn of(token: &str) -> Self {
+        if token.is_empty() || token.contains(char::is_whitespace) {
+            TokenClass::Other
+        } else if is_float(token) {
+            TokenClass::Number
+        } else {
+            TokenClass::Word
+        }
+    }
+}
+
+/// Returns `true` if `token` parses as an `f64` once its underscores are
+/// removed, without allocating.
+fn is_float(token: &str) -> bool {
+    let mut bytes = token.bytes().filter(|&b| b != b'_').peekable();
+    bytes.next_if(|&b| b == b'+' || b == b'-');
+
+    let lowercase = bytes.clone().map(|b| b.to_ascii_lowercase());
+    if ["inf", "infinity", "nan"]
+        .iter()
+        .any(|word| lowercase.clone().eq(word.bytes()))
+    {
+        return true;
+    }
+
+    let mut digits = 0;
+    while bytes.next_if(u8::is_ascii_digit).is_some() {
+        digits += 1;
+    }
+    if bytes.next_if_eq(&b'.').is_some() {
+        while bytes.next_if(u8::is_ascii_digit).is_some() {
+            digits += 1;
+        }
+    }
+    if digits == 0 {
+        return false;
+    }
+
+    if bytes.next_if(|&b| b == b'e' || b == b'E').is_some() {
+        bytes.next_if(|&b| b == b'+' || b == b'-');
+        if bytes.next_if(u8::is_ascii_digit).is_none() {
+            return false;
+        }
+        while bytes.next_if(u8::is_ascii_digit).is_some() {}
+    }
+    bytes.next().is_none()
+}
+
+impl<'a> Scanner<'a> {
+    /// Starts collecting [`Stats`] on what the scanner consumes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("x 1 99999999999 2.5\nnext line\n").with_stats();
+    /// scanner.next_word();
+    /// scanner.next_number::<i32>();
+    /// assert_eq!(scanner.next_number::<i32>(), None);
+    /// scanner.next_word();
+    /// scanner.next_float();
+    /// scanner.next_line();
+    /// scanner.next_line();
+    ///
+    /// let stats = scanner.stats().unwrap();
+    /// assert_eq!((stats.words, stats.numbers, stats.other), (1, 3, 2));
+    /// assert_eq!((stats.bytes, stats.lines, stats.failures), (30, 2, 1));
+    /// ```
+    pub fn with_stats(mut self) -> Self {
+        self.stats = Some(Stats::default());
+        self
+    }
+
+    /// Returns the statistics collected so far, or `None` if collection was
+    /// not enabled with [`Scanner::with_stats`].
+    pub fn stats(&self) -> Option<Stats> {
+        self.stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_failure_counts_once() {
+        let mut scanner = Scanner::new("2 1\n0 x\n").with_stats();
+        assert_eq!(
+            scanner.next_graph(crate::scanner::GraphFormat::ZERO_BASED),
+            None
+        );
+        assert_eq!(
+            scanner.stats(),
+            Some(Stats {
+                failures: 1,
+                ..Stats::default()
+            })
+        );
+        assert_eq!(Scanner::new("a").stats(), None);
+    }
+
+    #[test]
+    fn test_token_class_matches_float_parsing() {
+        for token in [
+            "1",
+            "-1_000",
+            "+.5",
+            "5.",
+            "1e9",
+            "2.5E-3",
+            "1_e_5",
+            "_",
+            ".",
+            "-",
+            "e5",
+            "1e",
+            "1e+",
+            "inf",
+            "-Infinity",
+            "NaN",
+            "nan1",
+            "0x10",
+            "1.2.3",
+            "١",
+        ] {
+            let expected = token.replace('_', "").parse::<f64>().is_ok();
+            assert_eq!(is_float(token), expected, "{token}");
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+
+use super::{ScanError, Scanner, Token};
+
+/// A [`Stream`] over the tokens of a [`Scanner`], for async pipelines.
+///
+/// Yields the tokens of [`Scanner::next_any`] and ends at the end of the
+/// input, without a [`Token::Eof`]. The input is already in memory, so every
+/// poll is ready immediately.
+///
+/// Created by [`Scanner::token_stream`].
+pub struct TokenStream<'s, 'a> {
+    scanner: &'s mut Scanner<'a>,
+    finished: bool,
+}
+
+impl<'a> Stream for TokenStream<'_, 'a> {
+    type Item = Result<Token<'a>, ScanError>;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let stream = self.get_mut();
+        if stream.finished {
+            return Poll::Ready(None);
+        }
+
+        match stream.scanner.try_next_any() {
+            Ok(Token::Eof) => {
+                stream.finished = true;
+                Poll::Ready(None)
+            }
+            Ok(token) => Poll::Ready(Some(Ok(token))),
+            Err(error) => {
+                stream.finished = true;
+                Poll::Ready(Some(Err(error)))
+            }
+        }
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Returns a [`Stream`] over the remaining tokens.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::StreamExt;
+    /// use scanner::scanner::{Scanner, Token};
+    ///
+    /// futures::executor::block_on(async {
+    ///     let mut scanner = Scanner::new("x = 1");
+    ///     let mut stream = scanner.token_stream();
+    ///     while let Some(token) = stream.next().await {
+    ///         if let Token::Int(value) = token.unwrap() {
+    ///             assert_eq!(value, 1);
+    ///         }
+    ///     }
+    /// });
+    /// ```
+    pub fn token_stream(&mut self) -> TokenStream<'_, 'a> {
+        TokenStream {
+            scanner: self,
+            finished: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on_stream;
+
+    use super::*;
+    use crate::scanner::ScanErrorKind;
+
+    #[test]
+    fn test_budget_error_ends_stream() {
+        let mut scanner = Scanner::new("a b c").with_budget(2, usize::MAX);
+        let tokens: Vec<_> = block_on_stream(scanner.token_stream()).collect();
+        assert_eq!(tokens.len(), 3);
+        assert_eq!(tokens[1], Ok(Token::Word("b")));
+        assert_eq!(
+            tokens[2].as_ref().unwrap_err().kind(),
+            &ScanErrorKind::BudgetExceeded
+        );
+    }
+}
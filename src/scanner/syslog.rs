@@ -0,0 +1,260 @@
+use super::{ScanError, ScanErrorKind, Scanner};
+
+/// A syslog message in the format of RFC 5424 or the older BSD format of
+/// RFC 3164.
+///
+/// Fields missing from the message, or logged as the RFC 5424 nil value
+/// `-`, are `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyslogRecord<'a> {
+    /// The priority value, `facility * 8 + severity`.
+    pub priority: Option<u8>,
+    /// The timestamp, as written.
+    pub timestamp: Option<&'a str>,
+    /// The host that sent the message.
+    pub hostname: Option<&'a str>,
+    /// The application name, or the tag of an RFC 3164 message.
+    pub app: Option<&'a str>,
+    /// The process ID.
+    pub proc_id: Option<&'a str>,
+    /// The message type, in RFC 5424.
+    pub msg_id: Option<&'a str>,
+    /// The structured data elements, as written, in RFC 5424.
+    pub structured_data: Option<&'a str>,
+    /// The free-form message.
+    pub message: &'a str,
+}
+
+/// Splits the next space-delimited field off `text`, mapping `-` to `None`.
+fn field(text: &str) -> Option<(Option<&str>, &str)> {
+    let end = text.find(' ').unwrap_or(text.len());
+    let value = &text[..end];
+    let rest = text[end..].strip_prefix(' ').unwrap_or("");
+    (!value.is_empty()).then_some(((value != "-").then_some(value), rest))
+}
+
+/// Returns the length of the RFC 5424 structured data at the start of
+/// `text`: `-` or one or more bracketed elements.
+fn structured_data_len(text: &str) -> Option<usize> {
+    if text.starts_with('-') {
+        return Some(1);
+    }
+
+    let mut len = 0;
+    while text[len..].starts_with('[') {
+        let mut escaped = false;
+        let close = text[len..].char_indices().find(|&(_, c)| {
+            let close = c == ']' && !escaped;
+            escaped = c == '\\' && !escaped;
+            close
+        })?;
+        len += close.0 + 1;
+    }
+
+    (len > 0).then_some(len)
+}
+
+/// Returns `true` if `text` starts with an RFC 3164 timestamp such as
+/// `Oct  1 22:14:15`.
+fn is_bsd_timestamp(text: &str) -> bool {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let Some(timestamp) = text.get(..15) else {
+        return false;
+    };
+    let bytes = timestamp.as_bytes();
+    let digit = |i: usize| bytes[i].is_ascii_digit();
+
+    MONTHS.contains(&&timestamp[..3])
+        && bytes[3] == b' '
+        && (bytes[4] == b' ' || digit(4))
+        && digit(5)
+        && bytes[6] == b' '
+        && [7, 8, 10, 11, 13, 14].into_iter().all(digit)
+        && bytes[9] == b':'
+        && bytes[12] == b':'
+}
+
+impl<'a> SyslogRecord<'a> {
+    /// Parses a whole syslog line.
+    fn parse(line: &'a str) -> Option<SyslogRecord<'a>> {
+        let (priority, rest) = match line.strip_prefix('<') {
+            Some(rest) => {
+                let (priority, rest) = rest.split_once('>')?;
+                let priority = Some(priority)
+                    .filter(|p| {
+                        (1..=3).contains(&p.len()) && p.bytes().all(|b| b.is_ascii_digit())
+                    })?
+                    .parse()
+                    .ok()
+                    .filter(|&p| p <= 191)?;
+                (Some(priority), rest)
+            }
+            None => (None, line),
+        };
+
+        match rest.strip_prefix("1 ") {
+            Some(rest) => Self::parse_rfc5424(priority?, rest),
+            None => Self::parse_rfc3164(priority, rest),
+        }
+    }
+
+    /// Parses what follows the priority and version of an RFC 5424 message.
+    fn parse_rfc5424(priority: u8, rest: &'a str) -> Option<SyslogRecord<'a>> {
+        let (timestamp, rest) = field(rest)?;
+        let (hostname, rest) = field(rest)?;
+        let (app, rest) = field(rest)?;
+        let (proc_id, rest) = field(rest)?;
+        let (msg_id, rest) = field(rest)?;
+
+        let len = structured_data_len(rest)?;
+        let structured_data = (&rest[..len] != "-").then_some(&rest[..len]);
+        let message = match &rest[len..] {
+            "" => "",
+            message => message.strip_prefix(' ')?,
+        };
+
+        Some(SyslogRecord {
+            priority: Some(priority),
+            timestamp,
+            hostname,
+            app,
+            proc_id,
+            msg_id,
+            structured_data,
+            message: message.strip_prefix('\u{feff}').unwrap_or(message),
+        })
+    }
+
+    /// Parses what follows the priority of an RFC 3164 message.
+    fn parse_rfc3164(priority: Option<u8>, rest: &'a str) -> Option<SyslogRecord<'a>> {
+        if !is_bsd_timestamp(rest) {
+            return None;
+        }
+        let (timestamp, rest) = (&rest[..15], rest[15..].strip_prefix(' ')?);
+        let (hostname, rest) = field(rest)?;
+
+        let tag_len = rest.find([':', '[', ' ']).unwrap_or(rest.len());
+        let (app, mut rest) = (&rest[..tag_len], &rest[tag_len..]);
+        let mut proc_id = None;
+        if let Some((pid, after)) = rest.strip_prefix('[').and_then(|r| r.split_once(']')) {
+            proc_id = Some(pid);
+            rest = after;
+        }
+        let rest = rest.strip_prefix(':').unwrap_or(rest);
+
+        Some(SyslogRecord {
+            priority,
+            timestamp: Some(timestamp),
+            hostname,
+            app: (!app.is_empty()).then_some(app),
+            proc_id,
+            msg_id: None,
+            structured_data: None,
+            message: rest.strip_prefix(' ').unwrap_or(rest),
+        })
+    }
+
+    /// Returns the facility of the message, such as 4 for `auth`.
+    pub fn facility(&self) -> Option<u8> {
+        self.priority.map(|priority| priority / 8)
+    }
+
+    /// Returns the severity of the message, from 0 for emergencies to 7 for
+    /// debug messages.
+    pub fn severity(&self) -> Option<u8> {
+        self.priority.map(|priority| priority % 8)
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Scans the next line as a syslog message, in the format of RFC 5424
+    /// or of RFC 3164.
+    ///
+    /// An RFC 5424 message is recognized by the version `1` after its
+    /// priority. Otherwise, the line must follow RFC 3164, with an optional
+    /// priority, a timestamp such as `Oct 11 22:14:15`, the hostname and an
+    /// optional `tag[pid]:` before the message.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(SyslogRecord)` for a valid message.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new(concat!(
+    ///     "<34>Oct 11 22:14:15 mymachine su[42]: 'su root' failed\n",
+    ///     "<165>1 2003-10-11T22:14:15.003Z host evntslog - ID47 [exampleSDID@32473 iut=\"3\"] hi\n",
+    /// ));
+    /// let bsd = scanner.next_syslog().unwrap();
+    /// assert_eq!((bsd.facility(), bsd.severity()), (Some(4), Some(2)));
+    /// assert_eq!((bsd.app, bsd.proc_id), (Some("su"), Some("42")));
+    /// assert_eq!(bsd.message, "'su root' failed");
+    ///
+    /// let record = scanner.next_syslog().unwrap();
+    /// assert_eq!(record.timestamp, Some("2003-10-11T22:14:15.003Z"));
+    /// assert_eq!((record.proc_id, record.msg_id), (None, Some("ID47")));
+    /// assert_eq!(record.structured_data, Some("[exampleSDID@32473 iut=\"3\"]"));
+    /// assert_eq!(record.message, "hi");
+    /// ```
+    pub fn next_syslog(&mut self) -> Option<SyslogRecord<'a>> {
+        self.try_next_syslog().ok()
+    }
+
+    /// Scans the next line as a syslog message.
+    ///
+    /// See [`Scanner::next_syslog`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if no input remains.
+    /// * [`ScanErrorKind::InvalidFormat`] if the next line is not a valid
+    ///   message.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_syslog(&mut self) -> Result<SyslogRecord<'a>, ScanError> {
+        let checkpoint = self.checkpoint();
+        let start = self.position;
+        let line = self.try_next_line()?;
+
+        SyslogRecord::parse(line).ok_or_else(|| {
+            self.restore(checkpoint);
+            let format = "syslog message";
+            ScanError::new(ScanErrorKind::InvalidFormat { format }, start)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_syslog_variants() {
+        let record = SyslogRecord::parse("Feb  5 01:02:03 host kernel: boot").unwrap();
+        assert_eq!(record.priority, None);
+        assert_eq!(
+            (record.hostname, record.app),
+            (Some("host"), Some("kernel"))
+        );
+        assert_eq!(record.message, "boot");
+
+        let record = SyslogRecord::parse("<14>1 - - - - - [a x=\"\\]\\\"\"][b]").unwrap();
+        assert_eq!((record.timestamp, record.hostname), (None, None));
+        assert_eq!(record.structured_data, Some("[a x=\"\\]\\\"\"][b]"));
+        assert_eq!(record.message, "");
+
+        for invalid in [
+            "<192>Oct 11 22:14:15 h m",
+            "<1>Oct 1 22:14:15 h m",
+            "1 - - - - - -",
+            "<5>1 - -",
+        ] {
+            assert_eq!(SyslogRecord::parse(invalid), None, "{invalid}");
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use super::Scanner;
+
+/// A block of lines whose columns are aligned with whitespace.
+///
+/// Created by [`Scanner::detect_table`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table<'a> {
+    lines: Vec<&'a str>,
+    /// Character offset at which every column starts.
+    columns: Vec<usize>,
+}
+
+impl<'a> Table<'a> {
+    /// Returns the character offset at which every column starts.
+    pub fn column_starts(&self) -> &[usize] {
+        &self.columns
+    }
+
+    /// Returns the number of rows, including a header row if there is one.
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns `true` if the table has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Returns an iterator over the rows of the table, each row being the
+    /// trimmed cells of one line.
+    ///
+    /// The last column extends to the end of the line, so a trailing free-text
+    /// column such as a command line is kept whole.
+    pub fn rows(&self) -> impl Iterator<Item = Vec<&'a str>> + '_ {
+        self.lines.iter().map(|line| self.cells(line))
+    }
+
+    fn cells(&self, line: &'a str) -> Vec<&'a str> {
+        let byte_offset = |column: usize| {
+            line.char_indices()
+                .nth(column)
+                .map_or(line.len(), |(offset, _)| offset)
+        };
+
+        let mut bounds: Vec<usize> = self.columns.iter().map(|&c| byte_offset(c)).collect();
+        bounds.push(line.len());
+
+        bounds
+            .windows(2)
+            .map(|bound| line[bound[0]..bound[1]].trim())
+            .collect()
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Scans the next block of non-blank lines as a whitespace-aligned table.
+    ///
+    /// Column boundaries are inferred from the character positions that are
+    /// whitespace in every line of the block, so cells may themselves contain
+    /// spaces as long as another line fills that position. This handles the
+    /// output of tools like `ps aux` or `df -h`, which delimiter-based
+    /// splitting cannot.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Table)` over the consumed block.
+    /// * `None` if only blank lines remain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new(
+    ///     "NAME       SIZE  MOUNTED ON\n\
+    ///      /dev/sda1  20G   /\n\
+    ///      tmpfs      1.5G  /run/user 1000\n",
+    /// );
+    /// let table = scanner.detect_table().unwrap();
+    /// let rows: Vec<_> = table.rows().collect();
+    /// assert_eq!(rows[0], ["NAME", "SIZE", "MOUNTED ON"]);
+    /// assert_eq!(rows[2], ["tmpfs", "1.5G", "/run/user 1000"]);
+    /// ```
+    pub fn detect_table(&mut self) -> Option<Table<'a>> {
+        let paragraph = self.next_paragraph()?;
+        let lines: Vec<&'a str> = self
+            .newline_policy
+            .split(paragraph)
+            .map(|line| line.strip_suffix('\r').unwrap_or(line))
+            .collect();
+
+        let width = lines.iter().map(|line| line.chars().count()).max()?;
+        let mut occupied = vec![false; width];
+        for line in &lines {
+            for (i, c) in line.chars().enumerate() {
+                occupied[i] |= !c.is_whitespace();
+            }
+        }
+
+        let columns = (0..width)
+            .filter(|&i| occupied[i] && (i == 0 || !occupied[i - 1]))
+            .collect();
+
+        Some(Table { lines, columns })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_table_ps() {
+        let mut scanner = Scanner::new(
+            "USER       PID %CPU COMMAND\n\
+             root         1  0.0 /sbin/init splash\n\
+             alice      942 12.5 vim notes.txt\n\
+             \n\
+             trailing text",
+        );
+
+        let table = scanner.detect_table().unwrap();
+        assert_eq!(table.len(), 3);
+        assert_eq!(table.column_starts(), [0, 11, 15, 20]);
+
+        let rows: Vec<_> = table.rows().collect();
+        assert_eq!(rows[0], ["USER", "PID", "%CPU", "COMMAND"]);
+        assert_eq!(rows[1], ["root", "1", "0.0", "/sbin/init splash"]);
+        assert_eq!(rows[2], ["alice", "942", "12.5", "vim notes.txt"]);
+
+        assert_eq!(scanner.get_remaining(), "trailing text");
+    }
+
+    #[test]
+    fn test_detect_table_short_rows() {
+        let mut scanner = Scanner::new("a  b  c\nx  y\n");
+        let table = scanner.detect_table().unwrap();
+        let rows: Vec<_> = table.rows().collect();
+        assert_eq!(rows[1], ["x", "y", ""]);
+        assert!(scanner.detect_table().is_none());
+    }
+}
@@ -0,0 +1,249 @@
+use super::Scanner;
+
+impl<'a> Scanner<'a> {
+    /// Consumes characters at the cursor for as long as `predicate` holds.
+    ///
+    /// Unlike [`Scanner::next_token`], leading whitespace is not skipped.
+    ///
+    /// # Returns
+    ///
+    /// The consumed slice, which is empty if the first character does not
+    /// satisfy `predicate`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("abc123");
+    /// assert_eq!(scanner.take_while(|c| c.is_alphabetic()), "abc");
+    /// assert_eq!(scanner.take_while(|c| c.is_alphabetic()), "");
+    /// assert_eq!(scanner.get_remaining(), "123");
+    /// ```
+    pub fn take_while<F>(&mut self, mut predicate: F) -> &'a str
+    where
+        F: FnMut(char) -> bool,
+    {
+        let remaining = self.get_remaining();
+        let len = remaining
+            .char_indices()
+            .find(|&(_, c)| !predicate(c))
+            .map_or(remaining.len(), |(i, _)| i);
+
+        self.take(len)
+    }
+
+    /// Consumes everything up to, but not including, the next occurrence of
+    /// `pattern`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` with the consumed slice.
+    /// * `None` if `pattern` does not occur in the remaining input or the
+    ///   budget is used up, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("key: value");
+    /// assert_eq!(scanner.take_until(": "), Some("key"));
+    /// assert_eq!(scanner.get_remaining(), ": value");
+    /// ```
+    pub fn take_until(&mut self, pattern: &str) -> Option<&'a str> {
+        let len = self.get_remaining().find(pattern)?;
+        let taken = self.take(len);
+        (len == 0 || !taken.is_empty()).then_some(taken)
+    }
+
+    /// Discards everything up to, but not including, the next occurrence of
+    /// `pattern`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `pattern` was found, `false` if it was not or the budget is
+    /// used up, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("noise noise <data>");
+    /// assert!(scanner.skip_until("<"));
+    /// assert_eq!(scanner.get_remaining(), "<data>");
+    /// assert!(!scanner.skip_until("#"));
+    /// ```
+    pub fn skip_until(&mut self, pattern: &str) -> bool {
+        self.take_until(pattern).is_some()
+    }
+
+    /// Consumes the rest of the current line, including the line
+    /// terminator.
+    ///
+    /// Unlike [`Scanner::next_line`], whitespace is trimmed from both ends
+    /// of the result, which makes it suited to reading a free-text field
+    /// after the tokens at the start of a line.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` with the trimmed text, which is empty if nothing but
+    ///   whitespace is left on the line.
+    /// * `None` at the end of the input or if the budget is used up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("3 bags of flour \n2 eggs");
+    /// assert_eq!(scanner.next_number(), Some(3));
+    /// assert_eq!(scanner.rest_of_line(), Some("bags of flour"));
+    /// assert_eq!(scanner.next_number(), Some(2));
+    /// assert_eq!(scanner.rest_of_line(), Some("eggs"));
+    /// assert_eq!(scanner.rest_of_line(), None);
+    /// ```
+    pub fn rest_of_line(&mut self) -> Option<&'a str> {
+        let remaining = self.get_remaining();
+        if remaining.is_empty() {
+            return None;
+        }
+
+        let (line, consumed) = match self.newline_policy.find(remaining) {
+            Some((newline_pos, len)) => (&remaining[..newline_pos], newline_pos + len),
+            None => (remaining, remaining.len()),
+        };
+
+        let text = line.trim();
+        let start = self.position + line.len() - line.trim_start().len();
+        let end = start + text.len();
+        self.consume(start, end, self.position + consumed).ok()?;
+        Some(text)
+    }
+
+    /// Consumes `prefix` if the remaining input starts with it exactly.
+    ///
+    /// Unlike [`Scanner::expect`], leading whitespace is not skipped.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `prefix` was consumed, `false` otherwise, in which case
+    /// nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("Card 1: 41 48");
+    /// assert!(scanner.consume_prefix("Card "));
+    /// assert_eq!(scanner.next_number(), Some(1));
+    /// assert!(!scanner.consume_prefix(" "));
+    /// assert!(scanner.consume_prefix(":"));
+    /// ```
+    pub fn consume_prefix(&mut self, prefix: &str) -> bool {
+        self.get_remaining().starts_with(prefix) && self.take_prefix(prefix.len())
+    }
+
+    /// Consumes `prefix` if the remaining input starts with it, ignoring
+    /// case.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `prefix` was consumed, `false` otherwise, in which case
+    /// nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("SELECT name");
+    /// assert!(scanner.consume_prefix_ci("select"));
+    /// assert_eq!(scanner.get_remaining(), " name");
+    /// ```
+    pub fn consume_prefix_ci(&mut self, prefix: &str) -> bool {
+        let mut remaining = self.get_remaining().chars();
+        let mut len = 0;
+        for expected in prefix.chars() {
+            match remaining.next() {
+                Some(c) if c.to_lowercase().eq(expected.to_lowercase()) => len += c.len_utf8(),
+                _ => return false,
+            }
+        }
+
+        self.take_prefix(len)
+    }
+
+    /// Consumes a matched prefix of `len` bytes, which may be empty.
+    fn take_prefix(&mut self, len: usize) -> bool {
+        len == 0 || !self.take(len).is_empty()
+    }
+
+    /// Consumes the next `len` bytes as a token. Consuming nothing is not
+    /// recorded as a token.
+    pub(super) fn take(&mut self, len: usize) -> &'a str {
+        if len == 0 {
+            return "";
+        }
+
+        let start = self.position;
+        match self.consume(start, start + len, start + len) {
+            Ok(()) => &self.input[start..start + len],
+            Err(_) => "",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::NewlinePolicy;
+
+    #[test]
+    fn test_take_primitives() {
+        let mut scanner = Scanner::new("GET /index.html HTTP/1.1\r\nHost: x");
+        assert_eq!(scanner.take_while(|c| c.is_ascii_uppercase()), "GET");
+        assert_eq!(scanner.take_while(char::is_whitespace), " ");
+        assert_eq!(scanner.take_until(" "), Some("/index.html"));
+        assert!(scanner.skip_until("\r\n"));
+        assert_eq!(scanner.get_remaining(), "\r\nHost: x");
+        assert_eq!(scanner.take_until("missing"), None);
+        assert_eq!(scanner.get_remaining(), "\r\nHost: x");
+    }
+
+    #[test]
+    fn test_take_until_respects_budget() {
+        let mut scanner = Scanner::new("a b").with_budget(0, usize::MAX);
+        assert!(!scanner.skip_until("b"));
+        assert_eq!(scanner.take_until(" "), None);
+        assert_eq!(scanner.take_until("a"), Some(""));
+        assert_eq!(scanner.get_remaining(), "a b");
+    }
+
+    #[test]
+    fn test_take_while_to_end() {
+        let mut scanner = Scanner::new("ééé");
+        assert_eq!(scanner.take_while(|c| c == 'é'), "ééé");
+        assert_eq!(scanner.get_remaining(), "");
+        assert_eq!(scanner.last_span().unwrap().range(), 0..6);
+    }
+
+    #[test]
+    fn test_rest_of_line_loop() {
+        let mut scanner =
+            Scanner::new("id 7 \r\n\n  next\r").with_newline_policy(NewlinePolicy::Universal);
+        assert_eq!(scanner.next_word(), Some("id"));
+        assert_eq!(scanner.rest_of_line(), Some("7"));
+        assert_eq!(scanner.last_span().unwrap().range(), 3..4);
+
+        let mut rests = Vec::new();
+        while let Some(rest) = scanner.rest_of_line() {
+            rests.push(rest);
+        }
+        assert_eq!(rests, ["", "next"]);
+    }
+
+    #[test]
+    fn test_consume_prefix_ci_non_ascii() {
+        let mut scanner = Scanner::new("ÄRGER x");
+        assert!(!scanner.consume_prefix_ci("ärgerlich"));
+        assert!(scanner.consume_prefix_ci("ärger"));
+        assert_eq!(scanner.get_remaining(), " x");
+    }
+}
@@ -0,0 +1,400 @@
+use super::{NewlinePolicy, ScanError, Scanner, SignPolicy, Spanned};
+
+/// The kind of a token recognized by the generic lexer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    /// A run of alphanumeric characters and underscores that is not a number.
+    Word,
+    /// An integer, with an optional leading `-`.
+    Int,
+    /// A number with a fractional part or an exponent.
+    Float,
+    /// A single character that is neither whitespace nor part of a word or number.
+    Punct,
+    /// A line terminator of the scanner's [`NewlinePolicy`].
+    Newline,
+    /// The end of the input.
+    Eof,
+}
+
+/// A token classified by [`Scanner::next_any`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Token<'a> {
+    /// A run of alphanumeric characters and underscores that is not a number.
+    Word(&'a str),
+    /// An integer that fits in an `i64`.
+    Int(i64),
+    /// A number with a fractional part or an exponent, or an integer too
+    /// large for an `i64`.
+    Float(f64),
+    /// Any other single non-whitespace character.
+    Punct(char),
+    /// A line terminator of the scanner's [`NewlinePolicy`].
+    Newline,
+    /// The end of the input.
+    Eof,
+}
+
+impl Token<'_> {
+    /// Returns the kind of the token.
+    pub fn kind(&self) -> TokenKind {
+        match self {
+            Token::Word(_) => TokenKind::Word,
+            Token::Int(_) => TokenKind::Int,
+            Token::Float(_) => TokenKind::Float,
+            Token::Punct(_) => TokenKind::Punct,
+            Token::Newline => TokenKind::Newline,
+            Token::Eof => TokenKind::Eof,
+        }
+    }
+}
+
+/// An iterator over the tokens of a [`Scanner`], ending with a single
+/// [`Token::Eof`].
+///
+/// Created by [`Scanner::tokens`].
+pub struct Tokens<'s, 'a> {
+    scanner: &'s mut Scanner<'a>,
+    newlines: bool,
+    finished: bool,
+}
+
+impl Tokens<'_, '_> {
+    /// Skips [`Token::Newline`] tokens instead of yielding them.
+    pub fn without_newlines(mut self) -> Self {
+        self.newlines = false;
+        self
+    }
+}
+
+impl<'a> Iterator for Tokens<'_, 'a> {
+    type Item = Spanned<Token<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        loop {
+            let token = self.scanner.next_spanned();
+            match token.value {
+                Token::Newline if !self.newlines => continue,
+                Token::Eof => self.finished = true,
+                _ => {}
+            }
+            return Some(token);
+        }
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// Scans the next token, whatever it is, and classifies it.
+    ///
+    /// Whitespace other than line terminators is skipped. Words are runs of
+    /// alphanumeric characters and underscores, numbers follow the usual
+    /// decimal notation with an optional fraction and exponent, and a `-`
+    /// in front of digits is a sign according to the [`SignPolicy`]. Any
+    /// other character is returned on its own as [`Token::Punct`]. This
+    /// makes the scanner usable as a generic lexer for small languages.
+    ///
+    /// # Returns
+    ///
+    /// The next token, or [`Token::Eof`] once nothing more can be scanned,
+    /// including when the budget runs out. Use [`Scanner::try_next_any`] to
+    /// tell the two apart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{Scanner, Token};
+    /// let mut scanner = Scanner::new("x = 2.5;\n");
+    /// assert_eq!(scanner.next_any(), Token::Word("x"));
+    /// assert_eq!(scanner.next_any(), Token::Punct('='));
+    /// assert_eq!(scanner.next_any(), Token::Float(2.5));
+    /// assert_eq!(scanner.next_any(), Token::Punct(';'));
+    /// assert_eq!(scanner.next_any(), Token::Newline);
+    /// assert_eq!(scanner.next_any(), Token::Eof);
+    /// ```
+    pub fn next_any(&mut self) -> Token<'a> {
+        self.try_next_any().unwrap_or(Token::Eof)
+    }
+
+    /// Scans the next token, whatever it is, and classifies it.
+    ///
+    /// This is the `Result` returning counterpart of [`Scanner::next_any`].
+    /// [`Token::Eof`] is only returned at the end of the input.
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::BudgetExceeded`](super::ScanErrorKind::BudgetExceeded)
+    ///   if the scanner's budget is used up before the end of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{ScanErrorKind, Scanner, Token};
+    /// let mut scanner = Scanner::new("a b").with_budget(1, usize::MAX);
+    /// assert_eq!(scanner.try_next_any(), Ok(Token::Word("a")));
+    /// let error = scanner.try_next_any().unwrap_err();
+    /// assert_eq!(error.kind(), &ScanErrorKind::BudgetExceeded);
+    /// ```
+    pub fn try_next_any(&mut self) -> Result<Token<'a>, ScanError> {
+        let Some((kind, start, end)) = lex(
+            self.get_remaining(),
+            self.previous_char(),
+            self.sign_policy,
+            self.newline_policy,
+        ) else {
+            return Ok(Token::Eof);
+        };
+
+        let (start, end) = (self.position + start, self.position + end);
+        self.consume(start, end, end)?;
+
+        let text = &self.input[start..end];
+        Ok(match kind {
+            TokenKind::Word => Token::Word(text),
+            TokenKind::Int => text.parse().map_or_else(
+                |_| Token::Float(text.parse().unwrap_or(f64::NAN)),
+                Token::Int,
+            ),
+            TokenKind::Float => Token::Float(text.parse().unwrap_or(f64::NAN)),
+            TokenKind::Punct => Token::Punct(text.chars().next().unwrap_or_default()),
+            TokenKind::Newline => Token::Newline,
+            TokenKind::Eof => Token::Eof,
+        })
+    }
+
+    /// Scans the next token like [`Scanner::next_any`], along with its span.
+    ///
+    /// [`Token::Eof`] gets an empty span at the end of the input, or where
+    /// scanning stopped if the budget ran out, so an unexpected end of input
+    /// can be reported at a real line and column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{Scanner, Span, Token};
+    /// let mut scanner = Scanner::new("f(x,\n  ");
+    /// while scanner.next_spanned().value != Token::Eof {}
+    /// let eof = scanner.next_spanned();
+    /// assert_eq!(eof.span, Span::new(7, 7));
+    /// assert_eq!(scanner.line_column(eof.span.start), (2, 3));
+    /// ```
+    pub fn next_spanned(&mut self) -> Spanned<Token<'a>> {
+        let value = self.next_any();
+        let span = match (value, self.last) {
+            (Token::Eof, _) | (_, None) => {
+                let end = if self.get_remaining().trim().is_empty() {
+                    self.input.len()
+                } else {
+                    self.position
+                };
+                self.span(end, end)
+            }
+            (_, Some((start, end))) => self.span(start, end),
+        };
+
+        Spanned { value, span }
+    }
+
+    /// Returns an iterator over the remaining tokens, each with its span.
+    ///
+    /// The iterator yields one [`Token::Eof`] at the end, so parsers can
+    /// match on [`Token::kind`] exhaustively instead of special-casing the
+    /// end of the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::{Scanner, TokenKind};
+    /// let mut scanner = Scanner::new("a + 1\nb");
+    /// let kinds: Vec<_> = scanner
+    ///     .tokens()
+    ///     .without_newlines()
+    ///     .map(|token| token.value.kind())
+    ///     .collect();
+    /// assert_eq!(
+    ///     kinds,
+    ///     [
+    ///         TokenKind::Word,
+    ///         TokenKind::Punct,
+    ///         TokenKind::Int,
+    ///         TokenKind::Word,
+    ///         TokenKind::Eof,
+    ///     ]
+    /// );
+    /// ```
+    pub fn tokens(&mut self) -> Tokens<'_, 'a> {
+        Tokens {
+            scanner: self,
+            newlines: true,
+            finished: false,
+        }
+    }
+}
+
+/// Finds the next token of `text` for the generic lexer.
+///
+/// `previous` is the character before `text`, used with `policy` to decide
+/// whether a `-` directly in front of digits is a sign or an operator.
+/// `newline_policy` decides which line terminators become
+/// [`TokenKind::Newline`].
+///
+/// Returns the kind of the token and its byte range in `text`, or `None` if
+/// only whitespace other than newlines remains.
+pub(super) fn lex(
+    text: &str,
+    previous: Option<char>,
+    policy: SignPolicy,
+    newline_policy: NewlinePolicy,
+) -> Option<(TokenKind, usize, usize)> {
+    let start = text.find(|c: char| newline_policy.is_terminator(c) || !c.is_whitespace())?;
+    let rest = &text[start..];
+    let previous = if start > 0 {
+        text[..start].chars().next_back()
+    } else {
+        previous
+    };
+
+    let first = rest.chars().next()?;
+    let is_digit_at = |i: usize| rest[i..].starts_with(|c: char| c.is_ascii_digit());
+    let word_len = |from: usize| {
+        rest[from..]
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .map_or(rest.len(), |i| from + i)
+    };
+
+    let signed = first == '-' && is_digit_at(1) && policy.is_sign_after(previous);
+
+    let (kind, len) = if let Some(len) = newline_policy.terminator_len(rest) {
+        (TokenKind::Newline, len)
+    } else if first.is_ascii_digit() || signed {
+        let mut len = usize::from(signed);
+        let digits = |from: usize| {
+            rest[from..]
+                .find(|c: char| !c.is_ascii_digit())
+                .map_or(rest.len(), |i| from + i)
+        };
+
+        len = digits(len);
+        let mut kind = TokenKind::Int;
+
+        if rest[len..].starts_with('.') && is_digit_at(len + 1) {
+            kind = TokenKind::Float;
+            len = digits(len + 1);
+        }
+
+        let exponent = rest[len..].strip_prefix(['e', 'E']).map(|exp| {
+            let sign = usize::from(exp.starts_with(['+', '-']));
+            (sign, exp[sign..].starts_with(|c: char| c.is_ascii_digit()))
+        });
+        if let Some((sign, true)) = exponent {
+            kind = TokenKind::Float;
+            len = digits(len + 1 + sign);
+        }
+
+        if word_len(len) > len {
+            (TokenKind::Word, word_len(len))
+        } else {
+            (kind, len)
+        }
+    } else if first.is_alphanumeric() || first == '_' {
+        (TokenKind::Word, word_len(0))
+    } else {
+        (TokenKind::Punct, first.len_utf8())
+    };
+
+    Some((kind, start, start + len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(mut text: &str) -> Vec<(TokenKind, &str)> {
+        let mut tokens = Vec::new();
+        let mut previous = None;
+        while let Some((kind, start, end)) = lex(
+            text,
+            previous,
+            SignPolicy::Contextual,
+            NewlinePolicy::LineFeed,
+        ) {
+            tokens.push((kind, &text[start..end]));
+            previous = text[..end].chars().next_back();
+            text = &text[end..];
+        }
+        tokens
+    }
+
+    #[test]
+    fn test_lex_kinds() {
+        use TokenKind::*;
+        assert_eq!(
+            kinds("x1 = -2.5e3 + 7\n5-3 f(-1) 3rd"),
+            [
+                (Word, "x1"),
+                (Punct, "="),
+                (Float, "-2.5e3"),
+                (Punct, "+"),
+                (Int, "7"),
+                (Newline, "\n"),
+                (Int, "5"),
+                (Punct, "-"),
+                (Int, "3"),
+                (Word, "f"),
+                (Punct, "("),
+                (Int, "-1"),
+                (Punct, ")"),
+                (Word, "3rd"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_next_any_numbers() {
+        let mut scanner = Scanner::new("-7 99999999999999999999 1e3 é_1");
+        assert_eq!(scanner.next_any(), Token::Int(-7));
+        assert_eq!(scanner.next_any(), Token::Float(1e20));
+        assert_eq!(scanner.next_any(), Token::Float(1000.0));
+        assert_eq!(scanner.next_any(), Token::Word("é_1"));
+        assert_eq!(scanner.next_any().kind(), TokenKind::Eof);
+    }
+
+    #[test]
+    fn test_tokens_end_with_eof() {
+        let mut scanner = Scanner::new("x\n\n");
+        let tokens: Vec<_> = scanner.tokens().collect();
+        let values: Vec<_> = tokens.iter().map(|token| token.value).collect();
+        assert_eq!(
+            values,
+            [Token::Word("x"), Token::Newline, Token::Newline, Token::Eof]
+        );
+        assert_eq!(tokens[3].span, crate::scanner::Span::new(3, 3));
+    }
+
+    #[test]
+    fn test_lex_trailing_dot_and_exponent() {
+        use TokenKind::*;
+        assert_eq!(kinds("1. 2e"), [(Int, "1"), (Punct, "."), (Word, "2e")]);
+    }
+
+    #[test]
+    fn test_budget_is_not_end_of_input() {
+        let mut scanner = Scanner::new("a b  ").with_budget(1, usize::MAX);
+        assert_eq!(scanner.try_next_any(), Ok(Token::Word("a")));
+        let error = scanner.try_next_any().unwrap_err();
+        assert_eq!(error.kind(), &crate::scanner::ScanErrorKind::BudgetExceeded);
+        assert_eq!(scanner.next_any(), Token::Eof);
+
+        let mut scanner = Scanner::new("a b  ").with_budget(2, usize::MAX);
+        scanner.next_any();
+        scanner.next_any();
+        assert_eq!(scanner.try_next_any(), Ok(Token::Eof));
+
+        let mut scanner = Scanner::new("a\n  b c\n").with_budget(2, usize::MAX);
+        let tokens: Vec<_> = scanner.indent_tokens().collect();
+        assert!(tokens.last().unwrap().is_err());
+    }
+}
@@ -0,0 +1,109 @@
+use super::stats::TokenClass;
+use super::Scanner;
+
+/// The number of characters of a token shown in trace events.
+const PREVIEW_CHARS: usize = 32;
+
+impl Scanner<'_> {
+    /// Emits a trace event for the consumption of `start..end`, moving the
+    /// cursor to `position`, unless the scanner is a lookahead fork.
+    pub(super) fn trace_consumed(&self, start: usize, end: usize, position: usize) {
+        if !self.traced {
+            return;
+        }
+
+        let token = self.input.get(start..end).unwrap_or_default();
+        let preview = match token.char_indices().nth(PREVIEW_CHARS) {
+            Some((cut, _)) => format!("{}…", &token[..cut]),
+            None => token.to_string(),
+        };
+
+        tracing::trace!(
+            kind = class_name(TokenClass::of(token)),
+            start,
+            end,
+            position,
+            value = %preview.escape_debug(),
+            "consumed token"
+        );
+    }
+
+    /// Emits a trace event for a failed scan or an unread rewinding the
+    /// cursor from `from` to the current position, unless the scanner is a
+    /// lookahead fork.
+    pub(super) fn trace_rewound(&self, from: usize) {
+        if !self.traced {
+            return;
+        }
+        tracing::trace!(from, to = self.position, "rewound cursor");
+    }
+}
+
+fn class_name(class: TokenClass) -> &'static str {
+    match class {
+        TokenClass::Number => "number",
+        TokenClass::Word => "word",
+        TokenClass::Other => "other",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    use super::*;
+
+    /// Collects the `value` field of every event.
+    struct Values(Arc<Mutex<Vec<String>>>);
+
+    impl Visit for Values {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "value" {
+                self.0.lock().unwrap().push(format!("{value:?}"));
+            }
+        }
+    }
+
+    impl Subscriber for Values {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn event(&self, event: &Event<'_>) {
+            event.record(&mut Values(Arc::clone(&self.0)));
+        }
+        fn enter(&self, _: &Id) {}
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[test]
+    fn test_consumed_tokens_are_traced() {
+        let values = Arc::new(Mutex::new(Vec::new()));
+        let long = "x".repeat(40);
+        let input = format!("a\t1 {long}");
+
+        tracing::subscriber::with_default(Values(Arc::clone(&values)), || {
+            let mut scanner = Scanner::new(&input);
+            assert!(scanner.has_next_word());
+            assert_eq!(scanner.count_tokens(), 3);
+            scanner.next_word();
+            scanner.next_number::<u8>();
+            scanner.next_word();
+        });
+
+        let expected = [
+            "a".to_string(),
+            "1".to_string(),
+            format!("{}…", &long[..32]),
+        ];
+        assert_eq!(*values.lock().unwrap(), expected);
+    }
+}
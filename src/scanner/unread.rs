@@ -0,0 +1,53 @@
+use super::Scanner;
+
+impl<'a> Scanner<'a> {
+    /// Returns the last consumed token, line or paragraph to the input, so
+    /// that the next scan reads it again.
+    ///
+    /// Only one token can be unread, as the scanner does not remember the
+    /// tokens before the last one.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` with the unread token.
+    /// * `None` if nothing has been consumed since the start or the last
+    ///   unread. The cursor is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("let x = 1");
+    /// assert_eq!(scanner.next_word(), Some("let"));
+    /// assert_eq!(scanner.next_word(), Some("x"));
+    /// assert_eq!(scanner.unread_last(), Some("x"));
+    /// assert_eq!(scanner.unread_last(), None);
+    /// assert_eq!(scanner.next_word(), Some("x"));
+    /// ```
+    pub fn unread_last(&mut self) -> Option<&'a str> {
+        let (start, end) = self.last.take()?;
+        #[cfg(feature = "tracing")]
+        let from = self.position;
+        self.position = start;
+        self.tokens = self.tokens.saturating_sub(1);
+        #[cfg(feature = "tracing")]
+        self.trace_rewound(from);
+        Some(&self.input[start..end])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unread_last_restores_token_budget() {
+        let mut scanner = Scanner::new("1 2").with_budget(1, usize::MAX);
+        assert_eq!(scanner.next_number::<u8>(), Some(1));
+        assert_eq!(scanner.next_number::<u8>(), None);
+
+        assert_eq!(scanner.unread_last(), Some("1"));
+        assert_eq!(scanner.last_span(), None);
+        assert_eq!(scanner.next_number::<u8>(), Some(1));
+    }
+}
@@ -0,0 +1,82 @@
+use semver::Version;
+
+use super::{ScanError, ScanErrorKind, Scanner};
+
+impl Scanner<'_> {
+    /// Scans for the next semantic version, such as `1.2.3-alpha.1+build5`.
+    ///
+    /// Leading whitespace is skipped. The version is the longest run of
+    /// ASCII alphanumerics, `.`, `-` and `+`, without trailing dots, so a
+    /// version ending a sentence or followed by a comma is still found.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Version)` if a valid version is next.
+    /// * `None` otherwise, in which case nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scanner::scanner::Scanner;
+    /// let mut scanner = Scanner::new("serde 1.0.210, semver 1.2.3-alpha.1+build5.");
+    /// scanner.next_word();
+    /// assert_eq!(scanner.next_semver().unwrap().to_string(), "1.0.210");
+    /// scanner.expect(",").unwrap();
+    /// scanner.next_word();
+    /// let version = scanner.next_semver().unwrap();
+    /// assert_eq!(version.pre.as_str(), "alpha.1");
+    /// assert_eq!(version.build.as_str(), "build5");
+    /// assert_eq!(scanner.get_remaining(), ".");
+    /// ```
+    pub fn next_semver(&mut self) -> Option<Version> {
+        self.try_next_semver().ok()
+    }
+
+    /// Scans for the next semantic version.
+    ///
+    /// See [`Scanner::next_semver`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ScanErrorKind::EndOfInput`] if only whitespace remains.
+    /// * [`ScanErrorKind::InvalidFormat`] if no valid version is next.
+    /// * [`ScanErrorKind::BudgetExceeded`] if the scanner's budget is used up.
+    pub fn try_next_semver(&mut self) -> Result<Version, ScanError> {
+        let remaining = self.get_remaining();
+        let trimmed = remaining.trim_start();
+        let start = self.position + remaining.len() - trimmed.len();
+
+        if trimmed.is_empty() {
+            return Err(self.error(ScanErrorKind::EndOfInput));
+        }
+
+        let len = trimmed
+            .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+')))
+            .unwrap_or(trimmed.len());
+        let text = trimmed[..len].trim_end_matches('.');
+
+        let version = Version::parse(text).map_err(|_| {
+            let format = "semantic version";
+            ScanError::new(ScanErrorKind::InvalidFormat { format }, start)
+        })?;
+        self.consume(start, start + text.len(), start + text.len())?;
+        Ok(version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_semver() {
+        let mut scanner = Scanner::new(" 1.2 01.2.3 1.2.3");
+        let error = scanner.try_next_semver().unwrap_err();
+        assert_eq!(error.to_string(), "invalid semantic version at offset 1");
+
+        scanner.next_word();
+        assert_eq!(scanner.next_semver(), None);
+        scanner.next_word();
+        assert_eq!(scanner.next_semver(), Some(Version::new(1, 2, 3)));
+    }
+}